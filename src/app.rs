@@ -1,14 +1,20 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::sync::Arc;
+use std::time::Duration;
 
 use ratatui::layout::Rect;
 use ratatui::widgets::TableState;
 
-use crate::backend::WingetBackend;
-use crate::config::Config;
+use tokio::sync::watch;
+
+use crate::backend::{ProgressFn, WingetBackend};
+use crate::cache::DiskCache;
+use crate::config::{Config, ConfigWarning};
+use crate::error::{WingetError, WingetErrorKind};
 use crate::models::{
-    OpResult, Operation, Package, PackageDetail, PackagePin, PinFilter, SortDir, SortField,
-    SourceFilter,
+    InstallOptions, OpResult, Operation, OperationProgress, Package, PackageDetail, PackagePin,
+    PinFilter, PinState, SearchRequest, SortDir, SortField, Source, SourceFilter, SourceUpdate,
+    UninstallOptions, INSTALLER_TYPES, INSTALL_ARCHITECTURES, SOURCE_TYPES,
 };
 use crate::theme::Theme;
 
@@ -23,6 +29,10 @@ pub struct LayoutRegions {
     pub list_content_y: u16,
     /// Tab click regions: (start_x, end_x, mode)
     pub tab_regions: Vec<(u16, u16, AppMode)>,
+    /// Row of the detail panel's tab strip
+    pub detail_tab_y: u16,
+    /// Detail tab click regions: (start_x, end_x, tab)
+    pub detail_tab_regions: Vec<(u16, u16, DetailTab)>,
 }
 
 /// Which panel currently has keyboard focus
@@ -41,6 +51,48 @@ impl FocusZone {
     }
 }
 
+/// Tabs of the detail panel. Overview, Installer and Dependencies come from
+/// `winget show`; Versions and Raw are fetched the first time they are opened.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DetailTab {
+    #[default]
+    Overview,
+    Versions,
+    Installer,
+    Dependencies,
+    Raw,
+}
+
+impl DetailTab {
+    pub const ALL: [DetailTab; 5] = [
+        Self::Overview,
+        Self::Versions,
+        Self::Installer,
+        Self::Dependencies,
+        Self::Raw,
+    ];
+
+    pub fn cycle(self) -> Self {
+        let i = Self::ALL.iter().position(|t| *t == self).unwrap_or(0);
+        Self::ALL[(i + 1) % Self::ALL.len()]
+    }
+
+    pub fn cycle_back(self) -> Self {
+        let i = Self::ALL.iter().position(|t| *t == self).unwrap_or(0);
+        Self::ALL[(i + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Overview => "Overview",
+            Self::Versions => "Versions",
+            Self::Installer => "Installer",
+            Self::Dependencies => "Dependencies",
+            Self::Raw => "Raw",
+        }
+    }
+}
+
 /// Messages sent from background tasks back to the UI
 #[derive(Debug)]
 pub enum AppMessage {
@@ -50,9 +102,38 @@ pub enum AppMessage {
     },
     DetailLoaded {
         generation: u64,
-        detail: PackageDetail,
+        detail: Box<PackageDetail>,
+    },
+    /// Details fetched ahead of time for a row next to the selection; `None`
+    /// when the prefetch failed or gave way to other winget calls
+    DetailPrefetched {
+        id: String,
+        detail: Option<Box<PackageDetail>>,
+    },
+    /// Versions available for a package, for the version picker or the
+    /// Versions detail tab
+    VersionsLoaded {
+        id: String,
+        versions: Vec<String>,
+    },
+    /// Unparsed `winget show` output for the Raw detail tab, for a package
+    /// whose cached details predate it
+    RawDetailLoaded {
+        id: String,
+        raw: String,
+    },
+    /// Sources reported by `winget source list`
+    SourcesLoaded(Vec<Source>),
+    /// `winget source list` failed; the filter keeps its default sources
+    SourcesFailed(String),
+    /// `winget source update` finished for one source
+    SourceUpdated {
+        name: String,
+        result: Result<(), String>,
     },
     OperationComplete(OpResult),
+    /// Live output or progress from the running operation
+    OperationProgress(OperationProgress),
     StatusUpdate(String),
     Error(String),
 }
@@ -62,6 +143,8 @@ pub enum AppMode {
     Search,
     Installed,
     Upgrades,
+    /// Every configured pin, including pins for packages no longer installed
+    Pins,
 }
 
 impl AppMode {
@@ -69,15 +152,17 @@ impl AppMode {
         match self {
             Self::Search => Self::Installed,
             Self::Installed => Self::Upgrades,
-            Self::Upgrades => Self::Search,
+            Self::Upgrades => Self::Pins,
+            Self::Pins => Self::Search,
         }
     }
 
     pub fn cycle_back(&self) -> Self {
         match self {
-            Self::Search => Self::Upgrades,
+            Self::Search => Self::Pins,
             Self::Installed => Self::Search,
             Self::Upgrades => Self::Installed,
+            Self::Pins => Self::Upgrades,
         }
     }
 
@@ -87,6 +172,7 @@ impl AppMode {
             Self::Search => "Search",
             Self::Installed => "Installed",
             Self::Upgrades => "Upgrades",
+            Self::Pins => "Pins",
         }
     }
 }
@@ -95,12 +181,65 @@ impl AppMode {
 pub enum InputMode {
     Normal,
     Search,
-    /// Inline prompt for typing a specific version before installing
+    /// Version picker for installing a specific version; typing filters the
+    /// list or enters a custom version
     VersionInput,
     /// In-memory filtering for Installed/Upgrades views using the existing list.
     LocalFilter,
 }
 
+/// Lifecycle of an entry in the operation queue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QueueStatus {
+    Pending,
+    Running,
+    Done,
+    Failed,
+    Cancelled,
+}
+
+impl QueueStatus {
+    pub fn is_finished(self) -> bool {
+        matches!(self, Self::Done | Self::Failed | Self::Cancelled)
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Running => "running",
+            Self::Done => "done",
+            Self::Failed => "failed",
+            Self::Cancelled => "cancelled",
+        }
+    }
+}
+
+/// One requested operation and what became of it.
+#[derive(Debug, Clone)]
+pub struct QueueEntry {
+    pub operation: Operation,
+    pub status: QueueStatus,
+    /// Result message once the entry has finished
+    pub message: String,
+}
+
+/// Handle to the operation task that is currently in flight.
+pub struct RunningOperation {
+    pub operation: Operation,
+    cancel: watch::Sender<bool>,
+    task: tokio::task::JoinHandle<()>,
+}
+
+/// Maximum number of operation output lines kept for the output overlay.
+pub const OP_OUTPUT_LIMIT: usize = 500;
+
+/// How long the cursor must rest on a row before its details are fetched.
+const DETAIL_DEBOUNCE: Duration = Duration::from_millis(120);
+
+/// How long after the selected row's details arrive before its neighbours are
+/// prefetched. Any cursor move in the meantime cancels the prefetch.
+const DETAIL_PREFETCH_DELAY: Duration = Duration::from_millis(250);
+
 /// Confirmation dialog state
 #[derive(Debug, Clone)]
 pub struct ConfirmDialog {
@@ -108,6 +247,502 @@ pub struct ConfirmDialog {
     pub operation: Operation,
 }
 
+/// A field of the install options dialog.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InstallField {
+    Scope,
+    Architecture,
+    InstallerType,
+    Locale,
+    Location,
+    Mode,
+    Override,
+}
+
+impl InstallField {
+    pub const ALL: [Self; 7] = [
+        Self::Scope,
+        Self::Architecture,
+        Self::InstallerType,
+        Self::Locale,
+        Self::Location,
+        Self::Mode,
+        Self::Override,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Scope => "Scope",
+            Self::Architecture => "Architecture",
+            Self::InstallerType => "Installer type",
+            Self::Locale => "Locale",
+            Self::Location => "Location",
+            Self::Mode => "Mode",
+            Self::Override => "Override args",
+        }
+    }
+
+    /// Whether the field takes typed text rather than cycling through choices.
+    pub fn is_text(self) -> bool {
+        matches!(self, Self::Locale | Self::Location | Self::Override)
+    }
+}
+
+/// Install options dialog state
+#[derive(Debug, Clone)]
+pub struct InstallDialog {
+    pub id: String,
+    pub options: InstallOptions,
+    /// Index into [`InstallField::ALL`] of the highlighted field
+    pub field: usize,
+}
+
+impl InstallDialog {
+    pub fn new(id: String) -> Self {
+        Self {
+            id,
+            options: InstallOptions::default(),
+            field: 0,
+        }
+    }
+
+    pub fn current_field(&self) -> InstallField {
+        InstallField::ALL[self.field]
+    }
+
+    /// Move the highlight by `delta` fields, wrapping around.
+    pub fn move_field(&mut self, delta: isize) {
+        let len = InstallField::ALL.len() as isize;
+        self.field = (self.field as isize + delta).rem_euclid(len) as usize;
+    }
+
+    /// Advance a choice field to its next value; text fields are unchanged.
+    pub fn cycle_current(&mut self) {
+        let field = self.current_field();
+        let options = &mut self.options;
+        match field {
+            InstallField::Scope => options.scope = options.scope.cycle(),
+            InstallField::Architecture => {
+                options.architecture = next_choice(&options.architecture, INSTALL_ARCHITECTURES)
+            }
+            InstallField::InstallerType => {
+                options.installer_type = next_choice(&options.installer_type, INSTALLER_TYPES)
+            }
+            InstallField::Mode => options.mode = options.mode.cycle(),
+            InstallField::Locale | InstallField::Location | InstallField::Override => {}
+        }
+    }
+
+    fn text_mut(&mut self) -> Option<&mut Option<String>> {
+        match self.current_field() {
+            InstallField::Locale => Some(&mut self.options.locale),
+            InstallField::Location => Some(&mut self.options.location),
+            InstallField::Override => Some(&mut self.options.override_args),
+            _ => None,
+        }
+    }
+
+    /// Append `c` to the highlighted text field.
+    pub fn push_char(&mut self, c: char) {
+        if let Some(text) = self.text_mut() {
+            text.get_or_insert_with(String::new).push(c);
+        }
+    }
+
+    /// Delete the last character of the highlighted text field, clearing it
+    /// back to winget's default once it is empty.
+    pub fn pop_char(&mut self) {
+        if let Some(text) = self.text_mut() {
+            if let Some(value) = text {
+                value.pop();
+                if value.is_empty() {
+                    *text = None;
+                }
+            }
+        }
+    }
+
+    /// The current value of `field` for display.
+    pub fn value(&self, field: InstallField) -> String {
+        let options = &self.options;
+        let text = |value: &Option<String>| value.clone().unwrap_or_default();
+        match field {
+            InstallField::Scope => options.scope.as_arg().unwrap_or("any").to_string(),
+            InstallField::Mode => options
+                .mode
+                .as_flag()
+                .map_or("default", |flag| flag.trim_start_matches("--"))
+                .to_string(),
+            InstallField::Architecture => options
+                .architecture
+                .clone()
+                .unwrap_or_else(|| "any".to_string()),
+            InstallField::InstallerType => options
+                .installer_type
+                .clone()
+                .unwrap_or_else(|| "any".to_string()),
+            InstallField::Locale => text(&options.locale),
+            InstallField::Location => text(&options.location),
+            InstallField::Override => text(&options.override_args),
+        }
+    }
+
+    /// The install operation with blank text fields left to winget.
+    pub fn operation(&self) -> Operation {
+        let mut options = self.options.clone();
+        for text in [
+            &mut options.locale,
+            &mut options.location,
+            &mut options.override_args,
+        ] {
+            *text = text
+                .take()
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty());
+        }
+        Operation::Install {
+            id: self.id.clone(),
+            version: None,
+            options,
+        }
+    }
+}
+
+/// The value after `current` in `choices`, wrapping through `None` ("any").
+fn next_choice(current: &Option<String>, choices: &[&str]) -> Option<String> {
+    let next = match current {
+        None => 0,
+        Some(value) => choices.iter().position(|c| c == value).map_or(0, |i| i + 1),
+    };
+    choices.get(next).map(|c| c.to_string())
+}
+
+/// A field of the uninstall options dialog.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UninstallField {
+    Version,
+    AllVersions,
+    Purge,
+    Force,
+}
+
+impl UninstallField {
+    pub const ALL: [Self; 4] = [Self::Version, Self::AllVersions, Self::Purge, Self::Force];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Version => "Version",
+            Self::AllVersions => "All versions",
+            Self::Purge => "Purge files",
+            Self::Force => "Force",
+        }
+    }
+}
+
+/// Uninstall options dialog state
+#[derive(Debug, Clone)]
+pub struct UninstallDialog {
+    pub id: String,
+    pub options: UninstallOptions,
+    /// Index into [`UninstallField::ALL`] of the highlighted field
+    pub field: usize,
+}
+
+impl UninstallDialog {
+    pub fn new(id: String) -> Self {
+        Self {
+            id,
+            options: UninstallOptions::default(),
+            field: 0,
+        }
+    }
+
+    pub fn current_field(&self) -> UninstallField {
+        UninstallField::ALL[self.field]
+    }
+
+    /// Move the highlight by `delta` fields, wrapping around.
+    pub fn move_field(&mut self, delta: isize) {
+        let len = UninstallField::ALL.len() as isize;
+        self.field = (self.field as isize + delta).rem_euclid(len) as usize;
+    }
+
+    /// Flip the highlighted switch; the version field is unchanged.
+    pub fn toggle_current(&mut self) {
+        let field = self.current_field();
+        let options = &mut self.options;
+        match field {
+            UninstallField::Version => {}
+            UninstallField::AllVersions => options.all_versions = !options.all_versions,
+            UninstallField::Purge => options.purge = !options.purge,
+            UninstallField::Force => options.force = !options.force,
+        }
+    }
+
+    /// Append `c` to the version when it is highlighted.
+    pub fn push_char(&mut self, c: char) {
+        if self.current_field() == UninstallField::Version {
+            self.options.version.get_or_insert_with(String::new).push(c);
+        }
+    }
+
+    /// Delete the last character of the version when it is highlighted.
+    pub fn pop_char(&mut self) {
+        if self.current_field() == UninstallField::Version {
+            if let Some(version) = &mut self.options.version {
+                version.pop();
+                if version.is_empty() {
+                    self.options.version = None;
+                }
+            }
+        }
+    }
+
+    /// The current value of `field` for display.
+    pub fn value(&self, field: UninstallField) -> String {
+        let switch = |on: bool| if on { "yes" } else { "no" }.to_string();
+        match field {
+            UninstallField::Version => self.options.version.clone().unwrap_or_default(),
+            UninstallField::AllVersions => switch(self.options.all_versions),
+            UninstallField::Purge => switch(self.options.purge),
+            UninstallField::Force => switch(self.options.force),
+        }
+    }
+
+    /// The uninstall operation, with a blank version meaning "any".
+    pub fn operation(&self) -> Operation {
+        let mut options = self.options.clone();
+        options.version = options
+            .version
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty());
+        Operation::Uninstall {
+            id: self.id.clone(),
+            options,
+        }
+    }
+}
+
+/// Kinds of pin offered by the pin dialog, plus removing the current pin.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PinChoice {
+    Regular,
+    Blocking,
+    Gating,
+    Remove,
+}
+
+impl PinChoice {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Regular => "Regular  — skipped by upgrade --all",
+            Self::Blocking => "Blocking — never upgraded until unpinned",
+            Self::Gating => "Gating   — upgrades only within a version range",
+            Self::Remove => "Remove pin",
+        }
+    }
+}
+
+/// Pin dialog state
+#[derive(Debug, Clone)]
+pub struct PinDialog {
+    pub id: String,
+    /// Choices on offer; `Remove` only when the package is already pinned
+    pub choices: Vec<PinChoice>,
+    pub selected: usize,
+    /// Version range for a gating pin, e.g. `1.2.*`
+    pub gating_range: String,
+}
+
+impl PinDialog {
+    /// Open on the package's current pin kind, or on a blocking pin when it
+    /// has none. The gating range starts from the installed version.
+    pub fn new(id: String, installed_version: &str, current: &PinState) -> Self {
+        let mut choices = vec![PinChoice::Regular, PinChoice::Blocking, PinChoice::Gating];
+        if current.is_pinned() {
+            choices.push(PinChoice::Remove);
+        }
+        let (selected, gating_range) = match current {
+            PinState::Pinned => (0, gating_pattern(installed_version)),
+            PinState::Gating(range) => (2, range.clone()),
+            PinState::Blocking | PinState::None => (1, gating_pattern(installed_version)),
+        };
+        Self {
+            id,
+            choices,
+            selected,
+            gating_range,
+        }
+    }
+
+    pub fn current_choice(&self) -> PinChoice {
+        self.choices[self.selected]
+    }
+
+    /// Move the highlight by `delta` choices, wrapping around.
+    pub fn move_selection(&mut self, delta: isize) {
+        let len = self.choices.len() as isize;
+        self.selected = (self.selected as isize + delta).rem_euclid(len) as usize;
+    }
+
+    /// Append `c` to the gating range while a gating pin is highlighted.
+    pub fn push_char(&mut self, c: char) {
+        if self.current_choice() == PinChoice::Gating {
+            self.gating_range.push(c);
+        }
+    }
+
+    /// Delete the last character of the gating range while it is highlighted.
+    pub fn pop_char(&mut self) {
+        if self.current_choice() == PinChoice::Gating {
+            self.gating_range.pop();
+        }
+    }
+
+    /// The chosen operation, or `None` for a gating pin without a range.
+    pub fn operation(&self) -> Option<Operation> {
+        let id = self.id.clone();
+        let pin = match self.current_choice() {
+            PinChoice::Regular => PinState::Pinned,
+            PinChoice::Blocking => PinState::Blocking,
+            PinChoice::Gating => {
+                let range = self.gating_range.trim();
+                if range.is_empty() {
+                    return None;
+                }
+                PinState::Gating(range.to_string())
+            }
+            PinChoice::Remove => return Some(Operation::Unpin { id }),
+        };
+        Some(Operation::Pin { id, pin })
+    }
+}
+
+/// A gating range that allows patch upgrades of `version`: `1.2.3` → `1.2.*`.
+fn gating_pattern(version: &str) -> String {
+    let version = version.trim();
+    match version.rsplit_once('.') {
+        Some((prefix, _)) => format!("{prefix}.*"),
+        None if version.is_empty() => String::new(),
+        None => format!("{version}.*"),
+    }
+}
+
+/// A field of the add-source dialog.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SourceField {
+    Name,
+    Argument,
+    Type,
+}
+
+impl SourceField {
+    pub const ALL: [Self; 3] = [Self::Name, Self::Argument, Self::Type];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Name => "Name",
+            Self::Argument => "URL / path",
+            Self::Type => "Type",
+        }
+    }
+}
+
+/// Add-source dialog state
+#[derive(Debug, Clone)]
+pub struct SourceDialog {
+    pub source: Source,
+    /// Index into [`SourceField::ALL`] of the highlighted field
+    pub field: usize,
+}
+
+impl SourceDialog {
+    /// Start on a REST source, the usual kind for private feeds.
+    pub fn new() -> Self {
+        Self {
+            source: Source {
+                source_type: SOURCE_TYPES[0].to_string(),
+                ..Source::default()
+            },
+            field: 0,
+        }
+    }
+
+    pub fn current_field(&self) -> SourceField {
+        SourceField::ALL[self.field]
+    }
+
+    /// Move the highlight by `delta` fields, wrapping around.
+    pub fn move_field(&mut self, delta: isize) {
+        let len = SourceField::ALL.len() as isize;
+        self.field = (self.field as isize + delta).rem_euclid(len) as usize;
+    }
+
+    /// Switch to the next source type when it is highlighted.
+    pub fn cycle_current(&mut self) {
+        if self.current_field() == SourceField::Type {
+            let next = SOURCE_TYPES
+                .iter()
+                .position(|t| *t == self.source.source_type)
+                .map_or(0, |i| (i + 1) % SOURCE_TYPES.len());
+            self.source.source_type = SOURCE_TYPES[next].to_string();
+        }
+    }
+
+    fn text_mut(&mut self) -> Option<&mut String> {
+        match self.current_field() {
+            SourceField::Name => Some(&mut self.source.name),
+            SourceField::Argument => Some(&mut self.source.url),
+            SourceField::Type => None,
+        }
+    }
+
+    /// Append `c` to the highlighted text field.
+    pub fn push_char(&mut self, c: char) {
+        if let Some(text) = self.text_mut() {
+            text.push(c);
+        }
+    }
+
+    /// Delete the last character of the highlighted text field.
+    pub fn pop_char(&mut self) {
+        if let Some(text) = self.text_mut() {
+            text.pop();
+        }
+    }
+
+    /// The current value of `field` for display.
+    pub fn value(&self, field: SourceField) -> &str {
+        match field {
+            SourceField::Name => &self.source.name,
+            SourceField::Argument => &self.source.url,
+            SourceField::Type => &self.source.source_type,
+        }
+    }
+
+    /// The add-source operation, or `None` until a name and URL are given.
+    pub fn operation(&self) -> Option<Operation> {
+        let name = self.source.name.trim();
+        let url = self.source.url.trim();
+        if name.is_empty() || url.is_empty() {
+            return None;
+        }
+        Some(Operation::AddSource {
+            source: Source {
+                name: name.to_string(),
+                url: url.to_string(),
+                source_type: self.source.source_type.clone(),
+            },
+        })
+    }
+}
+
+impl Default for SourceDialog {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 pub struct App {
     pub theme: Theme,
     pub mode: AppMode,
@@ -125,8 +760,22 @@ pub struct App {
     pub status_message: String,
     pub loading: bool,
     pub confirm: Option<ConfirmDialog>,
-    /// Version string being edited in the VersionInput prompt
+    /// Install options dialog, open while choosing installer settings
+    pub install_dialog: Option<InstallDialog>,
+    /// Uninstall options dialog, open while choosing what to remove
+    pub uninstall_dialog: Option<UninstallDialog>,
+    /// Pin dialog, open while choosing the kind of pin
+    pub pin_dialog: Option<PinDialog>,
+    /// Filter or custom version being typed in the version picker
     pub version_input: String,
+    /// Package the version picker was opened for
+    pub picker_id: String,
+    /// Versions offered by the version picker, newest first
+    pub picker_versions: Vec<String>,
+    /// Whether `winget show --versions` is still running for the picker
+    pub picker_loading: bool,
+    /// Highlighted row among the versions matching `version_input`
+    pub picker_selected: usize,
     pub show_help: bool,
     /// Scroll offset for the help overlay (in rendered lines)
     pub help_scroll: u16,
@@ -144,6 +793,12 @@ pub struct App {
     pub detail_scroll: usize,
     /// Total rendered line count of the detail panel (set during rendering)
     pub detail_content_lines: usize,
+    /// Whether the release notes section of the detail panel is expanded
+    pub release_notes_expanded: bool,
+    /// Active tab of the detail panel
+    pub detail_tab: DetailTab,
+    /// Detail tab fetch in flight, as (tab, package id)
+    pub detail_tab_loading: Option<(DetailTab, String)>,
     /// Tick counter for animations (spinner, etc.)
     pub tick: usize,
     /// Incremented on each view refresh; stale results are discarded
@@ -152,10 +807,54 @@ pub struct App {
     pub detail_generation: u64,
     /// Cache of package details to avoid repeated winget show calls
     pub detail_cache: HashMap<String, PackageDetail>,
+    /// `winget show` for the selected row; aborting it kills the process
+    detail_task: Option<tokio::task::JoinHandle<()>>,
+    /// Low-priority `winget show` calls for the rows around the selection
+    prefetch_tasks: HashMap<String, tokio::task::JoinHandle<()>>,
+    /// Lists and details kept between launches
+    pub disk_cache: DiskCache,
+    /// Age of the cached list on screen while winget revalidates it
+    pub stale_list_age: Option<Duration>,
+    /// Available versions per package id, shared by the Versions tab and the
+    /// version picker
+    pub versions_cache: HashMap<String, Vec<String>>,
+    /// Unparsed `winget show` output per package id, for the Raw tab
+    pub raw_cache: HashMap<String, String>,
     /// Indices into filtered_packages that are selected for batch operations
     pub selected_packages: HashSet<usize>,
     /// A high-signal status message to restore after the next list refresh completes.
     pub post_refresh_status: Option<String>,
+    /// Phase reported by the running operation (e.g. "Downloading")
+    pub op_phase: Option<String>,
+    /// Percent complete reported by the running operation
+    pub op_percent: Option<u8>,
+    /// winget output from the running (or most recent) operation
+    pub op_output: VecDeque<String>,
+    /// Whether the operation output overlay is visible
+    pub show_output: bool,
+    /// The operation task in flight, kept so it can be cancelled
+    pub running_op: Option<RunningOperation>,
+    /// Whether the "cancel the running operation?" prompt is open
+    pub confirm_cancel: bool,
+    /// Every requested operation, run one at a time in order
+    pub queue: Vec<QueueEntry>,
+    /// Whether the Queue view overlay is visible
+    pub show_queue: bool,
+    /// Highlighted row in the Queue view
+    pub queue_selected: usize,
+    /// An operation reported that Windows must restart to finish
+    pub reboot_pending: bool,
+    /// Whether the Sources view overlay is visible
+    pub show_sources: bool,
+    /// Configured sources, as last reported by `winget source list`
+    pub sources: Vec<Source>,
+    /// Highlighted row in the Sources view
+    pub sources_selected: usize,
+    pub sources_loading: bool,
+    /// Result of the last `winget source update`, by source name
+    pub source_updates: HashMap<String, SourceUpdate>,
+    /// Add-source dialog state
+    pub source_dialog: Option<SourceDialog>,
     pub backend: Arc<dyn WingetBackend>,
     pub message_tx: tokio::sync::mpsc::UnboundedSender<AppMessage>,
     pub message_rx: tokio::sync::mpsc::UnboundedReceiver<AppMessage>,
@@ -202,7 +901,7 @@ fn compare_versions(a: &str, b: &str) -> std::cmp::Ordering {
 }
 
 impl App {
-    fn annotate_pins(packages: &mut [Package], pins: Vec<PackagePin>) {
+    pub fn annotate_pins(packages: &mut [Package], pins: Vec<PackagePin>) {
         let pin_map: HashMap<String, _> = pins
             .into_iter()
             .map(|pin| (pin.id, pin.pin_state))
@@ -217,7 +916,7 @@ impl App {
     pub fn new(backend: Arc<dyn WingetBackend>, cfg: Config) -> Self {
         let (message_tx, message_rx) = tokio::sync::mpsc::unbounded_channel();
         Self {
-            theme: Theme::from_name(cfg.theme),
+            theme: cfg.build_theme(),
             mode: cfg.default_view,
             input_mode: InputMode::Normal,
             focus: FocusZone::PackageList,
@@ -233,7 +932,14 @@ impl App {
             status_message: "Loading...".to_string(),
             loading: false,
             confirm: None,
+            install_dialog: None,
+            uninstall_dialog: None,
+            pin_dialog: None,
             version_input: String::new(),
+            picker_id: String::new(),
+            picker_versions: Vec::new(),
+            picker_loading: false,
+            picker_selected: 0,
             show_help: false,
             help_scroll: 0,
             help_max_scroll: 0,
@@ -244,18 +950,67 @@ impl App {
             table_state: TableState::default(),
             detail_scroll: 0,
             detail_content_lines: 0,
+            release_notes_expanded: false,
+            detail_tab: DetailTab::default(),
+            detail_tab_loading: None,
             tick: 0,
             view_generation: 0,
             detail_generation: 0,
             detail_cache: HashMap::new(),
+            detail_task: None,
+            prefetch_tasks: HashMap::new(),
+            disk_cache: DiskCache::default(),
+            stale_list_age: None,
+            versions_cache: HashMap::new(),
+            raw_cache: HashMap::new(),
             selected_packages: HashSet::new(),
             post_refresh_status: None,
+            op_phase: None,
+            op_percent: None,
+            op_output: VecDeque::new(),
+            show_output: false,
+            running_op: None,
+            confirm_cancel: false,
+            queue: Vec::new(),
+            show_queue: false,
+            queue_selected: 0,
+            reboot_pending: false,
+            show_sources: false,
+            sources: Vec::new(),
+            sources_selected: 0,
+            sources_loading: false,
+            source_updates: HashMap::new(),
+            source_dialog: None,
             backend,
             message_tx,
             message_rx,
         }
     }
 
+    /// Keep lists and details in `cache` and reuse them on the next launch.
+    pub fn with_disk_cache(mut self, cache: DiskCache) -> Self {
+        self.disk_cache = cache;
+        self
+    }
+
+    /// Show the cached list for the current view, if one has not expired,
+    /// until [`refresh_view`](Self::refresh_view) replaces it.
+    pub fn show_cached_list(&mut self) {
+        let source = self.source_filter.as_arg();
+        let Some((packages, age)) = self.disk_cache.list(self.mode, source) else {
+            return;
+        };
+        self.packages = packages.to_vec();
+        self.stale_list_age = Some(age);
+        self.apply_filter();
+        // Details saved by an earlier session can fill the panel right away too
+        if let Some(id) = self.selected_package().map(|p| p.id.clone()) {
+            if self.disk_cache.detail(&id).is_some() {
+                self.load_detail(&id);
+            }
+        }
+    }
+
     pub fn apply_filter(&mut self) {
         // When a source filter is active, winget already filters server-side
         // (and omits the Source column), so accept all returned packages.
@@ -274,7 +1029,7 @@ impl App {
                 pkg.name.to_lowercase().contains(&query) || pkg.id.to_lowercase().contains(&query)
             });
         }
-        if self.mode != AppMode::Search {
+        if matches!(self.mode, AppMode::Installed | AppMode::Upgrades) {
             self.filtered_packages
                 .retain(|pkg| self.pin_filter.matches(&pkg.pin_state));
         }
@@ -305,6 +1060,15 @@ impl App {
                     self.filtered_packages.reverse();
                 }
             }
+            SortField::Relevance => {
+                // Stable, so winget's own order is kept within each rank.
+                let request = SearchRequest::parse(&self.search_query).unwrap_or_default();
+                self.filtered_packages
+                    .sort_by_key(|pkg| pkg.match_rank(&request));
+                if self.sort_dir == SortDir::Desc {
+                    self.filtered_packages.reverse();
+                }
+            }
         }
         // Keep selection in bounds
         if self.selected >= self.filtered_packages.len() {
@@ -318,6 +1082,16 @@ impl App {
         self.ensure_selection_visible();
     }
 
+    /// Whether the Search view shows the "Match" column: only when winget
+    /// reported a match reason for at least one visible result.
+    pub fn shows_match_column(&self) -> bool {
+        self.mode == AppMode::Search
+            && self
+                .filtered_packages
+                .iter()
+                .any(|pkg| !pkg.match_reason.is_empty())
+    }
+
     pub fn selected_package(&self) -> Option<&Package> {
         self.filtered_packages.get(self.selected)
     }
@@ -352,9 +1126,15 @@ impl App {
         }
     }
 
+    /// Rows of the detail panel available to its content, below the borders
+    /// and the tab strip.
+    pub fn detail_viewport_height(&self) -> usize {
+        self.layout.detail_panel.height.saturating_sub(4) as usize
+    }
+
     /// Scroll the detail panel by `delta` lines, clamped to valid range.
     pub fn scroll_detail(&mut self, delta: isize) {
-        let viewport = self.layout.detail_panel.height.saturating_sub(3) as usize;
+        let viewport = self.detail_viewport_height();
         let max = self.detail_content_lines.saturating_sub(viewport);
         self.detail_scroll = (self.detail_scroll as isize + delta).clamp(0, max as isize) as usize;
     }
@@ -363,6 +1143,20 @@ impl App {
         self.status_message = msg.into();
     }
 
+    /// Show the first problem found in config.toml or a theme file, keeping
+    /// it in the status bar once the first list has loaded.
+    pub fn report_config_warnings(&mut self, warnings: &[ConfigWarning]) {
+        let Some(first) = warnings.first() else {
+            return;
+        };
+        let status = match warnings.len() {
+            1 => first.to_string(),
+            n => format!("{first} (+{} more; see --check-config)", n - 1),
+        };
+        self.set_status(status.clone());
+        self.post_refresh_status = Some(status);
+    }
+
     /// Advance through sort states: None → Name↑ → Name↓ → ID↑ → ID↓ → Version↑ → Version↓ → None → …
     pub fn cycle_sort(&mut self) {
         use crate::models::{SortDir, SortField};
@@ -373,7 +1167,14 @@ impl App {
             (SortField::Id, SortDir::Asc) => (SortField::Id, SortDir::Desc),
             (SortField::Id, SortDir::Desc) => (SortField::Version, SortDir::Asc),
             (SortField::Version, SortDir::Asc) => (SortField::Version, SortDir::Desc),
+            (SortField::Version, SortDir::Desc) if self.mode == AppMode::Search => {
+                (SortField::Relevance, SortDir::Asc)
+            }
             (SortField::Version, SortDir::Desc) => (SortField::None, SortDir::Asc),
+            (SortField::Relevance, SortDir::Asc) if self.mode == AppMode::Search => {
+                (SortField::Relevance, SortDir::Desc)
+            }
+            (SortField::Relevance, _) => (SortField::None, SortDir::Asc),
         };
         self.sort_field = next_field;
         self.sort_dir = next_dir;
@@ -398,6 +1199,27 @@ impl App {
         FRAMES[self.tick % FRAMES.len()]
     }
 
+    /// Short "Downloading 45%" style label for the running operation, if any.
+    pub fn operation_progress_label(&self) -> Option<String> {
+        match (&self.op_phase, self.op_percent) {
+            (Some(phase), Some(percent)) => Some(format!("{phase} {percent}%")),
+            (Some(phase), None) => Some(phase.clone()),
+            (None, Some(percent)) => Some(format!("{percent}%")),
+            (None, None) => None,
+        }
+    }
+
+    /// "3 winget · 2 waiting" style label while the backend runs more than one
+    /// winget process or is holding some back.
+    pub fn process_depth_label(&self) -> Option<String> {
+        let depth = self.backend.process_depth();
+        match (depth.running(), depth.waiting()) {
+            (0 | 1, 0) => None,
+            (running, 0) => Some(format!("{running} winget")),
+            (running, waiting) => Some(format!("{running} winget · {waiting} waiting")),
+        }
+    }
+
     fn ensure_detail_hint(detail: &mut PackageDetail) {
         if !detail.description.is_empty()
             || !detail.publisher.is_empty()
@@ -425,25 +1247,35 @@ impl App {
         let backend = self.backend.clone();
         let tx = self.message_tx.clone();
         let mode = self.mode;
-        let query = self.search_query.clone();
-        let source_arg = self.source_filter.as_arg();
+        // Invalid syntax is flagged in the search bar and searches for nothing
+        let request = SearchRequest::parse(&self.search_query).unwrap_or_default();
+        let source = self.source_filter.as_arg().map(str::to_string);
 
         tokio::spawn(async move {
+            let source_arg = source.as_deref();
             let result = match mode {
                 AppMode::Search => {
-                    if query.is_empty() {
+                    if request.is_empty() {
                         Ok(Vec::new())
                     } else {
-                        backend.search(&query, source_arg).await
+                        backend.search(&request, source_arg).await
                     }
                 }
                 AppMode::Installed => backend.list_installed(source_arg).await,
                 AppMode::Upgrades => backend.list_upgrades(source_arg).await,
+                AppMode::Pins => backend.list_pins().await.map(|pins| {
+                    pins.into_iter()
+                        .filter(|pin| {
+                            source_arg.is_none_or(|src| pin.source.eq_ignore_ascii_case(src))
+                        })
+                        .map(PackagePin::into_package)
+                        .collect()
+                }),
             };
 
             match result {
                 Ok(mut packages) => {
-                    if mode != AppMode::Search {
+                    if matches!(mode, AppMode::Installed | AppMode::Upgrades) {
                         match backend.list_pins().await {
                             Ok(pins) => Self::annotate_pins(&mut packages, pins),
                             Err(e) => {
@@ -466,31 +1298,59 @@ impl App {
         });
     }
 
+    /// Show the details of `id`, fetching them now unless they are cached.
     pub fn load_detail(&mut self, id: &str) {
+        self.load_detail_after(id, Duration::ZERO);
+    }
+
+    /// Show the details of `id` while the user moves through the list. The
+    /// fetch waits until the cursor has rested for [`DETAIL_DEBOUNCE`], and
+    /// moving again before it finishes cancels it.
+    pub fn browse_detail(&mut self, id: &str) {
+        self.load_detail_after(id, DETAIL_DEBOUNCE);
+    }
+
+    fn load_detail_after(&mut self, id: &str, delay: Duration) {
         // Always increment generation to invalidate any in-flight detail requests.
         self.detail_generation += 1;
+        // Kill the show for the row we left, and every prefetch except one
+        // for the row we arrived at, which takes over as the detail fetch.
+        if let Some(task) = self.detail_task.take() {
+            task.abort();
+        }
+        let adopted = self
+            .prefetch_tasks
+            .remove(id)
+            .filter(|task| !task.is_finished());
+        for (_, task) in self.prefetch_tasks.drain() {
+            task.abort();
+        }
 
-        // Return cached detail immediately if available
+        // Return cached detail immediately if available, from this session
+        // or an earlier one
+        if let Some(saved) = self
+            .disk_cache
+            .detail(id)
+            .filter(|_| !self.detail_cache.contains_key(id))
+            .cloned()
+        {
+            self.detail_cache.insert(id.to_string(), saved);
+        }
         if let Some(cached) = self.detail_cache.get(id) {
+            if !cached.raw.is_empty() {
+                self.raw_cache.insert(id.to_string(), cached.raw.clone());
+            }
             self.detail = Some(cached.clone());
             self.detail_loading = false;
+            self.load_detail_tab(id);
+            self.prefetch_neighbours();
             return;
         }
 
-        // Determine if this package can be looked up via `winget show --exact`.
-        // Truncated IDs, ARP entries, and MSIX sideloads have no manifest so the
-        // call would always fail. Show a local detail stub instead.
-        let is_truncated = id.ends_with('…') || id.ends_with("...");
-        let is_local = id.starts_with("ARP\\") || id.starts_with("MSIX\\");
-        let pkg_source_empty = self
-            .filtered_packages
-            .iter()
-            .find(|p| p.id == id)
-            .is_some_and(|p| p.source.is_empty());
-
-        if is_truncated || is_local || pkg_source_empty {
+        // Packages without a manifest get a local detail stub instead.
+        if self.lacks_manifest(id) {
             if let Some(pkg) = self.filtered_packages.iter().find(|p| p.id == id) {
-                let kind = if is_truncated {
+                let kind = if id.ends_with('…') || id.ends_with("...") {
                     "Package ID was truncated by winget"
                 } else if id.starts_with("ARP\\") {
                     "Installed via Windows registry (Add/Remove Programs)"
@@ -527,27 +1387,223 @@ impl App {
         }
 
         // Pre-populate from Package list data for instant feedback
-        if let Some(pkg) = self.filtered_packages.iter().find(|p| p.id == id) {
-            self.detail = Some(PackageDetail {
-                id: pkg.id.clone(),
+        self.detail = self.list_detail(id);
+        self.detail_loading = true;
+
+        self.load_detail_tab(id);
+
+        // A prefetch already running for this row finishes the job.
+        if adopted.is_some() {
+            self.detail_task = adopted;
+            return;
+        }
+
+        let generation = self.detail_generation;
+        let backend = self.backend.clone();
+        let tx = self.message_tx.clone();
+        let id = id.to_string();
+
+        self.detail_task = Some(tokio::spawn(async move {
+            if !delay.is_zero() {
+                tokio::time::sleep(delay).await;
+            }
+            match backend.show(&id).await {
+                Ok(detail) => {
+                    let _ = tx.send(AppMessage::DetailLoaded {
+                        generation,
+                        detail: Box::new(detail),
+                    });
+                }
+                Err(e) => {
+                    let _ = tx.send(AppMessage::Error(e.to_string()));
+                }
+            }
+        }));
+    }
+
+    /// Stop every detail fetch and prefetch, e.g. when leaving a view.
+    pub fn cancel_detail_loads(&mut self) {
+        if let Some(task) = self.detail_task.take() {
+            task.abort();
+        }
+        for (_, task) in self.prefetch_tasks.drain() {
+            task.abort();
+        }
+    }
+
+    /// Quietly fetch details for the rows above and below the selection so
+    /// stepping onto them shows their details at once. Each prefetch waits
+    /// [`DETAIL_PREFETCH_DELAY`] and gives way when winget calls are queued.
+    fn prefetch_neighbours(&mut self) {
+        let neighbours = [self.selected.checked_sub(1), self.selected.checked_add(1)];
+        for index in neighbours.into_iter().flatten() {
+            let Some(pkg) = self.filtered_packages.get(index) else {
+                continue;
+            };
+            let id = pkg.id.clone();
+            if self.detail_cache.contains_key(&id)
+                || self.disk_cache.detail(&id).is_some()
+                || self.prefetch_tasks.contains_key(&id)
+                || self.lacks_manifest(&id)
+            {
+                continue;
+            }
+            let backend = self.backend.clone();
+            let tx = self.message_tx.clone();
+            let task_id = id.clone();
+            let task = tokio::spawn(async move {
+                tokio::time::sleep(DETAIL_PREFETCH_DELAY).await;
+                let detail = if backend.process_depth().waiting() > 0 {
+                    None
+                } else {
+                    backend.show(&task_id).await.ok().map(Box::new)
+                };
+                let _ = tx.send(AppMessage::DetailPrefetched {
+                    id: task_id,
+                    detail,
+                });
+            });
+            self.prefetch_tasks.insert(id, task);
+        }
+    }
+
+    /// The detail panel's placeholder for `id`, built from its list row.
+    fn list_detail(&self, id: &str) -> Option<PackageDetail> {
+        self.filtered_packages
+            .iter()
+            .find(|p| p.id == id)
+            .map(|pkg| PackageDetail {
+                id: pkg.id.clone(),
                 name: pkg.name.clone(),
                 version: pkg.version.clone(),
                 source: pkg.source.clone(),
                 pin_state: pkg.pin_state.clone(),
                 ..PackageDetail::default()
-            });
+            })
+    }
+
+    /// Merge fetched details over `base` and cache the result.
+    fn complete_detail(
+        &mut self,
+        detail: PackageDetail,
+        base: Option<&PackageDetail>,
+    ) -> PackageDetail {
+        // Merge: if winget show returned empty fields, keep pre-populated data
+        let mut merged = match base {
+            Some(existing) => detail.merge_over(existing),
+            None => detail,
+        };
+        // `winget show` returns the latest manifest version, not the
+        // installed version.  Restore the installed version from the
+        // package list so the detail pane shows the correct value.
+        if let Some(pkg) = self.filtered_packages.iter().find(|p| p.id == merged.id) {
+            if !pkg.version.is_empty() {
+                merged.version = pkg.version.clone();
+            }
+        }
+        Self::ensure_detail_hint(&mut merged);
+        // Cache for instant retrieval on revisit
+        if !merged.id.is_empty() {
+            if !merged.raw.is_empty() {
+                self.raw_cache.insert(merged.id.clone(), merged.raw.clone());
+            }
+            self.detail_cache.insert(merged.id.clone(), merged.clone());
+            self.disk_cache.put_detail(&merged);
+        }
+        merged
+    }
+
+    /// Whether `id` can be looked up via `winget show --exact`. Truncated IDs,
+    /// ARP entries, MSIX sideloads and packages without a source have no
+    /// manifest, so the call would always fail.
+    fn lacks_manifest(&self, id: &str) -> bool {
+        let is_truncated = id.ends_with('…') || id.ends_with("...");
+        let is_local = id.starts_with("ARP\\") || id.starts_with("MSIX\\");
+        let pkg_source_empty = self
+            .filtered_packages
+            .iter()
+            .find(|p| p.id == id)
+            .is_some_and(|p| p.source.is_empty());
+        is_truncated || is_local || pkg_source_empty
+    }
+
+    /// Switch the detail panel to `tab`, fetching its data for the shown package.
+    pub fn select_detail_tab(&mut self, tab: DetailTab) {
+        if tab == self.detail_tab {
+            return;
         }
+        self.detail_tab = tab;
+        self.detail_scroll = 0;
+        if let Some(id) = self.detail.as_ref().map(|d| d.id.clone()) {
+            self.load_detail_tab(&id);
+        }
+    }
+
+    /// Fetch the data behind the active detail tab for `id` unless it is cached
+    /// or already on its way. The other tabs render from the loaded detail.
+    fn load_detail_tab(&mut self, id: &str) {
+        let tab = self.detail_tab;
+        let cached = match tab {
+            DetailTab::Versions => self.versions_cache.contains_key(id),
+            // The detail fetch brings the raw output along
+            DetailTab::Raw => {
+                self.raw_cache.contains_key(id)
+                    || (self.detail_loading && self.detail.as_ref().is_some_and(|d| d.id == id))
+            }
+            DetailTab::Overview | DetailTab::Installer | DetailTab::Dependencies => return,
+        };
+        let in_flight = self
+            .detail_tab_loading
+            .as_ref()
+            .is_some_and(|(t, i)| *t == tab && i == id);
+        if cached || in_flight || id.is_empty() || self.lacks_manifest(id) {
+            return;
+        }
+        self.detail_tab_loading = Some((tab, id.to_string()));
 
-        self.detail_loading = true;
-        let generation = self.detail_generation;
         let backend = self.backend.clone();
         let tx = self.message_tx.clone();
         let id = id.to_string();
+        tokio::spawn(async move {
+            let message = if tab == DetailTab::Versions {
+                backend
+                    .show_versions(&id)
+                    .await
+                    .map(|versions| AppMessage::VersionsLoaded { id, versions })
+            } else {
+                backend
+                    .show(&id)
+                    .await
+                    .map(|detail| AppMessage::RawDetailLoaded {
+                        id,
+                        raw: detail.raw,
+                    })
+            };
+            let _ = tx.send(message.unwrap_or_else(|e| AppMessage::Error(e.to_string())));
+        });
+    }
+
+    /// Open the version picker for `id` and fetch its available versions,
+    /// unless the Versions tab already has them.
+    pub fn open_version_picker(&mut self, id: &str) {
+        self.input_mode = InputMode::VersionInput;
+        self.version_input.clear();
+        self.picker_id = id.to_string();
+        self.picker_versions.clear();
+        self.picker_selected = 0;
+        if let Some(versions) = self.versions_cache.get(id).cloned() {
+            self.set_picker_versions(versions);
+            return;
+        }
+        self.picker_loading = true;
 
+        let backend = self.backend.clone();
+        let tx = self.message_tx.clone();
+        let id = id.to_string();
         tokio::spawn(async move {
-            match backend.show(&id).await {
-                Ok(detail) => {
-                    let _ = tx.send(AppMessage::DetailLoaded { generation, detail });
+            match backend.show_versions(&id).await {
+                Ok(versions) => {
+                    let _ = tx.send(AppMessage::VersionsLoaded { id, versions });
                 }
                 Err(e) => {
                     let _ = tx.send(AppMessage::Error(e.to_string()));
@@ -556,43 +1612,237 @@ impl App {
         });
     }
 
-    pub fn execute_operation(&self, op: Operation) {
+    /// Source names for the `f` filter cycle, falling back to winget's
+    /// defaults until `winget source list` has answered.
+    pub fn source_names(&self) -> Vec<String> {
+        if self.sources.is_empty() {
+            SourceFilter::DEFAULT_SOURCES
+                .iter()
+                .map(|name| name.to_string())
+                .collect()
+        } else {
+            self.sources.iter().map(|s| s.name.clone()).collect()
+        }
+    }
+
+    /// Fetch the configured sources for the Sources view and source filter.
+    pub fn load_sources(&mut self) {
+        self.sources_loading = true;
         let backend = self.backend.clone();
         let tx = self.message_tx.clone();
+        tokio::spawn(async move {
+            match backend.list_sources().await {
+                Ok(sources) => {
+                    let _ = tx.send(AppMessage::SourcesLoaded(sources));
+                }
+                Err(e) => {
+                    let _ = tx.send(AppMessage::SourcesFailed(e.to_string()));
+                }
+            }
+        });
+    }
 
+    /// Run `winget source update` for every listed source in turn, reporting
+    /// each result as it arrives.
+    pub fn update_sources(&mut self) {
+        let names: Vec<String> = self.sources.iter().map(|s| s.name.clone()).collect();
+        self.source_updates = names
+            .iter()
+            .map(|name| (name.clone(), SourceUpdate::Updating))
+            .collect();
+        let backend = self.backend.clone();
+        let tx = self.message_tx.clone();
         tokio::spawn(async move {
-            let result = match &op {
-                Operation::Install { id, version } => backend.install(id, version.as_deref()).await,
-                Operation::Uninstall { id } => backend.uninstall(id).await,
-                Operation::Upgrade { id } => backend.upgrade(id).await,
-                Operation::Pin { id } => backend.pin(id).await,
-                Operation::Unpin { id } => backend.unpin(id).await,
+            for name in names {
+                let result = backend
+                    .update_source(&name)
+                    .await
+                    .map(|_| ())
+                    .map_err(|e| e.to_string());
+                let _ = tx.send(AppMessage::SourceUpdated { name, result });
+            }
+        });
+    }
+
+    /// Show `versions` in the open picker, starting on the latest.
+    fn set_picker_versions(&mut self, versions: Vec<String>) {
+        self.picker_versions = versions;
+        self.picker_loading = false;
+        // Start on the latest version, as installing without one would
+        let latest = self.latest_version().map(str::to_string);
+        self.picker_selected = latest
+            .and_then(|latest| self.filtered_versions().iter().position(|v| *v == latest))
+            .unwrap_or(0);
+    }
+
+    /// Close the version picker and forget its versions.
+    pub fn close_version_picker(&mut self) {
+        self.input_mode = InputMode::Normal;
+        self.version_input.clear();
+        self.picker_versions.clear();
+        self.picker_selected = 0;
+        self.picker_loading = false;
+    }
+
+    /// Picker versions containing the typed text, in their original order.
+    /// Unless it is listed exactly, the typed text itself comes first, so a
+    /// custom version like `1.2` is not replaced by the listed `1.2.3`.
+    pub fn filtered_versions(&self) -> Vec<&str> {
+        let filter = self.version_input.trim();
+        let mut matches: Vec<&str> = self
+            .picker_versions
+            .iter()
+            .map(String::as_str)
+            .filter(|v| v.contains(filter))
+            .collect();
+        if !filter.is_empty() && !matches.is_empty() && !matches.contains(&filter) {
+            matches.insert(0, filter);
+        }
+        matches
+    }
+
+    /// Whether `version` is in the list winget returned, as opposed to typed.
+    pub fn is_listed_version(&self, version: &str) -> bool {
+        self.picker_versions.iter().any(|v| v == version)
+    }
+
+    /// The highest version the picker offers.
+    pub fn latest_version(&self) -> Option<&str> {
+        self.picker_versions
+            .iter()
+            .max_by(|a, b| version_key(a).cmp(&version_key(b)))
+            .map(String::as_str)
+    }
+
+    /// Move the picker highlight by `delta`, clamped to the matching versions.
+    pub fn move_version_selection(&mut self, delta: isize) {
+        let max = self.filtered_versions().len().saturating_sub(1);
+        self.picker_selected = self.picker_selected.saturating_add_signed(delta).min(max);
+    }
+
+    /// The version the picker would install: the highlighted match, else the
+    /// typed custom value, else `None` for the latest.
+    pub fn picked_version(&self) -> Option<String> {
+        if let Some(version) = self.filtered_versions().get(self.picker_selected) {
+            return Some(version.to_string());
+        }
+        let typed = self.version_input.trim();
+        (!typed.is_empty()).then(|| typed.to_string())
+    }
+
+    /// Add `op` to the operation queue and start it if nothing else is running.
+    ///
+    /// All mutations go through the queue so that two winget installers never
+    /// run at once and collide in Windows Installer.
+    pub fn enqueue_operation(&mut self, op: Operation) {
+        self.queue.push(QueueEntry {
+            operation: op,
+            status: QueueStatus::Pending,
+            message: String::new(),
+        });
+        if self.running_op.is_some() {
+            let pending = self.pending_count();
+            let op = &self.queue[self.queue.len() - 1].operation;
+            self.set_status(format!("Queued: {op} ({pending} waiting)"));
+        } else {
+            self.start_next_operation();
+        }
+    }
+
+    /// Number of queue entries that have not started yet.
+    pub fn pending_count(&self) -> usize {
+        self.queue
+            .iter()
+            .filter(|entry| entry.status == QueueStatus::Pending)
+            .count()
+    }
+
+    /// Start the first pending queue entry, if any and if nothing is running.
+    fn start_next_operation(&mut self) {
+        if self.running_op.is_some() {
+            return;
+        }
+        let Some(entry) = self
+            .queue
+            .iter_mut()
+            .find(|entry| entry.status == QueueStatus::Pending)
+        else {
+            return;
+        };
+        entry.status = QueueStatus::Running;
+        let op = entry.operation.clone();
+        self.set_status(format!("{op}..."));
+        self.loading = true;
+        self.start_operation(op);
+    }
+
+    /// Swap the pending entry at `index` with its pending neighbour in
+    /// direction `delta`. Running and finished entries never move.
+    pub fn move_queue_entry(&mut self, index: usize, delta: isize) -> bool {
+        let Some(target) = index.checked_add_signed(delta) else {
+            return false;
+        };
+        let movable = |i: usize| {
+            self.queue
+                .get(i)
+                .is_some_and(|entry| entry.status == QueueStatus::Pending)
+        };
+        if !movable(index) || !movable(target) {
+            return false;
+        }
+        self.queue.swap(index, target);
+        true
+    }
+
+    /// Remove the pending entry at `index`; running and finished entries stay.
+    pub fn remove_queue_entry(&mut self, index: usize) -> Option<QueueEntry> {
+        if self.queue.get(index)?.status != QueueStatus::Pending {
+            return None;
+        }
+        let entry = self.queue.remove(index);
+        self.queue_selected = self.queue_selected.min(self.queue.len().saturating_sub(1));
+        Some(entry)
+    }
+
+    /// Drop done, failed and cancelled entries from the queue.
+    pub fn clear_finished_queue_entries(&mut self) {
+        self.queue.retain(|entry| !entry.status.is_finished());
+        self.queue_selected = self.queue_selected.min(self.queue.len().saturating_sub(1));
+    }
+
+    fn start_operation(&mut self, op: Operation) {
+        self.op_phase = None;
+        self.op_percent = None;
+        self.op_output.clear();
+
+        let backend = self.backend.clone();
+        let tx = self.message_tx.clone();
+        let progress_tx = self.message_tx.clone();
+        let progress = move |update: OperationProgress| {
+            let _ = progress_tx.send(AppMessage::OperationProgress(update));
+        };
+        let (cancel, mut cancel_rx) = watch::channel(false);
+        let operation = op.clone();
+
+        let task = tokio::spawn(async move {
+            // Dropping the backend future on cancellation drops the winget
+            // child process, which is spawned with kill_on_drop.
+            let (result, cancelled) = match &op {
                 Operation::BatchUpgrade { ids } => {
-                    // Execute sequentially to avoid Windows Installer conflicts
-                    let total = ids.len();
-                    let mut failures: Vec<String> = Vec::new();
-                    for (i, id) in ids.iter().enumerate() {
-                        let _ = tx.send(AppMessage::StatusUpdate(format!(
-                            "Upgrading {}/{}: {}...",
-                            i + 1,
-                            total,
-                            id
-                        )));
-                        if let Err(e) = backend.upgrade(id).await {
-                            failures.push(format!("{}: {}", id, e));
+                    Self::run_batch_upgrade(backend.as_ref(), ids, &tx, &progress, &mut cancel_rx)
+                        .await
+                }
+                single => {
+                    tokio::select! {
+                        biased;
+                        () = cancel_requested(&mut cancel_rx) => (
+                            Err(anyhow::anyhow!("stopped before winget finished")),
+                            true,
+                        ),
+                        result = Self::run_single(backend.as_ref(), single, &progress) => {
+                            (result, false)
                         }
                     }
-                    if failures.is_empty() {
-                        Ok(format!("All {} packages upgraded successfully", total))
-                    } else {
-                        Err(anyhow::anyhow!(
-                            "{}/{} succeeded, {} failed: {}",
-                            total - failures.len(),
-                            total,
-                            failures.len(),
-                            failures.join("; ")
-                        ))
-                    }
                 }
             };
 
@@ -601,16 +1851,171 @@ impl App {
                     operation: op,
                     success: true,
                     message: msg,
+                    cancelled,
+                    error_kind: None,
                 },
                 Err(e) => OpResult {
                     operation: op,
                     success: false,
                     message: e.to_string(),
+                    cancelled,
+                    error_kind: e.downcast_ref::<WingetError>().and_then(WingetError::kind),
                 },
             };
 
             let _ = tx.send(AppMessage::OperationComplete(op_result));
         });
+
+        self.running_op = Some(RunningOperation {
+            operation,
+            cancel,
+            task,
+        });
+    }
+
+    async fn run_single(
+        backend: &dyn WingetBackend,
+        op: &Operation,
+        progress: &ProgressFn,
+    ) -> anyhow::Result<String> {
+        let result = match op {
+            Operation::Install {
+                id,
+                version,
+                options,
+            } => {
+                backend
+                    .install(id, version.as_deref(), options, progress)
+                    .await
+            }
+            Operation::Uninstall { id, options } => backend.uninstall(id, options, progress).await,
+            Operation::Upgrade { id } => backend.upgrade(id, progress).await,
+            Operation::Pin { id, pin } => backend.pin(id, pin).await,
+            Operation::Unpin { id } => backend.unpin(id).await,
+            Operation::ResetPins => backend.reset_pins().await,
+            Operation::AddSource { source } => backend.add_source(source).await,
+            Operation::RemoveSource { name } => backend.remove_source(name).await,
+            Operation::ResetSources => backend.reset_sources().await,
+            Operation::BatchUpgrade { .. } => unreachable!("batch upgrades run item by item"),
+        };
+        Ok(result?)
+    }
+
+    /// Upgrade `ids` one after another, stopping before the next item once
+    /// cancellation is requested. Returns the result and whether it was cancelled.
+    async fn run_batch_upgrade(
+        backend: &dyn WingetBackend,
+        ids: &[String],
+        tx: &tokio::sync::mpsc::UnboundedSender<AppMessage>,
+        progress: &ProgressFn,
+        cancel: &mut watch::Receiver<bool>,
+    ) -> (anyhow::Result<String>, bool) {
+        // Execute sequentially to avoid Windows Installer conflicts
+        let total = ids.len();
+        let mut failures: Vec<String> = Vec::new();
+        for (i, id) in ids.iter().enumerate() {
+            if *cancel.borrow() {
+                let summary = Self::batch_cancel_summary(ids, i, false, &failures, progress);
+                return (Err(anyhow::anyhow!(summary)), true);
+            }
+            let _ = tx.send(AppMessage::StatusUpdate(format!(
+                "Upgrading {}/{}: {}...",
+                i + 1,
+                total,
+                id
+            )));
+            progress(OperationProgress::Output(format!("── {id} ──")));
+            tokio::select! {
+                biased;
+                () = cancel_requested(cancel) => {
+                    let summary = Self::batch_cancel_summary(ids, i, true, &failures, progress);
+                    return (Err(anyhow::anyhow!(summary)), true);
+                }
+                result = backend.upgrade(id, progress) => {
+                    if let Err(e) = result {
+                        failures.push(format!("{}: {}", id, e));
+                    }
+                }
+            }
+        }
+        let result = if failures.is_empty() {
+            Ok(format!("All {} packages upgraded successfully", total))
+        } else {
+            Err(anyhow::anyhow!(
+                "{}/{} succeeded, {} failed: {}",
+                total - failures.len(),
+                total,
+                failures.len(),
+                failures.join("; ")
+            ))
+        };
+        (result, false)
+    }
+
+    /// Describe how far a cancelled batch got: the items that finished, the
+    /// one that was interrupted mid-run (if any) and those never started.
+    /// The full lists go to the operation output; the returned summary counts.
+    fn batch_cancel_summary(
+        ids: &[String],
+        stopped_at: usize,
+        interrupted: bool,
+        failures: &[String],
+        progress: &ProgressFn,
+    ) -> String {
+        let finished = &ids[..stopped_at];
+        let not_started = &ids[stopped_at + usize::from(interrupted)..];
+        let upgraded = finished.len() - failures.len();
+
+        if !finished.is_empty() {
+            progress(OperationProgress::Output(format!(
+                "Finished: {}",
+                finished.join(", ")
+            )));
+        }
+        if interrupted {
+            progress(OperationProgress::Output(format!(
+                "Interrupted: {}",
+                ids[stopped_at]
+            )));
+        }
+        if !not_started.is_empty() {
+            progress(OperationProgress::Output(format!(
+                "Not started: {}",
+                not_started.join(", ")
+            )));
+        }
+
+        let mut summary = format!("{upgraded}/{} upgraded", ids.len());
+        if !failures.is_empty() {
+            summary.push_str(&format!(", {} failed", failures.len()));
+        }
+        if interrupted {
+            summary.push_str(&format!(", interrupted {}", ids[stopped_at]));
+        }
+        summary.push_str(&format!(", {} not started", not_started.len()));
+        summary
+    }
+
+    /// Whether an install, uninstall, upgrade or pin task is still in flight.
+    pub fn operation_running(&self) -> bool {
+        self.running_op
+            .as_ref()
+            .is_some_and(|running| !running.task.is_finished())
+    }
+
+    /// Ask the running operation to stop. winget is killed and a batch stops
+    /// before its next item; the task still reports an `OperationComplete`
+    /// describing what happened. Returns `false` when nothing is running.
+    pub fn cancel_operation(&mut self) -> bool {
+        if !self.operation_running() {
+            return false;
+        }
+        if let Some(running) = &self.running_op {
+            let _ = running.cancel.send(true);
+            let status = format!("{} — cancelling...", running.operation);
+            self.set_status(status);
+        }
+        true
     }
 
     /// Export the currently visible package list to a CSV file in the working directory.
@@ -623,6 +2028,7 @@ impl App {
             AppMode::Installed => "winget-installed.csv",
             AppMode::Upgrades => "winget-upgrades.csv",
             AppMode::Search => "winget-search.csv",
+            AppMode::Pins => "winget-pins.csv",
         };
 
         let file = std::fs::File::create(filename)
@@ -664,6 +2070,65 @@ impl App {
         Ok(())
     }
 
+    /// Reflect a successful pin, unpin or pin reset in the list and detail
+    /// panel right away, before the refresh re-reads the pins from winget.
+    fn show_new_pin_state(&mut self, operation: &Operation) {
+        let (id, pin_state) = match operation {
+            Operation::Pin { id, pin } => (id, pin.clone()),
+            Operation::Unpin { id } => (id, PinState::None),
+            Operation::ResetPins => {
+                for pkg in self
+                    .packages
+                    .iter_mut()
+                    .chain(self.filtered_packages.iter_mut())
+                {
+                    pkg.pin_state = PinState::None;
+                }
+                if let Some(detail) = self.detail.as_mut() {
+                    detail.pin_state = PinState::None;
+                }
+                return;
+            }
+            _ => return,
+        };
+        for pkg in self
+            .packages
+            .iter_mut()
+            .chain(self.filtered_packages.iter_mut())
+            .filter(|pkg| &pkg.id == id)
+        {
+            pkg.pin_state = pin_state.clone();
+        }
+        if let Some(detail) = self.detail.as_mut().filter(|detail| &detail.id == id) {
+            detail.pin_state = pin_state;
+        }
+    }
+
+    /// Follow up on failures winget explained with a known exit code:
+    /// remember a pending restart, or offer the obvious next operation.
+    fn react_to_failure(&mut self, result: &OpResult) {
+        match (result.error_kind, &result.operation) {
+            (Some(WingetErrorKind::RebootRequired), _) => self.reboot_pending = true,
+            (Some(WingetErrorKind::BlockedByPin), Operation::Upgrade { id })
+                if self.confirm.is_none() =>
+            {
+                self.confirm = Some(ConfirmDialog {
+                    message: format!("{id} is pinned. Remove the pin so it can be upgraded?"),
+                    operation: Operation::Unpin { id: id.clone() },
+                });
+            }
+            (Some(WingetErrorKind::AlreadyInstalled), Operation::Install { id, .. })
+                if self.confirm.is_none() =>
+            {
+                self.confirm = Some(ConfirmDialog {
+                    message: format!("{id} is already installed. Upgrade it instead?"),
+                    operation: Operation::Upgrade { id: id.clone() },
+                });
+            }
+            _ => {}
+        }
+    }
+
     /// Process all pending background-task messages.
     ///
     /// Returns `true` if at least one message was processed (i.e. app state
@@ -685,6 +2150,11 @@ impl App {
                     // re-anchor the cursor after the list is replaced.
                     let prev_id = self.selected_package().map(|p| p.id.clone());
                     self.packages = packages;
+                    self.stale_list_age = None;
+                    if matches!(self.mode, AppMode::Installed | AppMode::Upgrades) {
+                        let source = self.source_filter.as_arg();
+                        self.disk_cache.put_list(self.mode, source, &self.packages);
+                    }
                     self.apply_filter();
                     // Restore cursor to the same package (if it is still present)
                     // so that pressing 'r' to refresh does not jump the cursor.
@@ -704,6 +2174,11 @@ impl App {
                             if count == 1 { "" } else { "s" }
                         ));
                     }
+                    // Keep the status on the queued operation that is still running
+                    if let Some(running) = &self.running_op {
+                        let status = format!("{}...", running.operation);
+                        self.set_status(status);
+                    }
                     // Auto-load detail for the (restored) selected package
                     if let Some(pkg) = self.selected_package() {
                         let id = pkg.id.clone();
@@ -715,46 +2190,159 @@ impl App {
                     if generation < self.detail_generation {
                         continue;
                     }
-                    // Merge: if winget show returned empty fields, keep pre-populated data
-                    let mut merged = if let Some(existing) = &self.detail {
-                        detail.merge_over(existing)
-                    } else {
-                        detail
-                    };
-                    // `winget show` returns the latest manifest version, not the
-                    // installed version.  Restore the installed version from the
-                    // package list so the detail pane shows the correct value.
-                    if let Some(pkg) = self.filtered_packages.iter().find(|p| p.id == merged.id) {
-                        if !pkg.version.is_empty() {
-                            merged.version = pkg.version.clone();
+                    let base = self.detail.take();
+                    let merged = self.complete_detail(*detail, base.as_ref());
+                    self.detail = Some(merged);
+                    self.detail_loading = false;
+                    self.detail_task = None;
+                    self.prefetch_neighbours();
+                }
+                AppMessage::DetailPrefetched { id, detail } => {
+                    self.prefetch_tasks.remove(&id);
+                    let merged = detail.map(|detail| {
+                        let base = self.list_detail(&id);
+                        self.complete_detail(*detail, base.as_ref())
+                    });
+                    // The user may have stepped onto the row while it was in
+                    // flight, in which case the prefetch was its detail fetch.
+                    let shown = self.detail.as_ref().map(|d| d.id.as_str());
+                    if !self.detail_loading || shown != Some(id.as_str()) {
+                        continue;
+                    }
+                    self.detail_task = None;
+                    match merged {
+                        Some(merged) => {
+                            self.detail = Some(merged);
+                            self.detail_loading = false;
+                            self.prefetch_neighbours();
                         }
+                        // Fetch it for real, reporting any error
+                        None => self.load_detail(&id),
+                    }
+                }
+                AppMessage::VersionsLoaded { id, versions } => {
+                    if matches!(&self.detail_tab_loading, Some((DetailTab::Versions, i)) if *i == id)
+                    {
+                        self.detail_tab_loading = None;
                     }
-                    Self::ensure_detail_hint(&mut merged);
-                    // Cache for instant retrieval on revisit
-                    if !merged.id.is_empty() {
-                        self.detail_cache.insert(merged.id.clone(), merged.clone());
+                    self.versions_cache.insert(id.clone(), versions.clone());
+                    // Discard versions for a picker that was closed or reopened
+                    if self.input_mode != InputMode::VersionInput || id != self.picker_id {
+                        continue;
+                    }
+                    self.set_picker_versions(versions);
+                }
+                AppMessage::RawDetailLoaded { id, raw } => {
+                    if matches!(&self.detail_tab_loading, Some((DetailTab::Raw, i)) if *i == id) {
+                        self.detail_tab_loading = None;
+                    }
+                    self.raw_cache.insert(id, raw);
+                }
+                AppMessage::SourcesLoaded(sources) => {
+                    self.sources_loading = false;
+                    self.sources = sources;
+                    self.sources_selected = self
+                        .sources_selected
+                        .min(self.sources.len().saturating_sub(1));
+                    // A configured or remembered source may have been removed
+                    if let SourceFilter::Named(name) = &self.source_filter {
+                        // winget matches source names case-insensitively
+                        if !self.sources.is_empty()
+                            && !self
+                                .sources
+                                .iter()
+                                .any(|s| s.name.eq_ignore_ascii_case(name))
+                        {
+                            self.set_status(format!(
+                                "Source '{name}' is not configured -- showing all sources"
+                            ));
+                            self.source_filter = SourceFilter::All;
+                            self.loading = true;
+                            self.refresh_view();
+                        }
+                    }
+                }
+                AppMessage::SourcesFailed(msg) => {
+                    // Sources load alongside the first list at startup, so
+                    // only the Sources view reports the failure
+                    self.sources_loading = false;
+                    if self.show_sources {
+                        self.set_status(format!("Error: {msg}"));
+                    }
+                }
+                AppMessage::SourceUpdated { name, result } => {
+                    let update = match result {
+                        Ok(()) => SourceUpdate::Updated,
+                        Err(msg) => SourceUpdate::Failed(msg),
+                    };
+                    self.source_updates.insert(name, update);
+                    if !self
+                        .source_updates
+                        .values()
+                        .any(|u| *u == SourceUpdate::Updating)
+                    {
+                        let failed = self
+                            .source_updates
+                            .values()
+                            .filter(|u| matches!(u, SourceUpdate::Failed(_)))
+                            .count();
+                        self.set_status(if failed == 0 {
+                            "Sources updated".to_string()
+                        } else {
+                            format!("Sources updated -- {failed} failed")
+                        });
                     }
-                    self.detail = Some(merged);
-                    self.detail_loading = false;
                 }
                 AppMessage::OperationComplete(result) => {
                     // Invalidate cache for the affected package(s)
                     match &result.operation {
                         Operation::Install { id, .. }
-                        | Operation::Uninstall { id }
+                        | Operation::Uninstall { id, .. }
                         | Operation::Upgrade { id }
-                        | Operation::Pin { id }
+                        | Operation::Pin { id, .. }
                         | Operation::Unpin { id } => {
                             self.detail_cache.remove(id);
+                            self.disk_cache.invalidate(&[id]);
+                        }
+                        Operation::ResetPins => {
+                            self.detail_cache.clear();
+                            self.disk_cache.clear();
+                        }
+                        Operation::AddSource { .. }
+                        | Operation::RemoveSource { .. }
+                        | Operation::ResetSources => {
+                            if result.success {
+                                self.load_sources();
+                            }
                         }
                         Operation::BatchUpgrade { ids } => {
                             for id in ids {
                                 self.detail_cache.remove(id);
                             }
+                            let ids: Vec<&str> = ids.iter().map(String::as_str).collect();
+                            self.disk_cache.invalidate(&ids);
                             self.selected_packages.clear();
                         }
                     }
-                    let status = if result.success {
+                    self.running_op = None;
+                    self.confirm_cancel = false;
+                    if let Some(entry) = self
+                        .queue
+                        .iter_mut()
+                        .find(|entry| entry.status == QueueStatus::Running)
+                    {
+                        entry.status = if result.cancelled {
+                            QueueStatus::Cancelled
+                        } else if result.success {
+                            QueueStatus::Done
+                        } else {
+                            QueueStatus::Failed
+                        };
+                        entry.message = result.message.trim().to_string();
+                    }
+                    let status = if result.cancelled {
+                        format!("{} — cancelled: {}", result.operation, result.message)
+                    } else if result.success {
                         let detail = result.message.trim();
                         if detail.is_empty() {
                             format!("{} — done", result.operation)
@@ -765,25 +2353,56 @@ impl App {
                         format!("{} — failed: {}", result.operation, result.message)
                     };
                     self.set_status(status.clone());
+                    if result.success {
+                        self.show_new_pin_state(&result.operation);
+                    }
                     self.loading = false;
-                    // Refresh after successful mutations, or after a batch-upgrade
-                    // attempt where some items may still have changed state.
-                    if result.success || matches!(result.operation, Operation::BatchUpgrade { .. })
+                    self.op_phase = None;
+                    self.op_percent = None;
+                    // Refresh after successful mutations, or after a cancelled or
+                    // batch-upgrade attempt where some items may still have changed state.
+                    if result.success
+                        || result.cancelled
+                        || matches!(result.operation, Operation::BatchUpgrade { .. })
                     {
                         self.post_refresh_status = Some(status);
                         self.loading = true;
                         self.refresh_view();
                     }
+                    if !result.cancelled {
+                        self.react_to_failure(&result);
+                    }
+                    // Serialized queue: the next operation only starts once
+                    // this one has fully finished.
+                    self.start_next_operation();
                 }
                 AppMessage::Error(msg) => {
                     self.post_refresh_status = None;
                     self.set_status(format!("Error: {msg}"));
                     self.loading = false;
                     self.detail_loading = false;
+                    self.picker_loading = false;
+                    self.sources_loading = false;
+                    self.detail_tab_loading = None;
                     if let Some(detail) = &mut self.detail {
                         Self::ensure_detail_hint(detail);
                     }
                 }
+                AppMessage::OperationProgress(update) => match update {
+                    OperationProgress::Output(line) => {
+                        if self.op_output.len() == OP_OUTPUT_LIMIT {
+                            self.op_output.pop_front();
+                        }
+                        self.op_output.push_back(line);
+                    }
+                    OperationProgress::Phase(phase) => {
+                        self.op_phase = Some(phase);
+                        self.op_percent = None;
+                    }
+                    OperationProgress::Percent(percent) => {
+                        self.op_percent = Some(percent);
+                    }
+                },
                 AppMessage::StatusUpdate(msg) => {
                     self.set_status(msg);
                 }
@@ -793,7 +2412,15 @@ impl App {
     }
 }
 
-fn csv_escape(s: &str) -> String {
+/// Resolves once the user asks to cancel the running operation.
+async fn cancel_requested(cancel: &mut watch::Receiver<bool>) {
+    if cancel.wait_for(|&requested| requested).await.is_err() {
+        // The App dropped its handle without cancelling; never resolve.
+        std::future::pending::<()>().await;
+    }
+}
+
+pub fn csv_escape(s: &str) -> String {
     if s.contains(',') || s.contains('"') || s.contains('\n') || s.contains('\r') {
         format!("\"{}\"", s.replace('"', "\"\""))
     } else {
@@ -805,22 +2432,35 @@ fn csv_escape(s: &str) -> String {
 mod tests {
     use std::sync::Arc;
 
-    use anyhow::Result;
     use async_trait::async_trait;
 
     use super::*;
-    use crate::backend::WingetBackend;
-    use crate::models::{Package, PackageDetail, PackagePin, PinState, Source};
+    use crate::backend::{ProgressFn, WingetBackend};
+    use crate::error::Result;
+    use crate::models::{InstallScope, Package, PackageDetail, PackagePin, Source};
 
     /// Minimal backend that records `show` calls
     struct SpyBackend {
         show_calls: std::sync::Mutex<Vec<String>>,
+        /// Exit code `install` fails with, if set
+        install_exit_code: Option<i32>,
+        depth: std::sync::Mutex<crate::models::ProcessDepth>,
     }
 
     impl SpyBackend {
         fn new() -> Arc<Self> {
             Arc::new(Self {
                 show_calls: std::sync::Mutex::new(Vec::new()),
+                install_exit_code: None,
+                depth: Default::default(),
+            })
+        }
+
+        fn failing_install(code: u32) -> Arc<Self> {
+            Arc::new(Self {
+                show_calls: std::sync::Mutex::new(Vec::new()),
+                install_exit_code: Some(code as i32),
+                depth: Default::default(),
             })
         }
 
@@ -831,7 +2471,7 @@ mod tests {
 
     #[async_trait]
     impl WingetBackend for SpyBackend {
-        async fn search(&self, _: &str, _: Option<&str>) -> Result<Vec<Package>> {
+        async fn search(&self, _: &SearchRequest, _: Option<&str>) -> Result<Vec<Package>> {
             Ok(vec![])
         }
         async fn list_installed(&self, _: Option<&str>) -> Result<Vec<Package>> {
@@ -842,42 +2482,235 @@ mod tests {
         }
         async fn show(&self, id: &str) -> Result<PackageDetail> {
             self.show_calls.lock().unwrap().push(id.to_string());
-            Ok(PackageDetail::default())
+            Ok(PackageDetail {
+                raw: format!("Found Example [{id}]\nVersion: 1.10.0"),
+                ..PackageDetail::default()
+            })
         }
-        async fn install(&self, _: &str, _: Option<&str>) -> Result<String> {
-            Ok(String::new())
+        async fn show_versions(&self, _: &str) -> Result<Vec<String>> {
+            Ok(vec!["1.10.0".into(), "1.9.2".into(), "1.2.0".into()])
+        }
+        async fn install(
+            &self,
+            _: &str,
+            _: Option<&str>,
+            _: &InstallOptions,
+            _: &ProgressFn,
+        ) -> Result<String> {
+            match self.install_exit_code {
+                Some(code) => Err(WingetError::failed(Some(code), "")),
+                None => Ok(String::new()),
+            }
         }
-        async fn uninstall(&self, _: &str) -> Result<String> {
+        async fn uninstall(&self, _: &str, _: &UninstallOptions, _: &ProgressFn) -> Result<String> {
             Ok(String::new())
         }
-        async fn upgrade(&self, _: &str) -> Result<String> {
+        async fn upgrade(&self, _: &str, _: &ProgressFn) -> Result<String> {
             Ok(String::new())
         }
         async fn list_pins(&self) -> Result<Vec<PackagePin>> {
-            Ok(vec![])
+            Ok(vec![
+                PackagePin {
+                    id: "Git.Git".to_string(),
+                    name: "Git".to_string(),
+                    pinned_version: "2.45.*".to_string(),
+                    source: "winget".to_string(),
+                    pin_state: PinState::Gating("2.45.*".to_string()),
+                },
+                PackagePin {
+                    id: "Contoso.Store".to_string(),
+                    source: "msstore".to_string(),
+                    pin_state: PinState::Blocking,
+                    ..PackagePin::default()
+                },
+            ])
         }
-        async fn pin(&self, _: &str) -> Result<String> {
+        async fn pin(&self, _: &str, _: &PinState) -> Result<String> {
             Ok(String::new())
         }
         async fn unpin(&self, _: &str) -> Result<String> {
             Ok(String::new())
         }
-        async fn list_sources(&self) -> Result<Vec<Source>> {
-            Ok(vec![])
+        async fn reset_pins(&self) -> Result<String> {
+            Ok(String::new())
         }
-    }
-
-    fn make_app(backend: Arc<dyn WingetBackend>) -> App {
-        App::new(backend, crate::config::Config::default())
-    }
-
-    fn pkg(id: &str) -> Package {
+        async fn add_source(&self, _: &Source) -> Result<String> {
+            Ok(String::new())
+        }
+        async fn remove_source(&self, _: &str) -> Result<String> {
+            Ok(String::new())
+        }
+        async fn reset_sources(&self) -> Result<String> {
+            Ok(String::new())
+        }
+        async fn update_source(&self, name: &str) -> Result<String> {
+            if name == "contoso" {
+                Err(WingetError::failed(Some(1), "source unreachable"))
+            } else {
+                Ok(String::new())
+            }
+        }
+        async fn list_sources(&self) -> Result<Vec<Source>> {
+            Ok(["winget", "contoso"]
+                .map(|name| Source {
+                    name: name.to_string(),
+                    ..Source::default()
+                })
+                .to_vec())
+        }
+        fn process_depth(&self) -> crate::models::ProcessDepth {
+            *self.depth.lock().unwrap()
+        }
+    }
+
+    /// Backend whose install and upgrade of `hang_on` never finish, so tests
+    /// can cancel an operation while winget is "running".
+    struct HangingBackend {
+        hang_on: String,
+        started: std::sync::Mutex<Vec<String>>,
+        /// Ids whose hanging call was dropped before it finished
+        cancelled: std::sync::Mutex<Vec<String>>,
+    }
+
+    impl HangingBackend {
+        fn new(hang_on: &str) -> Arc<Self> {
+            Arc::new(Self {
+                hang_on: hang_on.to_string(),
+                started: std::sync::Mutex::new(Vec::new()),
+                cancelled: std::sync::Mutex::new(Vec::new()),
+            })
+        }
+
+        fn started(&self) -> Vec<String> {
+            self.started.lock().unwrap().clone()
+        }
+
+        fn cancelled(&self) -> Vec<String> {
+            self.cancelled.lock().unwrap().clone()
+        }
+
+        async fn run(&self, id: &str) -> Result<String> {
+            self.started.lock().unwrap().push(id.to_string());
+            if id == self.hang_on {
+                let _cancel = CancelGuard(&self.cancelled, id.to_string());
+                std::future::pending::<()>().await;
+            }
+            Ok(String::new())
+        }
+    }
+
+    /// Records its id when dropped, i.e. when the call holding it is cancelled.
+    struct CancelGuard<'a>(&'a std::sync::Mutex<Vec<String>>, String);
+
+    impl Drop for CancelGuard<'_> {
+        fn drop(&mut self) {
+            self.0.lock().unwrap().push(self.1.clone());
+        }
+    }
+
+    #[async_trait]
+    impl WingetBackend for HangingBackend {
+        async fn search(&self, _: &SearchRequest, _: Option<&str>) -> Result<Vec<Package>> {
+            Ok(vec![])
+        }
+        async fn list_installed(&self, _: Option<&str>) -> Result<Vec<Package>> {
+            Ok(vec![])
+        }
+        async fn list_upgrades(&self, _: Option<&str>) -> Result<Vec<Package>> {
+            Ok(vec![])
+        }
+        async fn show(&self, id: &str) -> Result<PackageDetail> {
+            self.run(id).await.map(|_| PackageDetail::default())
+        }
+        async fn show_versions(&self, _: &str) -> Result<Vec<String>> {
+            Ok(vec![])
+        }
+        async fn install(
+            &self,
+            id: &str,
+            _: Option<&str>,
+            _: &InstallOptions,
+            _: &ProgressFn,
+        ) -> Result<String> {
+            self.run(id).await
+        }
+        async fn uninstall(
+            &self,
+            id: &str,
+            _: &UninstallOptions,
+            _: &ProgressFn,
+        ) -> Result<String> {
+            self.run(id).await
+        }
+        async fn upgrade(&self, id: &str, _: &ProgressFn) -> Result<String> {
+            self.run(id).await
+        }
+        async fn list_pins(&self) -> Result<Vec<PackagePin>> {
+            Ok(vec![])
+        }
+        async fn pin(&self, _: &str, _: &PinState) -> Result<String> {
+            Ok(String::new())
+        }
+        async fn unpin(&self, _: &str) -> Result<String> {
+            Ok(String::new())
+        }
+        async fn reset_pins(&self) -> Result<String> {
+            Ok(String::new())
+        }
+        async fn add_source(&self, _: &Source) -> Result<String> {
+            Ok(String::new())
+        }
+        async fn remove_source(&self, _: &str) -> Result<String> {
+            Ok(String::new())
+        }
+        async fn reset_sources(&self) -> Result<String> {
+            Ok(String::new())
+        }
+        async fn update_source(&self, _: &str) -> Result<String> {
+            Ok(String::new())
+        }
+        async fn list_sources(&self) -> Result<Vec<Source>> {
+            Ok(vec![])
+        }
+    }
+
+    /// Wait (bounded) until `ready` holds, yielding to the spawned task.
+    async fn wait_until(ready: impl Fn() -> bool) {
+        for _ in 0..500 {
+            if ready() {
+                return;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(2)).await;
+        }
+        panic!("condition was not reached in time");
+    }
+
+    /// Receive the next `OperationComplete`, skipping progress and status messages.
+    async fn next_completion(app: &mut App) -> OpResult {
+        let wait = async {
+            loop {
+                if let Some(AppMessage::OperationComplete(result)) = app.message_rx.recv().await {
+                    return result;
+                }
+            }
+        };
+        tokio::time::timeout(std::time::Duration::from_secs(5), wait)
+            .await
+            .expect("operation should report completion")
+    }
+
+    fn make_app(backend: Arc<dyn WingetBackend>) -> App {
+        App::new(backend, crate::config::Config::default())
+    }
+
+    fn pkg(id: &str) -> Package {
         Package {
             id: id.to_string(),
             name: id.to_string(),
             version: "1.0".to_string(),
             source: "winget".to_string(),
             available_version: String::new(),
+            match_reason: String::new(),
             pin_state: PinState::None,
         }
     }
@@ -1021,14 +2854,16 @@ mod tests {
     fn app_mode_cycle_forward() {
         assert_eq!(AppMode::Search.cycle(), AppMode::Installed);
         assert_eq!(AppMode::Installed.cycle(), AppMode::Upgrades);
-        assert_eq!(AppMode::Upgrades.cycle(), AppMode::Search);
+        assert_eq!(AppMode::Upgrades.cycle(), AppMode::Pins);
+        assert_eq!(AppMode::Pins.cycle(), AppMode::Search);
     }
 
     #[test]
     fn app_mode_cycle_back() {
-        assert_eq!(AppMode::Search.cycle_back(), AppMode::Upgrades);
+        assert_eq!(AppMode::Search.cycle_back(), AppMode::Pins);
         assert_eq!(AppMode::Installed.cycle_back(), AppMode::Search);
         assert_eq!(AppMode::Upgrades.cycle_back(), AppMode::Installed);
+        assert_eq!(AppMode::Pins.cycle_back(), AppMode::Upgrades);
     }
 
     #[test]
@@ -1036,6 +2871,7 @@ mod tests {
         assert_eq!(AppMode::Search.label(), "Search");
         assert_eq!(AppMode::Installed.label(), "Installed");
         assert_eq!(AppMode::Upgrades.label(), "Upgrades");
+        assert_eq!(AppMode::Pins.label(), "Pins");
     }
 
     // ── move_selection ────────────────────────────────────────────────────────
@@ -1048,6 +2884,7 @@ mod tests {
                 version: "1.0".to_string(),
                 source: "winget".to_string(),
                 available_version: String::new(),
+                match_reason: String::new(),
                 pin_state: PinState::None,
             })
             .collect()
@@ -1226,6 +3063,7 @@ mod tests {
             version: version.to_string(),
             source: "winget".to_string(),
             available_version: String::new(),
+            match_reason: String::new(),
             pin_state: PinState::None,
         }
     }
@@ -1307,13 +3145,14 @@ mod tests {
     fn apply_filter_backfills_source_when_server_omits_it() {
         let spy = SpyBackend::new();
         let mut app = make_app(spy as Arc<dyn WingetBackend>);
-        app.source_filter = SourceFilter::Winget;
+        app.source_filter = SourceFilter::named("winget");
         app.packages = vec![Package {
             name: "Pkg One".to_string(),
             id: "Pkg.One".to_string(),
             version: "1.0.0".to_string(),
             source: String::new(),
             available_version: String::new(),
+            match_reason: String::new(),
             pin_state: PinState::None,
         }];
 
@@ -1558,6 +3397,27 @@ mod tests {
 
     // ── annotate_pins ──────────────────────────────────────────────────────
 
+    #[tokio::test]
+    async fn config_warnings_outlast_the_first_list_load() {
+        let mut app = make_app(SpyBackend::new() as Arc<dyn WingetBackend>);
+        app.report_config_warnings(&[
+            ConfigWarning {
+                file: "config.toml".to_string(),
+                line: 2,
+                message: "unknown key 'x'".to_string(),
+            },
+            ConfigWarning {
+                file: "config.toml".to_string(),
+                line: 5,
+                message: "unknown key 'y'".to_string(),
+            },
+        ]);
+        let expected = "config.toml line 2: unknown key 'x' (+1 more; see --check-config)";
+        assert_eq!(app.status_message, expected);
+        deliver_packages(&mut app, vec![pkg("A")]);
+        assert_eq!(app.status_message, expected);
+    }
+
     #[test]
     fn annotate_pins_applies_matching_pin_state() {
         let mut packages = vec![
@@ -1567,6 +3427,7 @@ mod tests {
         let pins = vec![PackagePin {
             id: "Google.Chrome".to_string(),
             pin_state: PinState::Blocking,
+            ..PackagePin::default()
         }];
         App::annotate_pins(&mut packages, pins);
         assert_eq!(packages[0].pin_state, PinState::Blocking);
@@ -1579,6 +3440,7 @@ mod tests {
         let pins = vec![PackagePin {
             id: "NoSuch.Id".to_string(),
             pin_state: PinState::Pinned,
+            ..PackagePin::default()
         }];
         App::annotate_pins(&mut packages, pins);
         assert_eq!(packages[0].pin_state, PinState::None);
@@ -1703,6 +3565,8 @@ mod tests {
                 },
                 success: true,
                 message: String::new(),
+                cancelled: false,
+                error_kind: None,
             }))
             .unwrap();
         app.process_messages();
@@ -1721,9 +3585,12 @@ mod tests {
                 operation: Operation::Install {
                     id: "7zip.7zip".to_string(),
                     version: None,
+                    options: InstallOptions::default(),
                 },
                 success: false,
                 message: "Access denied".to_string(),
+                cancelled: false,
+                error_kind: None,
             }))
             .unwrap();
         app.process_messages();
@@ -1753,9 +3620,12 @@ mod tests {
             .send(AppMessage::OperationComplete(OpResult {
                 operation: Operation::Pin {
                     id: "7zip.7zip".to_string(),
+                    pin: PinState::Blocking,
                 },
                 success: true,
                 message: "Pin added successfully".to_string(),
+                cancelled: false,
+                error_kind: None,
             }))
             .unwrap();
         app.process_messages();
@@ -1793,6 +3663,8 @@ mod tests {
                 },
                 success: true,
                 message: String::new(),
+                cancelled: false,
+                error_kind: None,
             }))
             .unwrap();
         app.process_messages();
@@ -1832,6 +3704,79 @@ mod tests {
         assert_eq!(app.status_message, "hello");
     }
 
+    #[test]
+    fn process_messages_operation_progress_updates_phase_and_percent() {
+        let spy = SpyBackend::new();
+        let mut app = make_app(spy as Arc<dyn WingetBackend>);
+        for update in [
+            OperationProgress::Phase("Downloading".to_string()),
+            OperationProgress::Percent(45),
+        ] {
+            app.message_tx
+                .send(AppMessage::OperationProgress(update))
+                .unwrap();
+        }
+        app.process_messages();
+        assert_eq!(
+            app.operation_progress_label().as_deref(),
+            Some("Downloading 45%")
+        );
+
+        app.message_tx
+            .send(AppMessage::OperationProgress(OperationProgress::Phase(
+                "Installing".to_string(),
+            )))
+            .unwrap();
+        app.process_messages();
+        assert_eq!(
+            app.operation_progress_label().as_deref(),
+            Some("Installing"),
+            "a new phase resets the percentage"
+        );
+    }
+
+    #[test]
+    fn process_messages_operation_output_is_bounded() {
+        let spy = SpyBackend::new();
+        let mut app = make_app(spy as Arc<dyn WingetBackend>);
+        for i in 0..OP_OUTPUT_LIMIT + 5 {
+            app.message_tx
+                .send(AppMessage::OperationProgress(OperationProgress::Output(
+                    format!("line {i}"),
+                )))
+                .unwrap();
+        }
+        app.process_messages();
+        assert_eq!(app.op_output.len(), OP_OUTPUT_LIMIT);
+        assert_eq!(app.op_output.front().map(String::as_str), Some("line 5"));
+    }
+
+    #[tokio::test]
+    async fn operation_complete_clears_progress_but_keeps_output() {
+        let spy = SpyBackend::new();
+        let mut app = make_app(spy as Arc<dyn WingetBackend>);
+        app.op_phase = Some("Installing".to_string());
+        app.op_percent = Some(80);
+        app.op_output
+            .push_back("Successfully installed".to_string());
+        app.message_tx
+            .send(AppMessage::OperationComplete(OpResult {
+                operation: Operation::Install {
+                    id: "Git.Git".into(),
+                    version: None,
+                    options: InstallOptions::default(),
+                },
+                success: true,
+                message: String::new(),
+                cancelled: false,
+                error_kind: None,
+            }))
+            .unwrap();
+        app.process_messages();
+        assert!(app.operation_progress_label().is_none());
+        assert_eq!(app.op_output.len(), 1);
+    }
+
     #[tokio::test]
     async fn process_messages_batch_upgrade_completion_clears_multi_select() {
         let spy = SpyBackend::new();
@@ -1844,6 +3789,8 @@ mod tests {
                 },
                 success: true,
                 message: "done".into(),
+                cancelled: false,
+                error_kind: None,
             }))
             .unwrap();
 
@@ -1937,6 +3884,7 @@ mod tests {
             version: "1.0".to_string(),
             source: "winget".to_string(),
             available_version: String::new(),
+            match_reason: String::new(),
             pin_state: PinState::None,
         }];
 
@@ -1993,7 +3941,7 @@ mod tests {
         app.message_tx
             .send(AppMessage::DetailLoaded {
                 generation: 1,
-                detail,
+                detail: Box::new(detail),
             })
             .unwrap();
         app.process_messages();
@@ -2017,7 +3965,7 @@ mod tests {
         app.message_tx
             .send(AppMessage::DetailLoaded {
                 generation: 1,
-                detail: PackageDetail::default(),
+                detail: Box::default(),
             })
             .unwrap();
         app.process_messages();
@@ -2045,6 +3993,7 @@ mod tests {
             version: "0.96.0".to_string(),
             source: "winget".to_string(),
             available_version: "0.98.1".to_string(),
+            match_reason: String::new(),
             pin_state: PinState::None,
         }];
         // winget show returns the latest manifest version 0.98.1
@@ -2058,7 +4007,7 @@ mod tests {
         app.message_tx
             .send(AppMessage::DetailLoaded {
                 generation: 1,
-                detail,
+                detail: Box::new(detail),
             })
             .unwrap();
         app.process_messages();
@@ -2082,7 +4031,7 @@ mod tests {
         app.message_tx
             .send(AppMessage::DetailLoaded {
                 generation: 2,
-                detail,
+                detail: Box::new(detail),
             })
             .unwrap();
         app.process_messages();
@@ -2132,17 +4081,240 @@ mod tests {
         assert_eq!(app.sort_field, crate::models::SortField::None);
     }
 
+    #[test]
+    fn cycle_sort_offers_match_order_in_search_view() {
+        use crate::models::{SortDir, SortField};
+        let spy = SpyBackend::new();
+        let mut app = make_app(spy as Arc<dyn WingetBackend>);
+        app.mode = AppMode::Search;
+        app.sort_field = SortField::Version;
+        app.sort_dir = SortDir::Desc;
+        app.cycle_sort();
+        assert_eq!(app.sort_field, SortField::Relevance);
+        assert_eq!(app.sort_dir, SortDir::Asc);
+        assert_eq!(app.status_message, "Sort: Match ↑");
+        app.cycle_sort();
+        assert_eq!(app.sort_dir, SortDir::Desc);
+        app.cycle_sort();
+        assert_eq!(app.sort_field, SortField::None);
+    }
+
+    #[test]
+    fn relevance_sort_ranks_exact_matches_before_tag_matches() {
+        let spy = SpyBackend::new();
+        let mut app = make_app(spy as Arc<dyn WingetBackend>);
+        app.mode = AppMode::Search;
+        app.search_query = "json".to_string();
+        let with_reason = |id: &str, reason: &str| Package {
+            match_reason: reason.to_string(),
+            ..pkg(id)
+        };
+        app.packages = vec![
+            with_reason("Example.Fx", "Tag: json"),
+            with_reason("Example.JsonViewer", ""),
+            with_reason("Example.Jq", "Tag: json"),
+            with_reason("Json", ""),
+        ];
+        app.sort_field = crate::models::SortField::Relevance;
+        app.apply_filter();
+        let ids: Vec<&str> = app
+            .filtered_packages
+            .iter()
+            .map(|p| p.id.as_str())
+            .collect();
+        assert_eq!(
+            ids,
+            vec!["Json", "Example.JsonViewer", "Example.Fx", "Example.Jq"]
+        );
+        assert!(app.shows_match_column());
+
+        app.mode = AppMode::Installed;
+        assert!(!app.shows_match_column());
+    }
+
+    // ── disk cache ────────────────────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn cached_list_is_shown_until_winget_revalidates_it() {
+        let spy = SpyBackend::new();
+        let mut cache = DiskCache::default();
+        cache.put_list(AppMode::Installed, None, &[pkg("Old.Pkg")]);
+        let mut app = make_app(spy as Arc<dyn WingetBackend>).with_disk_cache(cache);
+
+        app.show_cached_list();
+        assert_eq!(app.filtered_packages[0].id, "Old.Pkg");
+        assert!(app.stale_list_age.is_some());
+
+        deliver_packages(&mut app, vec![pkg("New.Pkg")]);
+        assert_eq!(app.filtered_packages[0].id, "New.Pkg");
+        assert!(app.stale_list_age.is_none());
+        let (saved, _) = app.disk_cache.list(AppMode::Installed, None).unwrap();
+        assert_eq!(saved[0].id, "New.Pkg");
+    }
+
+    #[test]
+    fn search_view_has_no_cached_list() {
+        let spy = SpyBackend::new();
+        let mut cache = DiskCache::default();
+        cache.put_list(AppMode::Installed, None, &[pkg("Old.Pkg")]);
+        let mut app = make_app(spy as Arc<dyn WingetBackend>).with_disk_cache(cache);
+        app.mode = AppMode::Search;
+        app.show_cached_list();
+        assert!(app.filtered_packages.is_empty());
+        assert!(app.stale_list_age.is_none());
+    }
+
+    #[tokio::test]
+    async fn saved_details_are_shown_without_winget_show() {
+        let spy = SpyBackend::new();
+        let mut cache = DiskCache::default();
+        cache.put_detail(&PackageDetail {
+            id: "Git.Git".to_string(),
+            publisher: "The Git Team".to_string(),
+            ..PackageDetail::default()
+        });
+        let mut app = make_app(spy.clone() as Arc<dyn WingetBackend>).with_disk_cache(cache);
+        app.load_detail("Git.Git");
+        assert!(!app.detail_loading);
+        assert_eq!(app.detail.as_ref().unwrap().publisher, "The Git Team");
+        for _ in 0..10 {
+            tokio::task::yield_now().await;
+        }
+        assert!(spy.show_calls().is_empty());
+    }
+
+    #[tokio::test]
+    async fn completed_operation_invalidates_the_disk_cache() {
+        let spy = SpyBackend::new();
+        let mut cache = DiskCache::default();
+        cache.put_list(AppMode::Upgrades, None, &[pkg("Google.Chrome")]);
+        cache.put_detail(&PackageDetail {
+            id: "Google.Chrome".to_string(),
+            ..PackageDetail::default()
+        });
+        let mut app = make_app(spy as Arc<dyn WingetBackend>).with_disk_cache(cache);
+        app.message_tx
+            .send(AppMessage::OperationComplete(OpResult {
+                operation: Operation::Upgrade {
+                    id: "Google.Chrome".to_string(),
+                },
+                success: true,
+                message: String::new(),
+                cancelled: false,
+                error_kind: None,
+            }))
+            .unwrap();
+        app.process_messages();
+        assert!(app.disk_cache.detail("Google.Chrome").is_none());
+        assert!(app.disk_cache.list(AppMode::Upgrades, None).is_none());
+    }
+
+    // ── detail debounce and prefetch ──────────────────────────────────────────
+
+    /// Wait for background tasks, draining messages, until `done` or 2 seconds.
+    async fn settle(app: &mut App, done: impl Fn(&App) -> bool) {
+        for _ in 0..200 {
+            app.process_messages();
+            if done(app) {
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    }
+
+    #[tokio::test]
+    async fn browsing_fetches_only_the_row_the_cursor_rests_on() {
+        let spy = SpyBackend::new();
+        let mut app = make_app(spy.clone() as Arc<dyn WingetBackend>);
+        app.browse_detail("A.One");
+        app.browse_detail("B.Two");
+        app.browse_detail("C.Three");
+        assert!(app.detail_loading);
+        settle(&mut app, |app| !app.detail_loading).await;
+        assert_eq!(spy.show_calls(), vec!["C.Three".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn leaving_a_row_cancels_its_show() {
+        let backend = HangingBackend::new("Slow.Pkg");
+        let mut app = make_app(backend.clone() as Arc<dyn WingetBackend>);
+        app.load_detail("Slow.Pkg");
+        for _ in 0..10 {
+            tokio::task::yield_now().await;
+        }
+        assert_eq!(backend.started(), vec!["Slow.Pkg".to_string()]);
+
+        app.browse_detail("Fast.Pkg");
+        for _ in 0..10 {
+            tokio::task::yield_now().await;
+        }
+        assert_eq!(backend.cancelled(), vec!["Slow.Pkg".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn neighbours_are_prefetched_once_the_selection_loads() {
+        let spy = SpyBackend::new();
+        let mut app = make_app(spy.clone() as Arc<dyn WingetBackend>);
+        app.packages = vec![pkg("A.One"), pkg("B.Two"), pkg("C.Three"), pkg("D.Four")];
+        app.filtered_packages = app.packages.clone();
+        app.selected = 1;
+        app.load_detail("B.Two");
+        settle(&mut app, |app| {
+            app.detail_cache.contains_key("A.One") && app.detail_cache.contains_key("C.Three")
+        })
+        .await;
+        assert!(app.detail_cache.contains_key("A.One"));
+        assert!(app.detail_cache.contains_key("C.Three"));
+        assert!(!spy.show_calls().contains(&"D.Four".to_string()));
+
+        // Stepping onto a prefetched row shows it without another winget call
+        let calls = spy.show_calls().len();
+        app.selected = 2;
+        app.browse_detail("C.Three");
+        assert!(!app.detail_loading);
+        assert_eq!(app.detail.as_ref().unwrap().id, "C.Three");
+        assert_eq!(spy.show_calls().len(), calls);
+    }
+
+    // ── process_depth_label ───────────────────────────────────────────────────
+
+    #[test]
+    fn process_depth_label_reports_concurrent_and_waiting_processes() {
+        use crate::models::ProcessDepth;
+        let spy = SpyBackend::new();
+        let app = make_app(spy.clone() as Arc<dyn WingetBackend>);
+        assert_eq!(app.process_depth_label(), None);
+
+        *spy.depth.lock().unwrap() = ProcessDepth {
+            reads_running: 1,
+            ..ProcessDepth::default()
+        };
+        assert_eq!(app.process_depth_label(), None, "one process is the norm");
+
+        *spy.depth.lock().unwrap() = ProcessDepth {
+            reads_running: 3,
+            reads_waiting: 4,
+            writes_running: 1,
+            writes_waiting: 0,
+        };
+        assert_eq!(
+            app.process_depth_label().as_deref(),
+            Some("4 winget · 4 waiting")
+        );
+    }
+
     // ── scroll_detail ─────────────────────────────────────────────────────────
 
     #[test]
     fn scroll_detail_forward_clamps_at_max() {
         let spy = SpyBackend::new();
         let mut app = make_app(spy as Arc<dyn WingetBackend>);
+        // 10 rows minus borders and the tab strip leave 6 visible lines
         app.layout.detail_panel.height = 10;
         app.detail_content_lines = 20;
         app.detail_scroll = 0;
         app.scroll_detail(100);
-        assert_eq!(app.detail_scroll, 13);
+        assert_eq!(app.detail_scroll, 14);
     }
 
     #[test]
@@ -2183,7 +4355,7 @@ mod tests {
         app.message_tx
             .send(AppMessage::DetailLoaded {
                 generation: 1,
-                detail,
+                detail: Box::new(detail),
             })
             .unwrap();
         app.process_messages();
@@ -2204,6 +4376,7 @@ mod tests {
             version: "2.43.0".to_string(),
             source: String::new(),
             available_version: String::new(),
+            match_reason: String::new(),
             pin_state: PinState::None,
         }];
 
@@ -2240,6 +4413,7 @@ mod tests {
             version: "1.0.0.0".to_string(),
             source: String::new(),
             available_version: String::new(),
+            match_reason: String::new(),
             pin_state: PinState::None,
         }];
 
@@ -2273,6 +4447,7 @@ mod tests {
             version: "2024.01.01".to_string(),
             source: "winget".to_string(),
             available_version: String::new(),
+            match_reason: String::new(),
             pin_state: PinState::None,
         }];
 
@@ -2314,6 +4489,7 @@ mod tests {
             version: "3.0".to_string(),
             source: String::new(),
             available_version: String::new(),
+            match_reason: String::new(),
             pin_state: PinState::None,
         }];
 
@@ -2342,6 +4518,7 @@ mod tests {
             version: "1.0".to_string(),
             source: String::new(),
             available_version: String::new(),
+            match_reason: String::new(),
             pin_state: PinState::None,
         }];
 
@@ -2376,6 +4553,8 @@ mod tests {
                 },
                 success: true,
                 message: "Successfully upgraded".to_string(),
+                cancelled: false,
+                error_kind: None,
             }))
             .unwrap();
         app.process_messages();
@@ -2402,9 +4581,12 @@ mod tests {
             .send(AppMessage::OperationComplete(OpResult {
                 operation: Operation::Pin {
                     id: "Microsoft.VisualStudioCode".to_string(),
+                    pin: PinState::Blocking,
                 },
                 success: true,
                 message: "Pin added".to_string(),
+                cancelled: false,
+                error_kind: None,
             }))
             .unwrap();
         app.process_messages();
@@ -2425,9 +4607,12 @@ mod tests {
             .send(AppMessage::OperationComplete(OpResult {
                 operation: Operation::Uninstall {
                     id: "Pkg.ToRemove".to_string(),
+                    options: UninstallOptions::default(),
                 },
                 success: false,
                 message: "Access is denied".to_string(),
+                cancelled: false,
+                error_kind: None,
             }))
             .unwrap();
         app.process_messages();
@@ -2461,6 +4646,771 @@ mod tests {
         assert!(!app.detail_loading);
     }
 
+    // ── cancellation ──────────────────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn cancel_operation_stops_a_running_install() {
+        let backend = HangingBackend::new("Git.Git");
+        let mut app = make_app(backend.clone() as Arc<dyn WingetBackend>);
+        app.enqueue_operation(Operation::Install {
+            id: "Git.Git".into(),
+            version: None,
+            options: InstallOptions::default(),
+        });
+        wait_until(|| backend.started().len() == 1).await;
+        assert!(app.operation_running());
+
+        assert!(app.cancel_operation());
+        let result = next_completion(&mut app).await;
+        assert!(result.cancelled);
+        assert!(!result.success);
+
+        app.message_tx
+            .send(AppMessage::OperationComplete(result))
+            .unwrap();
+        app.process_messages();
+        assert!(app.running_op.is_none());
+        assert!(
+            app.status_message.contains("cancelled"),
+            "status should say the install was cancelled: {}",
+            app.status_message
+        );
+    }
+
+    #[tokio::test]
+    async fn cancel_batch_upgrade_reports_finished_interrupted_and_not_started() {
+        let backend = HangingBackend::new("Pkg.Two");
+        let mut app = make_app(backend.clone() as Arc<dyn WingetBackend>);
+        app.enqueue_operation(Operation::BatchUpgrade {
+            ids: vec!["Pkg.One".into(), "Pkg.Two".into(), "Pkg.Three".into()],
+        });
+        wait_until(|| backend.started().len() == 2).await;
+
+        assert!(app.cancel_operation());
+        let result = next_completion(&mut app).await;
+        assert!(result.cancelled);
+        assert_eq!(
+            result.message,
+            "1/3 upgraded, interrupted Pkg.Two, 1 not started"
+        );
+        assert_eq!(
+            backend.started(),
+            vec!["Pkg.One".to_string(), "Pkg.Two".to_string()],
+            "no item may start after cancellation"
+        );
+    }
+
+    #[test]
+    fn batch_cancel_summary_lists_each_group_in_the_output() {
+        let ids: Vec<String> = ["A", "B", "C", "D"].iter().map(|s| s.to_string()).collect();
+        let lines = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = lines.clone();
+        let progress = move |update| {
+            if let OperationProgress::Output(line) = update {
+                sink.lock().unwrap().push(line);
+            }
+        };
+        let summary =
+            App::batch_cancel_summary(&ids, 2, false, &["B: failed".to_string()], &progress);
+        assert_eq!(summary, "1/4 upgraded, 1 failed, 2 not started");
+        assert_eq!(
+            *lines.lock().unwrap(),
+            vec![
+                "Finished: A, B".to_string(),
+                "Not started: C, D".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn cancel_operation_without_running_task_returns_false() {
+        let spy = SpyBackend::new();
+        let mut app = make_app(spy as Arc<dyn WingetBackend>);
+        assert!(!app.cancel_operation());
+    }
+
+    // ── operation queue ───────────────────────────────────────────────────────
+
+    fn queued(ops: &[(&str, QueueStatus)]) -> Vec<QueueEntry> {
+        ops.iter()
+            .map(|&(id, status)| QueueEntry {
+                operation: Operation::Upgrade { id: id.into() },
+                status,
+                message: String::new(),
+            })
+            .collect()
+    }
+
+    fn queue_ids(app: &App) -> Vec<String> {
+        app.queue
+            .iter()
+            .map(|entry| match &entry.operation {
+                Operation::Upgrade { id } => id.clone(),
+                other => other.to_string(),
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn enqueue_waits_for_running_operation_then_starts_next() {
+        let backend = HangingBackend::new("Pkg.Slow");
+        let mut app = make_app(backend.clone() as Arc<dyn WingetBackend>);
+        app.enqueue_operation(Operation::Upgrade {
+            id: "Pkg.Slow".into(),
+        });
+        app.enqueue_operation(Operation::Upgrade {
+            id: "Pkg.Next".into(),
+        });
+        wait_until(|| backend.started().len() == 1).await;
+        assert_eq!(app.queue[0].status, QueueStatus::Running);
+        assert_eq!(app.queue[1].status, QueueStatus::Pending);
+        assert!(app.status_message.starts_with("Queued: Upgrading Pkg.Next"));
+
+        app.cancel_operation();
+        let result = next_completion(&mut app).await;
+        app.message_tx
+            .send(AppMessage::OperationComplete(result))
+            .unwrap();
+        app.process_messages();
+
+        assert_eq!(app.queue[0].status, QueueStatus::Cancelled);
+        assert_eq!(app.queue[1].status, QueueStatus::Running);
+        wait_until(|| backend.started().len() == 2).await;
+        let result = next_completion(&mut app).await;
+        assert!(result.success);
+        app.message_tx
+            .send(AppMessage::OperationComplete(result))
+            .unwrap();
+        app.process_messages();
+        assert_eq!(app.queue[1].status, QueueStatus::Done);
+        assert!(app.running_op.is_none());
+    }
+
+    #[test]
+    fn move_queue_entry_only_swaps_pending_neighbours() {
+        let spy = SpyBackend::new();
+        let mut app = make_app(spy as Arc<dyn WingetBackend>);
+        app.queue = queued(&[
+            ("A", QueueStatus::Running),
+            ("B", QueueStatus::Pending),
+            ("C", QueueStatus::Pending),
+        ]);
+        assert!(
+            !app.move_queue_entry(1, -1),
+            "a pending entry cannot jump ahead of the running one"
+        );
+        assert!(app.move_queue_entry(1, 1));
+        assert_eq!(queue_ids(&app), vec!["A", "C", "B"]);
+        assert!(!app.move_queue_entry(2, 1), "no entry past the end");
+        assert!(!app.move_queue_entry(0, 1), "the running entry stays put");
+    }
+
+    #[test]
+    fn remove_queue_entry_rejects_running_and_finished_entries() {
+        let spy = SpyBackend::new();
+        let mut app = make_app(spy as Arc<dyn WingetBackend>);
+        app.queue = queued(&[
+            ("A", QueueStatus::Done),
+            ("B", QueueStatus::Running),
+            ("C", QueueStatus::Pending),
+        ]);
+        assert!(app.remove_queue_entry(0).is_none());
+        assert!(app.remove_queue_entry(1).is_none());
+        assert!(app.remove_queue_entry(2).is_some());
+        assert_eq!(queue_ids(&app), vec!["A", "B"]);
+    }
+
+    #[test]
+    fn clear_finished_queue_entries_keeps_running_and_pending() {
+        let spy = SpyBackend::new();
+        let mut app = make_app(spy as Arc<dyn WingetBackend>);
+        app.queue = queued(&[
+            ("A", QueueStatus::Done),
+            ("B", QueueStatus::Failed),
+            ("C", QueueStatus::Running),
+            ("D", QueueStatus::Cancelled),
+            ("E", QueueStatus::Pending),
+        ]);
+        app.queue_selected = 4;
+        app.clear_finished_queue_entries();
+        assert_eq!(queue_ids(&app), vec!["C", "E"]);
+        assert_eq!(app.queue_selected, 1);
+    }
+
+    // ── error reactions ───────────────────────────────────────────────────────
+
+    fn failed(operation: Operation, kind: WingetErrorKind) -> OpResult {
+        OpResult {
+            operation,
+            success: false,
+            message: kind.message().to_string(),
+            cancelled: false,
+            error_kind: Some(kind),
+        }
+    }
+
+    #[tokio::test]
+    async fn failed_install_reports_error_kind_from_exit_code() {
+        let backend = SpyBackend::failing_install(0x8A15_010D);
+        let mut app = make_app(backend as Arc<dyn WingetBackend>);
+        app.enqueue_operation(Operation::Install {
+            id: "Git.Git".into(),
+            version: None,
+            options: InstallOptions::default(),
+        });
+        let result = next_completion(&mut app).await;
+        assert!(!result.success);
+        assert_eq!(result.error_kind, Some(WingetErrorKind::AlreadyInstalled));
+        assert!(
+            result.message.contains("already installed"),
+            "message should be the friendly one: {}",
+            result.message
+        );
+    }
+
+    #[test]
+    fn reboot_required_failure_flags_pending_reboot() {
+        let spy = SpyBackend::new();
+        let mut app = make_app(spy as Arc<dyn WingetBackend>);
+        let op = Operation::Upgrade { id: "Foo".into() };
+        app.message_tx
+            .send(AppMessage::OperationComplete(failed(
+                op,
+                WingetErrorKind::RebootRequired,
+            )))
+            .unwrap();
+        app.process_messages();
+        assert!(app.reboot_pending);
+        assert!(app.confirm.is_none());
+    }
+
+    #[test]
+    fn already_installed_failure_offers_upgrade() {
+        let spy = SpyBackend::new();
+        let mut app = make_app(spy as Arc<dyn WingetBackend>);
+        let op = Operation::Install {
+            id: "Git.Git".into(),
+            version: None,
+            options: InstallOptions::default(),
+        };
+        app.message_tx
+            .send(AppMessage::OperationComplete(failed(
+                op,
+                WingetErrorKind::AlreadyInstalled,
+            )))
+            .unwrap();
+        app.process_messages();
+        let confirm = app.confirm.expect("should offer an upgrade");
+        assert!(matches!(confirm.operation, Operation::Upgrade { ref id } if id == "Git.Git"));
+    }
+
+    #[test]
+    fn pinned_upgrade_failure_offers_unpin() {
+        let spy = SpyBackend::new();
+        let mut app = make_app(spy as Arc<dyn WingetBackend>);
+        let op = Operation::Upgrade { id: "Foo".into() };
+        app.message_tx
+            .send(AppMessage::OperationComplete(failed(
+                op,
+                WingetErrorKind::BlockedByPin,
+            )))
+            .unwrap();
+        app.process_messages();
+        let confirm = app.confirm.expect("should offer to unpin");
+        assert!(matches!(confirm.operation, Operation::Unpin { ref id } if id == "Foo"));
+    }
+
+    #[tokio::test]
+    async fn cancelled_operation_does_not_offer_follow_up() {
+        let spy = SpyBackend::new();
+        let mut app = make_app(spy as Arc<dyn WingetBackend>);
+        let mut result = failed(
+            Operation::Upgrade { id: "Foo".into() },
+            WingetErrorKind::BlockedByPin,
+        );
+        result.cancelled = true;
+        app.message_tx
+            .send(AppMessage::OperationComplete(result))
+            .unwrap();
+        app.process_messages();
+        assert!(app.confirm.is_none());
+    }
+
+    // ── install options dialog ────────────────────────────────────────────────
+
+    #[test]
+    fn install_dialog_architecture_cycles_back_to_any() {
+        let mut dialog = InstallDialog::new("Git.Git".to_string());
+        dialog.field = 1;
+        assert_eq!(dialog.current_field(), InstallField::Architecture);
+        for expected in INSTALL_ARCHITECTURES {
+            dialog.cycle_current();
+            assert_eq!(dialog.options.architecture.as_deref(), Some(*expected));
+        }
+        dialog.cycle_current();
+        assert_eq!(dialog.options.architecture, None);
+        assert_eq!(dialog.value(InstallField::Architecture), "any");
+    }
+
+    #[test]
+    fn install_dialog_backspace_clears_text_back_to_default() {
+        let mut dialog = InstallDialog::new("Git.Git".to_string());
+        dialog.field = 3;
+        dialog.push_char('d');
+        assert_eq!(dialog.options.locale.as_deref(), Some("d"));
+        dialog.pop_char();
+        assert_eq!(dialog.options.locale, None);
+    }
+
+    #[test]
+    fn install_dialog_typing_into_choice_field_is_ignored() {
+        let mut dialog = InstallDialog::new("Git.Git".to_string());
+        dialog.push_char('x');
+        assert_eq!(dialog.options, InstallOptions::default());
+        assert_eq!(dialog.value(InstallField::Scope), "any");
+    }
+
+    #[test]
+    fn install_dialog_operation_trims_text_fields() {
+        let mut dialog = InstallDialog::new("Git.Git".to_string());
+        dialog.options.scope = InstallScope::Machine;
+        dialog.options.locale = Some(" en-US ".to_string());
+        dialog.options.location = Some("   ".to_string());
+        let Operation::Install {
+            id,
+            version,
+            options,
+        } = dialog.operation()
+        else {
+            panic!("expected Install operation");
+        };
+        assert_eq!(id, "Git.Git");
+        assert_eq!(version, None);
+        assert_eq!(options.scope, InstallScope::Machine);
+        assert_eq!(options.locale.as_deref(), Some("en-US"));
+        assert_eq!(options.location, None);
+    }
+
+    #[test]
+    fn uninstall_dialog_toggles_switches_and_edits_version() {
+        let mut dialog = UninstallDialog::new("Foo.Bar".to_string());
+        dialog.push_char('2');
+        dialog.push_char(' ');
+        dialog.move_field(2);
+        dialog.toggle_current();
+        assert_eq!(dialog.current_field(), UninstallField::Purge);
+        assert_eq!(dialog.value(UninstallField::Purge), "yes");
+        dialog.push_char('x');
+
+        let Operation::Uninstall { id, options } = dialog.operation() else {
+            panic!("expected Uninstall operation");
+        };
+        assert_eq!(id, "Foo.Bar");
+        assert_eq!(options.version.as_deref(), Some("2"));
+        assert!(options.purge);
+        assert!(!options.all_versions && !options.force);
+    }
+
+    // ── pin dialog ────────────────────────────────────────────────────────────
+
+    #[test]
+    fn gating_pattern_allows_patch_upgrades() {
+        assert_eq!(gating_pattern("1.2.3"), "1.2.*");
+        assert_eq!(gating_pattern("24"), "24.*");
+        assert_eq!(gating_pattern(""), "");
+    }
+
+    #[test]
+    fn pin_dialog_starts_on_current_kind_and_offers_remove_when_pinned() {
+        let unpinned = PinDialog::new("Foo".to_string(), "1.2.3", &PinState::None);
+        assert_eq!(unpinned.current_choice(), PinChoice::Blocking);
+        assert!(!unpinned.choices.contains(&PinChoice::Remove));
+        assert_eq!(unpinned.gating_range, "1.2.*");
+
+        let gated = PinDialog::new(
+            "Foo".to_string(),
+            "1.2.3",
+            &PinState::Gating("1.*".to_string()),
+        );
+        assert_eq!(gated.current_choice(), PinChoice::Gating);
+        assert_eq!(gated.gating_range, "1.*");
+        assert!(gated.choices.contains(&PinChoice::Remove));
+    }
+
+    #[test]
+    fn pin_dialog_gating_needs_a_range() {
+        let mut dialog = PinDialog::new("Foo".to_string(), "", &PinState::None);
+        dialog.move_selection(1);
+        assert_eq!(dialog.current_choice(), PinChoice::Gating);
+        assert!(dialog.operation().is_none());
+        dialog.push_char('2');
+        assert!(matches!(
+            dialog.operation(),
+            Some(Operation::Pin { pin: PinState::Gating(ref range), .. }) if range == "2"
+        ));
+    }
+
+    #[tokio::test]
+    async fn successful_pin_shows_chosen_kind_before_refresh() {
+        let mut app = make_app(SpyBackend::new());
+        app.packages = vec![pkg("Foo")];
+        app.filtered_packages = app.packages.clone();
+        app.detail = Some(PackageDetail {
+            id: "Foo".to_string(),
+            ..PackageDetail::default()
+        });
+        let gating = PinState::Gating("1.*".to_string());
+        app.message_tx
+            .send(AppMessage::OperationComplete(OpResult {
+                operation: Operation::Pin {
+                    id: "Foo".to_string(),
+                    pin: gating.clone(),
+                },
+                success: true,
+                message: String::new(),
+                cancelled: false,
+                error_kind: None,
+            }))
+            .unwrap();
+        app.process_messages();
+        assert_eq!(app.filtered_packages[0].pin_state, gating);
+        assert_eq!(app.detail.unwrap().pin_state, gating);
+    }
+
+    #[tokio::test]
+    async fn pins_view_lists_every_pin_regardless_of_pin_filter() {
+        let mut app = make_app(SpyBackend::new());
+        app.mode = AppMode::Pins;
+        app.pin_filter = PinFilter::UnpinnedOnly;
+        app.source_filter = SourceFilter::named("winget");
+        app.loading = true;
+        app.refresh_view();
+        while app.loading {
+            tokio::task::yield_now().await;
+            app.process_messages();
+        }
+        assert_eq!(app.filtered_packages.len(), 1);
+        assert_eq!(app.filtered_packages[0].id, "Git.Git");
+        assert_eq!(app.filtered_packages[0].version, "2.45.*");
+
+        app.source_filter = SourceFilter::All;
+        app.loading = true;
+        app.refresh_view();
+        while app.loading {
+            tokio::task::yield_now().await;
+            app.process_messages();
+        }
+        assert_eq!(app.filtered_packages.len(), 2);
+        assert_eq!(app.filtered_packages[1].name, "Contoso.Store");
+    }
+
+    #[tokio::test]
+    async fn successful_pin_reset_clears_pins_and_detail_cache() {
+        let mut app = make_app(SpyBackend::new());
+        let mut pinned = pkg("Foo");
+        pinned.pin_state = PinState::Blocking;
+        app.packages = vec![pinned];
+        app.filtered_packages = app.packages.clone();
+        app.detail_cache
+            .insert("Foo".to_string(), PackageDetail::default());
+        app.message_tx
+            .send(AppMessage::OperationComplete(OpResult {
+                operation: Operation::ResetPins,
+                success: true,
+                message: String::new(),
+                cancelled: false,
+                error_kind: None,
+            }))
+            .unwrap();
+        app.process_messages();
+        assert_eq!(app.filtered_packages[0].pin_state, PinState::None);
+        assert!(app.detail_cache.is_empty());
+    }
+
+    // ── sources ───────────────────────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn source_update_reports_each_source_separately() {
+        let mut app = make_app(SpyBackend::new());
+        app.load_sources();
+        while app.sources_loading {
+            tokio::task::yield_now().await;
+            app.process_messages();
+        }
+        assert_eq!(app.sources.len(), 2);
+
+        app.update_sources();
+        assert_eq!(app.source_updates["winget"], SourceUpdate::Updating);
+        while app
+            .source_updates
+            .values()
+            .any(|u| *u == SourceUpdate::Updating)
+        {
+            tokio::task::yield_now().await;
+            app.process_messages();
+        }
+        assert_eq!(app.source_updates["winget"], SourceUpdate::Updated);
+        assert!(matches!(
+            app.source_updates["contoso"],
+            SourceUpdate::Failed(ref msg) if msg.contains("source unreachable")
+        ));
+        assert_eq!(app.status_message, "Sources updated -- 1 failed");
+    }
+
+    #[tokio::test]
+    async fn unknown_source_filter_falls_back_to_all_once_sources_load() {
+        let mut app = make_app(SpyBackend::new());
+        app.source_filter = SourceFilter::named("retired");
+        app.load_sources();
+        while app.sources_loading {
+            tokio::task::yield_now().await;
+            app.process_messages();
+        }
+        assert_eq!(app.source_filter, SourceFilter::All);
+        assert!(app.status_message.contains("'retired' is not configured"));
+        assert_eq!(app.source_names(), vec!["winget", "contoso"]);
+    }
+
+    #[tokio::test]
+    async fn configured_custom_source_filter_is_kept() {
+        let mut app = make_app(SpyBackend::new());
+        app.source_filter = SourceFilter::named("contoso");
+        app.load_sources();
+        while app.sources_loading {
+            tokio::task::yield_now().await;
+            app.process_messages();
+        }
+        assert_eq!(app.source_filter, SourceFilter::named("contoso"));
+    }
+
+    #[tokio::test]
+    async fn source_filter_matches_configured_names_case_insensitively() {
+        let mut app = make_app(SpyBackend::new());
+        app.source_filter = SourceFilter::named("Winget");
+        app.load_sources();
+        while app.sources_loading {
+            tokio::task::yield_now().await;
+            app.process_messages();
+        }
+        assert_eq!(app.source_filter, SourceFilter::named("Winget"));
+        assert!(!app.status_message.contains("not configured"));
+    }
+
+    #[test]
+    fn failed_source_list_leaves_the_list_load_alone() {
+        let mut app = make_app(SpyBackend::new());
+        app.loading = true;
+        app.sources_loading = true;
+        app.report_config_warnings(&[ConfigWarning {
+            file: "config.toml".to_string(),
+            line: 2,
+            message: "unknown key 'colour'".to_string(),
+        }]);
+        let status = app.status_message.clone();
+        app.message_tx
+            .send(AppMessage::SourcesFailed("winget timed out".to_string()))
+            .unwrap();
+        app.process_messages();
+        assert!(!app.sources_loading);
+        assert!(app.loading);
+        assert_eq!(app.status_message, status);
+        assert!(app.post_refresh_status.is_some());
+        assert_eq!(app.source_names(), vec!["winget", "msstore"]);
+
+        app.show_sources = true;
+        app.message_tx
+            .send(AppMessage::SourcesFailed("winget timed out".to_string()))
+            .unwrap();
+        app.process_messages();
+        assert_eq!(app.status_message, "Error: winget timed out");
+    }
+
+    #[test]
+    fn source_names_default_to_winget_and_msstore() {
+        let app = make_app(SpyBackend::new());
+        assert_eq!(app.source_names(), vec!["winget", "msstore"]);
+    }
+
+    #[test]
+    fn source_dialog_needs_name_and_url_and_cycles_type() {
+        let mut dialog = SourceDialog::new();
+        assert_eq!(dialog.source.source_type, "Microsoft.Rest");
+        for c in "contoso".chars() {
+            dialog.push_char(c);
+        }
+        assert!(dialog.operation().is_none(), "a URL is required");
+        dialog.move_field(1);
+        for c in "https://pkgs.contoso.com/api".chars() {
+            dialog.push_char(c);
+        }
+        dialog.move_field(1);
+        dialog.push_char('x');
+        dialog.cycle_current();
+        assert_eq!(dialog.source.source_type, "Microsoft.PreIndexed.Package");
+        dialog.cycle_current();
+        assert!(matches!(
+            dialog.operation(),
+            Some(Operation::AddSource { ref source })
+                if source.name == "contoso"
+                    && source.url == "https://pkgs.contoso.com/api"
+                    && source.source_type == "Microsoft.Rest"
+        ));
+    }
+
+    // ── detail tabs ───────────────────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn versions_tab_loads_lazily_and_caches() {
+        let mut app = make_app(SpyBackend::new());
+        app.filtered_packages = vec![pkg("Foo.Bar")];
+        app.load_detail("Foo.Bar");
+        assert!(
+            app.detail_tab_loading.is_none(),
+            "the Overview tab needs no extra call"
+        );
+
+        app.select_detail_tab(DetailTab::Versions);
+        assert_eq!(
+            app.detail_tab_loading,
+            Some((DetailTab::Versions, "Foo.Bar".to_string()))
+        );
+        while app.detail_tab_loading.is_some() {
+            tokio::task::yield_now().await;
+            app.process_messages();
+        }
+        assert_eq!(
+            app.versions_cache.get("Foo.Bar").map(Vec::len),
+            Some(3),
+            "versions are cached per package"
+        );
+
+        // The version picker reuses the cached list without another fetch
+        app.open_version_picker("Foo.Bar");
+        assert!(!app.picker_loading);
+        assert_eq!(app.picked_version().as_deref(), Some("1.10.0"));
+    }
+
+    #[tokio::test]
+    async fn raw_tab_uses_the_output_of_the_detail_fetch() {
+        let spy = SpyBackend::new();
+        let mut app = make_app(spy.clone() as Arc<dyn WingetBackend>);
+        app.filtered_packages = vec![pkg("Foo.Bar"), pkg("Baz.Qux")];
+        app.detail_tab = DetailTab::Raw;
+        app.load_detail("Baz.Qux");
+        assert!(app.detail_tab_loading.is_none());
+        while app.detail_loading {
+            tokio::task::yield_now().await;
+            app.process_messages();
+        }
+        assert_eq!(
+            app.raw_cache.get("Baz.Qux").map(String::as_str),
+            Some("Found Example [Baz.Qux]\nVersion: 1.10.0")
+        );
+        assert!(!app.raw_cache.contains_key("Foo.Bar"));
+        assert_eq!(spy.show_calls(), vec!["Baz.Qux"], "one winget show");
+    }
+
+    #[tokio::test]
+    async fn raw_tab_fetches_output_missing_from_cached_details() {
+        let mut app = make_app(SpyBackend::new());
+        app.filtered_packages = vec![pkg("Foo.Bar")];
+        app.detail_cache.insert(
+            "Foo.Bar".to_string(),
+            PackageDetail {
+                id: "Foo.Bar".to_string(),
+                ..PackageDetail::default()
+            },
+        );
+        app.load_detail("Foo.Bar");
+        app.select_detail_tab(DetailTab::Raw);
+        assert_eq!(
+            app.detail_tab_loading,
+            Some((DetailTab::Raw, "Foo.Bar".to_string()))
+        );
+        while app.detail_tab_loading.is_some() {
+            tokio::task::yield_now().await;
+            app.process_messages();
+        }
+        assert!(app.raw_cache["Foo.Bar"].starts_with("Found Example"));
+    }
+
+    #[test]
+    fn detail_tabs_skip_fetching_for_packages_without_a_manifest() {
+        let mut app = make_app(SpyBackend::new());
+        let mut local = pkg("ARP\\Machine\\X64\\Tool");
+        local.source = String::new();
+        app.filtered_packages = vec![local];
+        app.detail_tab = DetailTab::Versions;
+        app.load_detail("ARP\\Machine\\X64\\Tool");
+        assert!(app.detail_tab_loading.is_none());
+    }
+
+    #[test]
+    fn selecting_a_detail_tab_resets_scroll() {
+        let mut app = make_app(SpyBackend::new());
+        app.detail_scroll = 7;
+        app.select_detail_tab(DetailTab::Installer);
+        assert_eq!(app.detail_tab, DetailTab::Installer);
+        assert_eq!(app.detail_scroll, 0);
+    }
+
+    // ── version picker ────────────────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn version_picker_loads_versions_and_highlights_latest() {
+        let mut app = make_app(SpyBackend::new());
+        app.open_version_picker("Foo.Bar");
+        assert!(app.picker_loading);
+        while app.picker_loading {
+            tokio::task::yield_now().await;
+            app.process_messages();
+        }
+        assert_eq!(app.picker_versions, vec!["1.10.0", "1.9.2", "1.2.0"]);
+        assert_eq!(app.latest_version(), Some("1.10.0"));
+        assert_eq!(app.picked_version().as_deref(), Some("1.10.0"));
+    }
+
+    #[test]
+    fn versions_for_a_closed_picker_are_discarded() {
+        let mut app = make_app(SpyBackend::new());
+        app.picker_id = "Foo.Bar".to_string();
+        app.message_tx
+            .send(AppMessage::VersionsLoaded {
+                id: "Foo.Bar".to_string(),
+                versions: vec!["1.0".to_string()],
+            })
+            .unwrap();
+        app.process_messages();
+        assert!(app.picker_versions.is_empty());
+    }
+
+    #[test]
+    fn picked_version_is_none_without_versions_or_text() {
+        let mut app = make_app(SpyBackend::new());
+        app.input_mode = InputMode::VersionInput;
+        assert_eq!(app.picked_version(), None);
+        app.version_input = " 2.0 ".to_string();
+        assert_eq!(app.picked_version().as_deref(), Some("2.0"));
+    }
+
+    #[test]
+    fn typed_version_is_not_replaced_by_a_longer_listed_one() {
+        let mut app = make_app(SpyBackend::new());
+        app.input_mode = InputMode::VersionInput;
+        app.picker_versions = vec!["1.2.3".into(), "1.1.0".into()];
+        app.version_input = "1.2".to_string();
+        assert_eq!(app.filtered_versions(), vec!["1.2", "1.2.3"]);
+        assert_eq!(app.picked_version().as_deref(), Some("1.2"));
+        app.move_version_selection(1);
+        assert_eq!(app.picked_version().as_deref(), Some("1.2.3"));
+
+        // A listed version is offered once
+        app.version_input = "1.2.3".to_string();
+        app.picker_selected = 0;
+        assert_eq!(app.filtered_versions(), vec!["1.2.3"]);
+        assert_eq!(app.picked_version().as_deref(), Some("1.2.3"));
+    }
+
     // ── csv_escape ────────────────────────────────────────────────────────────
 
     #[test]
@@ -1,12 +1,18 @@
-use anyhow::Result;
 use async_trait::async_trait;
 
-use crate::models::{Package, PackageDetail, PackagePin, Source};
+use crate::error::Result;
+use crate::models::{
+    InstallOptions, OperationProgress, Package, PackageDetail, PackagePin, PinState, ProcessDepth,
+    SearchRequest, Source, UninstallOptions,
+};
+
+/// Callback that receives live output and progress from a running operation.
+pub type ProgressFn = dyn Fn(OperationProgress) + Send + Sync;
 
 #[async_trait]
 pub trait WingetBackend: Send + Sync {
-    /// Search for packages matching query, optionally filtered by source
-    async fn search(&self, query: &str, source: Option<&str>) -> Result<Vec<Package>>;
+    /// Search for packages matching a parsed query, optionally filtered by source
+    async fn search(&self, request: &SearchRequest, source: Option<&str>) -> Result<Vec<Package>>;
 
     /// List all installed packages, optionally filtered by source
     async fn list_installed(&self, source: Option<&str>) -> Result<Vec<Package>>;
@@ -14,28 +20,66 @@ pub trait WingetBackend: Send + Sync {
     /// List packages with available upgrades, optionally filtered by source
     async fn list_upgrades(&self, source: Option<&str>) -> Result<Vec<Package>>;
 
-    /// Show detailed info for a specific package
+    /// Show detailed info for a specific package, with the unparsed output
     async fn show(&self, id: &str) -> Result<PackageDetail>;
 
-    /// Install a package by id, optionally a specific version
-    async fn install(&self, id: &str, version: Option<&str>) -> Result<String>;
+    /// List every version of a package available from its source, newest first
+    async fn show_versions(&self, id: &str) -> Result<Vec<String>>;
+
+    /// Install a package by id, optionally a specific version and with extra
+    /// installer options, reporting live output and progress through `progress`
+    async fn install(
+        &self,
+        id: &str,
+        version: Option<&str>,
+        options: &InstallOptions,
+        progress: &ProgressFn,
+    ) -> Result<String>;
 
-    /// Uninstall a package by id
-    async fn uninstall(&self, id: &str) -> Result<String>;
+    /// Uninstall a package by id with extra uninstaller options, reporting
+    /// live output and progress
+    async fn uninstall(
+        &self,
+        id: &str,
+        options: &UninstallOptions,
+        progress: &ProgressFn,
+    ) -> Result<String>;
 
-    /// Upgrade a package (prefers id matching; backends may fallback to name)
-    async fn upgrade(&self, id: &str) -> Result<String>;
+    /// Upgrade a package (prefers id matching; backends may fallback to name),
+    /// reporting live output and progress
+    async fn upgrade(&self, id: &str, progress: &ProgressFn) -> Result<String>;
 
     /// List all configured package pins
     async fn list_pins(&self) -> Result<Vec<PackagePin>>;
 
-    /// Pin the currently installed version of a package
-    async fn pin(&self, id: &str) -> Result<String>;
+    /// Pin a package with a regular, blocking or gating pin, replacing any
+    /// existing pin
+    async fn pin(&self, id: &str, pin: &PinState) -> Result<String>;
 
     /// Remove any pin applied to a package
     async fn unpin(&self, id: &str) -> Result<String>;
 
+    /// Remove every configured pin
+    async fn reset_pins(&self) -> Result<String>;
+
     /// List configured package sources
-    #[allow(dead_code)]
     async fn list_sources(&self) -> Result<Vec<Source>>;
+
+    /// Add a source by name, argument (URL or path) and optional type
+    async fn add_source(&self, source: &Source) -> Result<String>;
+
+    /// Remove a source by name
+    async fn remove_source(&self, name: &str) -> Result<String>;
+
+    /// Restore the default sources
+    async fn reset_sources(&self) -> Result<String>;
+
+    /// Refresh the cached index of a single source
+    async fn update_source(&self, name: &str) -> Result<String>;
+
+    /// How many winget processes are running or queued right now. Backends
+    /// that do not spawn processes report nothing.
+    fn process_depth(&self) -> ProcessDepth {
+        ProcessDepth::default()
+    }
 }
@@ -0,0 +1,384 @@
+//! On-disk cache of package lists and details, so a launch can show the last
+//! known Installed and Upgrades lists at once while winget revalidates them.
+//!
+//! On Windows the file lives at `%LOCALAPPDATA%\winget-tui\cache.json`; other
+//! platforms fall back to `$HOME/.cache/winget-tui/cache.json`. Every entry
+//! carries the time it was saved. Lists older than [`LIST_TTL`] are not shown,
+//! and details older than [`DETAIL_TTL`] are fetched again. A missing, corrupt
+//! or outdated file is treated as empty.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::app::AppMode;
+use crate::models::{Package, PackageDetail};
+
+/// How old a cached list may be and still be shown while it revalidates
+pub const LIST_TTL: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+/// How long cached details are used without running `winget show` again
+pub const DETAIL_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+/// Details kept on disk; the oldest are dropped beyond this
+const MAX_DETAILS: usize = 500;
+/// Bumped whenever the file layout changes, so old files are ignored
+const FORMAT_VERSION: u32 = 1;
+
+#[derive(Debug, Default)]
+pub struct DiskCache {
+    /// Where the cache is saved; `None` keeps it in memory only
+    path: Option<PathBuf>,
+    data: CacheData,
+    /// Changed since it was loaded or last saved
+    dirty: bool,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CacheData {
+    version: u32,
+    /// Keyed by [`list_key`]
+    lists: HashMap<String, Entry<Vec<Package>>>,
+    details: HashMap<String, Entry<PackageDetail>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Entry<T> {
+    /// Seconds since the Unix epoch
+    saved_at: u64,
+    value: T,
+}
+
+impl<T> Entry<T> {
+    fn new(value: T) -> Self {
+        Self {
+            saved_at: now_secs(),
+            value,
+        }
+    }
+
+    fn age(&self) -> Duration {
+        Duration::from_secs(now_secs().saturating_sub(self.saved_at))
+    }
+}
+
+impl DiskCache {
+    /// Load the cache from the platform cache path, falling back to an empty
+    /// cache. Never returns an error.
+    pub fn load() -> Self {
+        match Self::cache_path() {
+            Some(path) => Self::load_from(path),
+            None => Self::default(),
+        }
+    }
+
+    /// Load the cache saved at `path`, or start an empty one that saves there.
+    pub fn load_from(path: PathBuf) -> Self {
+        let data = std::fs::read_to_string(&path)
+            .ok()
+            .and_then(|text| serde_json::from_str::<CacheData>(&text).ok())
+            .filter(|data| data.version == FORMAT_VERSION)
+            .unwrap_or_default();
+        Self {
+            path: Some(path),
+            data,
+            dirty: false,
+        }
+    }
+
+    /// Returns the platform-specific cache file path, or `None` if the
+    /// required environment variable is not set.
+    fn cache_path() -> Option<PathBuf> {
+        // Windows: %LOCALAPPDATA%\winget-tui\cache.json
+        if let Ok(local) = std::env::var("LOCALAPPDATA") {
+            return Some(PathBuf::from(local).join("winget-tui").join("cache.json"));
+        }
+        // Fallback for non-Windows (dev / CI)
+        if let Ok(home) = std::env::var("HOME") {
+            return Some(
+                PathBuf::from(home)
+                    .join(".cache")
+                    .join("winget-tui")
+                    .join("cache.json"),
+            );
+        }
+        None
+    }
+
+    /// Write the cache to disk if it changed. Failures are ignored: the
+    /// cache only ever speeds things up. The app saves once, when the cache
+    /// is dropped on exit, so serializing it never holds up the UI.
+    pub fn save(&mut self) {
+        if self.path.is_none() || !self.dirty {
+            return;
+        }
+        self.dirty = false;
+        self.prune_details();
+        self.data.version = FORMAT_VERSION;
+        if let (Some(path), Ok(json)) = (&self.path, serde_json::to_string(&self.data)) {
+            let _ = write_atomically(path, &json);
+        }
+    }
+
+    /// The cached list for `mode` and `source`, with its age, unless it has
+    /// expired. Only the Installed and Upgrades lists are cached.
+    pub fn list(&self, mode: AppMode, source: Option<&str>) -> Option<(&[Package], Duration)> {
+        let entry = self.data.lists.get(&list_key(mode, source)?)?;
+        let age = entry.age();
+        (age <= LIST_TTL).then_some((entry.value.as_slice(), age))
+    }
+
+    pub fn put_list(&mut self, mode: AppMode, source: Option<&str>, packages: &[Package]) {
+        if let Some(key) = list_key(mode, source) {
+            self.data.lists.insert(key, Entry::new(packages.to_vec()));
+            self.dirty = true;
+        }
+    }
+
+    /// Cached details for `id`, unless they are older than [`DETAIL_TTL`].
+    pub fn detail(&self, id: &str) -> Option<&PackageDetail> {
+        self.data
+            .details
+            .get(id)
+            .filter(|entry| entry.age() <= DETAIL_TTL)
+            .map(|entry| &entry.value)
+    }
+
+    pub fn put_detail(&mut self, detail: &PackageDetail) {
+        if !detail.id.is_empty() {
+            self.data
+                .details
+                .insert(detail.id.clone(), Entry::new(detail.clone()));
+            self.dirty = true;
+        }
+    }
+
+    /// Forget everything cached about `ids` after an operation changed them:
+    /// their details, and every list that contains one of them.
+    pub fn invalidate(&mut self, ids: &[&str]) {
+        for id in ids {
+            self.data.details.remove(*id);
+        }
+        self.data
+            .lists
+            .retain(|_, entry| !entry.value.iter().any(|pkg| ids.contains(&pkg.id.as_str())));
+        self.dirty = true;
+    }
+
+    /// Forget every cached list and detail.
+    pub fn clear(&mut self) {
+        self.data.lists.clear();
+        self.data.details.clear();
+        self.dirty = true;
+    }
+
+    fn prune_details(&mut self) {
+        let details = &mut self.data.details;
+        details.retain(|_, entry| entry.age() <= DETAIL_TTL);
+        if details.len() > MAX_DETAILS {
+            let mut saved: Vec<u64> = details.values().map(|entry| entry.saved_at).collect();
+            saved.sort_unstable_by(|a, b| b.cmp(a));
+            let cutoff = saved[MAX_DETAILS - 1];
+            details.retain(|_, entry| entry.saved_at >= cutoff);
+        }
+    }
+}
+
+/// Saving on drop covers every way out of the app: quitting, an error from
+/// the event loop, or a panic.
+impl Drop for DiskCache {
+    fn drop(&mut self) {
+        self.save();
+    }
+}
+
+/// Cache key for a list view, e.g. `installed` or `upgrades@winget`.
+fn list_key(mode: AppMode, source: Option<&str>) -> Option<String> {
+    let view = match mode {
+        AppMode::Installed => "installed",
+        AppMode::Upgrades => "upgrades",
+        AppMode::Search | AppMode::Pins => return None,
+    };
+    Some(match source {
+        Some(source) => format!("{view}@{source}"),
+        None => view.to_string(),
+    })
+}
+
+/// Write to a sibling temp file and rename it over `path`, so a crash
+/// mid-write never leaves a truncated cache behind.
+fn write_atomically(path: &Path, contents: &str) -> std::io::Result<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let tmp = path.with_extension("json.tmp");
+    std::fs::write(&tmp, contents)?;
+    std::fs::rename(&tmp, path)
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |since| since.as_secs())
+}
+
+/// "just now", "5 min ago", "3 h ago" or "2 days ago"
+pub fn describe_age(age: Duration) -> String {
+    let secs = age.as_secs();
+    match secs {
+        0..60 => "just now".to_string(),
+        60..3600 => format!("{} min ago", secs / 60),
+        3600..86400 => format!("{} h ago", secs / 3600),
+        _ => match secs / 86400 {
+            1 => "1 day ago".to_string(),
+            days => format!("{days} days ago"),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::PinState;
+
+    fn pkg(id: &str) -> Package {
+        Package {
+            id: id.to_string(),
+            name: id.to_string(),
+            version: "1.0".to_string(),
+            source: "winget".to_string(),
+            available_version: String::new(),
+            match_reason: String::new(),
+            pin_state: PinState::None,
+        }
+    }
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir()
+            .join(format!("winget-tui-cache-{}-{name}", std::process::id()))
+            .join("cache.json")
+    }
+
+    #[test]
+    fn lists_and_details_survive_a_round_trip() {
+        let path = temp_path("round-trip");
+        let mut cache = DiskCache::load_from(path.clone());
+        let mut pinned = pkg("Git.Git");
+        pinned.pin_state = PinState::Gating("2.*".to_string());
+        cache.put_list(AppMode::Installed, None, &[pinned, pkg("7zip.7zip")]);
+        cache.put_detail(&PackageDetail {
+            id: "Git.Git".to_string(),
+            publisher: "The Git Team".to_string(),
+            tags: vec!["vcs".to_string()],
+            ..PackageDetail::default()
+        });
+        cache.save();
+
+        let loaded = DiskCache::load_from(path.clone());
+        let (packages, age) = loaded.list(AppMode::Installed, None).unwrap();
+        assert_eq!(packages.len(), 2);
+        assert_eq!(packages[0].pin_state, PinState::Gating("2.*".to_string()));
+        assert!(age < Duration::from_secs(60));
+        let detail = loaded.detail("Git.Git").unwrap();
+        assert_eq!(detail.publisher, "The Git Team");
+        assert_eq!(detail.tags, vec!["vcs".to_string()]);
+        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[test]
+    fn only_a_changed_cache_is_written() {
+        let path = temp_path("dirty");
+        let mut cache = DiskCache::load_from(path.clone());
+        cache.save();
+        assert!(!path.exists(), "nothing changed, nothing written");
+
+        cache.put_list(AppMode::Installed, None, &[pkg("A.A")]);
+        cache.save();
+        assert!(path.exists());
+        std::fs::remove_file(&path).unwrap();
+        cache.save();
+        assert!(!path.exists(), "already saved");
+        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[test]
+    fn dropping_a_changed_cache_saves_it() {
+        let path = temp_path("drop");
+        {
+            let mut cache = DiskCache::load_from(path.clone());
+            cache.put_list(AppMode::Upgrades, None, &[pkg("A.A")]);
+        }
+        assert!(DiskCache::load_from(path.clone())
+            .list(AppMode::Upgrades, None)
+            .is_some());
+        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[test]
+    fn lists_are_keyed_by_view_and_source() {
+        let mut cache = DiskCache::default();
+        cache.put_list(AppMode::Upgrades, Some("winget"), &[pkg("A.A")]);
+        assert!(cache.list(AppMode::Upgrades, Some("winget")).is_some());
+        assert!(cache.list(AppMode::Upgrades, None).is_none());
+        assert!(cache.list(AppMode::Installed, Some("winget")).is_none());
+
+        cache.put_list(AppMode::Search, None, &[pkg("A.A")]);
+        assert!(cache.list(AppMode::Search, None).is_none());
+    }
+
+    #[test]
+    fn expired_entries_are_not_returned() {
+        let mut cache = DiskCache::default();
+        cache.put_list(AppMode::Installed, None, &[pkg("A.A")]);
+        cache.put_detail(&PackageDetail {
+            id: "A.A".to_string(),
+            ..PackageDetail::default()
+        });
+        let long_ago = now_secs() - LIST_TTL.as_secs() - 1;
+        cache.data.lists.get_mut("installed").unwrap().saved_at = long_ago;
+        cache.data.details.get_mut("A.A").unwrap().saved_at = long_ago;
+        assert!(cache.list(AppMode::Installed, None).is_none());
+        assert!(cache.detail("A.A").is_none());
+    }
+
+    #[test]
+    fn invalidate_drops_details_and_lists_mentioning_the_ids() {
+        let mut cache = DiskCache::default();
+        cache.put_list(AppMode::Installed, None, &[pkg("A.A"), pkg("B.B")]);
+        cache.put_list(AppMode::Upgrades, None, &[pkg("B.B")]);
+        for id in ["A.A", "B.B"] {
+            cache.put_detail(&PackageDetail {
+                id: id.to_string(),
+                ..PackageDetail::default()
+            });
+        }
+        cache.invalidate(&["A.A"]);
+        assert!(cache.detail("A.A").is_none());
+        assert!(cache.detail("B.B").is_some());
+        assert!(cache.list(AppMode::Installed, None).is_none());
+        assert!(cache.list(AppMode::Upgrades, None).is_some());
+    }
+
+    #[test]
+    fn corrupt_or_outdated_files_load_empty() {
+        let path = temp_path("corrupt");
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, "{not json").unwrap();
+        assert!(DiskCache::load_from(path.clone()).data.lists.is_empty());
+
+        let outdated =
+            r#"{"version":0,"lists":{"installed":{"saved_at":0,"value":[]}},"details":{}}"#;
+        std::fs::write(&path, outdated).unwrap();
+        assert!(DiskCache::load_from(path.clone()).data.lists.is_empty());
+        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[test]
+    fn describe_age_uses_the_largest_whole_unit() {
+        assert_eq!(describe_age(Duration::from_secs(5)), "just now");
+        assert_eq!(describe_age(Duration::from_secs(300)), "5 min ago");
+        assert_eq!(describe_age(Duration::from_secs(7200)), "2 h ago");
+        assert_eq!(describe_age(Duration::from_secs(86400)), "1 day ago");
+        assert_eq!(describe_age(Duration::from_secs(3 * 86400)), "3 days ago");
+    }
+}
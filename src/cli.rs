@@ -0,0 +1,233 @@
+//! Command-line arguments: session flags that override `config.toml` for the
+//! TUI, or a headless subcommand (see [`crate::headless`]).
+
+use std::path::PathBuf;
+
+use crate::app::AppMode;
+use crate::config::{self, Overrides};
+use crate::headless::{self, Invocation};
+use crate::models::{SearchRequest, SourceFilter};
+
+pub const USAGE: &str = "\
+Usage: winget-tui [OPTIONS]
+       winget-tui <COMMAND> [COMMAND OPTIONS]
+
+Options (override config.toml for this session):
+      --view <installed|search|upgrades|pins>   View to open
+  -s, --source <name>                           Source filter, e.g. winget or msstore
+      --sort <name|id|version|match|none>       Sort order; append _desc to reverse
+      --pin-filter <all|pinned|hide_pinned>     Pinned-package filter
+      --theme <name>                            Color theme: a preset, auto, or a file in themes/
+  -q, --query <text>                            Open Search and run this query
+      --config <path>                           Read settings from this file instead
+      --check-config                            Report problems in the config file and exit
+  -h, --help                                    Print this help
+
+Commands:
+  list             List installed packages
+  upgrades         List packages with an available upgrade
+  search <query>   Search for packages (supports tag:, cmd:, id:, name:, moniker: and \"exact\")
+  show <id>        Show the details of a package
+  pins             List configured pins
+  check            List available upgrades; exits with 100 when there are any
+  help             Print this help
+
+Command options:
+  -f, --format <table|json|csv>   Output format (default: table)
+      --json                      Same as --format json
+      --csv                       Same as --format csv
+  -s, --source <name>             Only use this source, e.g. winget or msstore
+      --config <path>             Read timeouts from this file instead";
+
+/// Everything parsed from the command line.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Args {
+    /// Replaces the platform config path
+    pub config_path: Option<PathBuf>,
+    pub overrides: Overrides,
+    /// Search to run on startup; implies the Search view
+    pub query: Option<String>,
+    /// Validate the config file instead of starting
+    pub check_config: bool,
+    /// A headless subcommand; `None` starts the TUI
+    pub command: Option<Invocation>,
+}
+
+/// Parse the arguments after the program name.
+pub fn parse(args: &[String]) -> Result<Args, String> {
+    let mut parsed = Args::default();
+
+    // `--config` is accepted before or after a subcommand
+    let mut rest = Vec::with_capacity(args.len());
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        if arg == "--config" {
+            let path = iter.next().ok_or("'--config' needs a value")?;
+            parsed.config_path = Some(PathBuf::from(path));
+        } else if let Some(path) = arg.strip_prefix("--config=") {
+            parsed.config_path = Some(PathBuf::from(path));
+        } else {
+            rest.push(arg.clone());
+        }
+    }
+
+    if rest.first().is_some_and(|arg| !arg.starts_with('-')) {
+        parsed.command = headless::parse_args(&rest)?;
+        return Ok(parsed);
+    }
+
+    let mut iter = rest.iter();
+    while let Some(arg) = iter.next() {
+        let (flag, inline) = match arg.split_once('=') {
+            Some((flag, value)) if flag.starts_with("--") => (flag, Some(value.to_string())),
+            _ => (arg.as_str(), None),
+        };
+        match flag {
+            "-h" | "--help" => {
+                parsed.command = Some(Invocation::help());
+                continue;
+            }
+            "--check-config" => {
+                parsed.check_config = true;
+                continue;
+            }
+            _ => {}
+        }
+        let value = match inline {
+            Some(value) => value,
+            None if flag.starts_with('-') => iter
+                .next()
+                .cloned()
+                .ok_or_else(|| format!("'{flag}' needs a value"))?,
+            None => return Err(format!("unexpected argument '{arg}'")),
+        };
+        let overrides = &mut parsed.overrides;
+        match flag {
+            "--view" => {
+                overrides.view = Some(
+                    config::parse_view(&value.to_ascii_lowercase())
+                        .ok_or_else(|| invalid(flag, &value))?,
+                );
+            }
+            "-s" | "--source" => overrides.source = Some(SourceFilter::named(&value)),
+            "--sort" => {
+                overrides.sort = Some(
+                    config::parse_sort(&value.to_ascii_lowercase())
+                        .ok_or_else(|| invalid(flag, &value))?,
+                );
+            }
+            "--pin-filter" => {
+                overrides.pin_filter = Some(
+                    config::parse_pin_filter(&value.to_ascii_lowercase())
+                        .ok_or_else(|| invalid(flag, &value))?,
+                );
+            }
+            // Checked once the config, and so the themes directory, is known
+            "--theme" => overrides.theme = Some(value),
+            "-q" | "--query" => {
+                SearchRequest::parse(&value).map_err(|e| format!("invalid --query: {e}"))?;
+                parsed.query = Some(value);
+            }
+            _ => return Err(format!("unknown option '{flag}'")),
+        }
+    }
+
+    if parsed.query.is_some() {
+        match parsed.overrides.view {
+            None | Some(AppMode::Search) => parsed.overrides.view = Some(AppMode::Search),
+            Some(_) => return Err("'--query' opens the Search view; drop '--view'".to_string()),
+        }
+    }
+    Ok(parsed)
+}
+
+fn invalid(flag: &str, value: &str) -> String {
+    format!("invalid value '{value}' for '{flag}'")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::headless::{Command, Format};
+    use crate::models::{PinFilter, SortDir, SortField};
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(str::to_string).collect()
+    }
+
+    #[test]
+    fn no_arguments_start_the_tui_with_no_overrides() {
+        assert_eq!(parse(&[]), Ok(Args::default()));
+    }
+
+    #[test]
+    fn session_flags_become_overrides() {
+        let parsed = parse(&args(
+            "--view upgrades --source winget --sort version_desc --pin-filter hide_pinned --theme nord",
+        ))
+        .unwrap();
+        assert_eq!(parsed.command, None);
+        assert_eq!(
+            parsed.overrides,
+            Overrides {
+                theme: Some("nord".to_string()),
+                view: Some(AppMode::Upgrades),
+                source: Some(SourceFilter::named("winget")),
+                sort: Some((SortField::Version, SortDir::Desc)),
+                pin_filter: Some(PinFilter::UnpinnedOnly),
+            }
+        );
+    }
+
+    #[test]
+    fn inline_values_and_config_path() {
+        let parsed = parse(&args("--view=pins --config=C:\\cfg.toml")).unwrap();
+        assert_eq!(parsed.overrides.view, Some(AppMode::Pins));
+        assert_eq!(parsed.config_path, Some(PathBuf::from("C:\\cfg.toml")));
+    }
+
+    #[test]
+    fn query_opens_search() {
+        let parsed = parse(&["--query".to_string(), "tag:editor vim".to_string()]).unwrap();
+        assert_eq!(parsed.query.as_deref(), Some("tag:editor vim"));
+        assert_eq!(parsed.overrides.view, Some(AppMode::Search));
+    }
+
+    #[test]
+    fn query_conflicts_with_another_view() {
+        assert!(parse(&args("--view upgrades -q vim")).is_err());
+        assert!(parse(&args("--query tag:")).is_err());
+    }
+
+    #[test]
+    fn invalid_values_are_errors() {
+        assert!(parse(&args("--view everything")).is_err());
+        assert!(parse(&args("--sort sideways")).is_err());
+        assert!(parse(&args("--pin-filter some")).is_err());
+        assert!(parse(&args("--view")).is_err());
+        assert!(parse(&args("--frobnicate 1")).is_err());
+        assert!(parse(&args("--view pins extra")).is_err());
+    }
+
+    #[test]
+    fn subcommand_keeps_config_path() {
+        let parsed = parse(&args("check --config my.toml --json")).unwrap();
+        assert_eq!(parsed.config_path, Some(PathBuf::from("my.toml")));
+        let command = parsed.command.unwrap();
+        assert_eq!(command.command, Command::Check);
+        assert_eq!(command.format, Format::Json);
+    }
+
+    #[test]
+    fn check_config_takes_no_value() {
+        let parsed = parse(&args("--check-config --config other.toml")).unwrap();
+        assert!(parsed.check_config);
+        assert_eq!(parsed.config_path, Some(PathBuf::from("other.toml")));
+    }
+
+    #[test]
+    fn help_flag_prints_usage() {
+        let parsed = parse(&args("--help")).unwrap();
+        assert_eq!(parsed.command.map(|c| c.command), Some(Command::Help));
+    }
+}
@@ -1,16 +1,167 @@
 use std::cmp::Ordering;
 use std::collections::HashMap;
 
-use anyhow::{bail, Context, Result};
 use async_trait::async_trait;
 use unicode_width::UnicodeWidthChar;
 
+use std::process::{ExitStatus, Stdio};
+use std::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
+use std::time::Duration;
+
+use tokio::io::AsyncReadExt;
 use tokio::process::Command;
+use tokio::sync::{Semaphore, SemaphorePermit};
+
+use crate::backend::{ProgressFn, WingetBackend};
+use crate::error::{Result, WingetError, WingetErrorKind};
+use crate::models::{
+    DependencyGroup, InstallOptions, OperationProgress, Package, PackageDetail, PackagePin,
+    PinState, ProcessDepth, SearchRequest, Source, UninstallOptions,
+};
+
+pub struct CliBackend {
+    /// Executable to run; `winget` on PATH unless a test substitutes a stub
+    program: String,
+    /// Arguments placed before every winget command line
+    program_args: Vec<String>,
+    timeouts: Timeouts,
+    limiter: ProcessLimiter,
+}
+
+/// How many read-only winget processes (list, search, show) may run at once
+const DEFAULT_READ_PROCESSES: usize = 3;
+/// How many mutating winget processes (install, pin, source changes) may run at once
+const DEFAULT_WRITE_PROCESSES: usize = 1;
+
+/// Caps the winget processes a backend runs at once.
+///
+/// Read-only calls and mutations queue in separate lanes, so a burst of
+/// `show` calls from fast scrolling cannot hold up an install, and a long
+/// install cannot hold up listing.
+struct ProcessLimiter {
+    read: Lane,
+    write: Lane,
+}
+
+impl ProcessLimiter {
+    fn new(reads: usize, writes: usize) -> Self {
+        Self {
+            read: Lane::new(reads),
+            write: Lane::new(writes),
+        }
+    }
+
+    fn depth(&self) -> ProcessDepth {
+        ProcessDepth {
+            reads_running: self.read.running.load(AtomicOrdering::Relaxed),
+            reads_waiting: self.read.waiting.load(AtomicOrdering::Relaxed),
+            writes_running: self.write.running.load(AtomicOrdering::Relaxed),
+            writes_waiting: self.write.waiting.load(AtomicOrdering::Relaxed),
+        }
+    }
+}
+
+/// One queue of winget processes with a fixed number of slots.
+struct Lane {
+    slots: Semaphore,
+    waiting: AtomicUsize,
+    running: AtomicUsize,
+}
+
+impl Lane {
+    fn new(slots: usize) -> Self {
+        Self {
+            slots: Semaphore::new(slots.max(1)),
+            waiting: AtomicUsize::new(0),
+            running: AtomicUsize::new(0),
+        }
+    }
+
+    /// Wait for a free slot. The slot is released when the returned guard drops.
+    async fn acquire(&self) -> LaneSlot<'_> {
+        let _waiting = Counted::new(&self.waiting);
+        let permit = self
+            .slots
+            .acquire()
+            .await
+            .expect("lane semaphore is never closed");
+        LaneSlot {
+            _running: Counted::new(&self.running),
+            _permit: permit,
+        }
+    }
+}
+
+/// A held lane slot; counts as running until dropped.
+struct LaneSlot<'a> {
+    _running: Counted<'a>,
+    _permit: SemaphorePermit<'a>,
+}
+
+/// Increments a counter for as long as it lives, so a cancelled wait or
+/// run never leaves the count behind.
+struct Counted<'a>(&'a AtomicUsize);
+
+impl<'a> Counted<'a> {
+    fn new(counter: &'a AtomicUsize) -> Self {
+        counter.fetch_add(1, AtomicOrdering::Relaxed);
+        Self(counter)
+    }
+}
+
+impl Drop for Counted<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, AtomicOrdering::Relaxed);
+    }
+}
+
+/// Kind of winget command, each with its own timeout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CommandClass {
+    /// `list`, `upgrade` (listing only) and `source` commands
+    List,
+    Search,
+    Show,
+    /// `pin list`, `pin add` and `pin remove`
+    Pin,
+    /// install, uninstall and upgrade
+    Operation,
+}
+
+/// How long each class of winget command may run before it is killed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timeouts {
+    pub list: Duration,
+    pub search: Duration,
+    pub show: Duration,
+    pub pin: Duration,
+    pub operation: Duration,
+}
 
-use crate::backend::WingetBackend;
-use crate::models::{Package, PackageDetail, PackagePin, PinState, Source};
+impl Default for Timeouts {
+    fn default() -> Self {
+        Self {
+            list: Duration::from_secs(120),
+            search: Duration::from_secs(60),
+            show: Duration::from_secs(30),
+            pin: Duration::from_secs(60),
+            // Installers can legitimately take a long time
+            operation: Duration::from_secs(30 * 60),
+        }
+    }
+}
 
-pub struct CliBackend;
+impl Timeouts {
+    fn for_class(&self, class: CommandClass) -> Duration {
+        match class {
+            CommandClass::List => self.list,
+            CommandClass::Search => self.search,
+            CommandClass::Show => self.show,
+            CommandClass::Pin => self.pin,
+            CommandClass::Operation => self.operation,
+        }
+    }
+}
 
 /// Returns `true` for winget footer lines like `"2 upgrades available."` or
 /// `"3 Pakete verfügen über Pins…"`.
@@ -48,6 +199,136 @@ fn sanitize_text(s: &str) -> String {
         .collect()
 }
 
+/// Returns `true` for the single-character spinner frames winget draws while
+/// it waits (`-`, `\`, `|`, `/`).
+fn is_spinner_frame(text: &str) -> bool {
+    matches!(text, "-" | "\\" | "|" | "/")
+}
+
+/// Parse a byte size such as `"12.5 MB"` into bytes.
+fn parse_byte_size(text: &str) -> Option<f64> {
+    let mut parts = text.split_whitespace();
+    let value: f64 = parts.next()?.parse().ok()?;
+    let scale = match parts.next().unwrap_or("B").to_ascii_uppercase().as_str() {
+        "B" => 1.0,
+        "KB" | "KIB" => 1024.0,
+        "MB" | "MIB" => 1024.0 * 1024.0,
+        "GB" | "GIB" => 1024.0 * 1024.0 * 1024.0,
+        _ => return None,
+    };
+    Some(value * scale)
+}
+
+/// Extract the completion percentage from a winget progress-bar segment.
+///
+/// winget draws either `"██████▒▒▒▒  45%"` or, for downloads,
+/// `"██████▒▒▒▒  12.0 MB / 45.3 MB"`. Lines that merely mention a percent
+/// somewhere in the middle are not treated as progress.
+fn parse_progress_percent(text: &str) -> Option<u8> {
+    let is_bar = text.contains(['█', '▒']);
+    if let Some(before) = text.strip_suffix('%') {
+        let number = before
+            .split_whitespace()
+            .last()?
+            .trim_start_matches(['█', '▒']);
+        let value: f64 = number.parse().ok()?;
+        return Some(value.clamp(0.0, 100.0) as u8);
+    }
+    if is_bar {
+        let sizes = text.trim_start_matches(['█', '▒', ' ']);
+        let (done, total) = sizes.split_once(" / ")?;
+        let (done, total) = (parse_byte_size(done)?, parse_byte_size(total)?);
+        if total > 0.0 {
+            return Some((done / total * 100.0).clamp(0.0, 100.0) as u8);
+        }
+    }
+    None
+}
+
+/// Map one of winget's status lines to a short phase label.
+///
+/// Only winget's English wording is recognised; other locales still report
+/// percentages and raw output, just without a phase label.
+fn detect_phase(text: &str) -> Option<&'static str> {
+    const PHASES: &[(&str, &str)] = &[
+        ("downloading", "Downloading"),
+        ("successfully verified installer hash", "Verifying"),
+        ("verifying", "Verifying"),
+        ("extracting", "Extracting"),
+        ("installing dependencies", "Installing dependencies"),
+        ("starting package install", "Installing"),
+        ("starting package uninstall", "Uninstalling"),
+    ];
+    let lower = text.to_ascii_lowercase();
+    PHASES
+        .iter()
+        .find(|(prefix, _)| lower.starts_with(prefix))
+        .map(|&(_, phase)| phase)
+}
+
+/// Incrementally turns raw winget stdout into [`OperationProgress`] events.
+///
+/// winget redraws its spinner and progress bar in place with `\r`, so only
+/// the last segment of a line is reported as output once the line ends;
+/// every segment is still inspected for a phase or percentage so progress is
+/// live even though the line itself never completes until the bar is done.
+#[derive(Default)]
+struct ProgressTracker {
+    pending: Vec<u8>,
+    line: String,
+    phase: Option<&'static str>,
+    percent: Option<u8>,
+}
+
+impl ProgressTracker {
+    fn feed(&mut self, chunk: &[u8], emit: &ProgressFn) {
+        for &byte in chunk {
+            match byte {
+                b'\r' => self.end_segment(emit),
+                b'\n' => self.end_line(emit),
+                _ => self.pending.push(byte),
+            }
+        }
+    }
+
+    /// Flush whatever is left once winget closes stdout.
+    fn finish(&mut self, emit: &ProgressFn) {
+        self.end_line(emit);
+    }
+
+    fn end_segment(&mut self, emit: &ProgressFn) {
+        let raw = std::mem::take(&mut self.pending);
+        let text = sanitize_text(String::from_utf8_lossy(&raw).trim());
+        if text.is_empty() {
+            return;
+        }
+        if let Some(percent) = parse_progress_percent(&text) {
+            if self.percent != Some(percent) {
+                self.percent = Some(percent);
+                emit(OperationProgress::Percent(percent));
+            }
+            self.line.clear();
+            return;
+        }
+        if let Some(phase) = detect_phase(&text) {
+            if self.phase != Some(phase) {
+                self.phase = Some(phase);
+                self.percent = None;
+                emit(OperationProgress::Phase(phase.to_string()));
+            }
+        }
+        self.line = text;
+    }
+
+    fn end_line(&mut self, emit: &ProgressFn) {
+        self.end_segment(emit);
+        let line = std::mem::take(&mut self.line);
+        if !line.is_empty() && !is_spinner_frame(&line) {
+            emit(OperationProgress::Output(line));
+        }
+    }
+}
+
 /// Pre-computed column indices for a package table.
 #[derive(Copy, Clone)]
 struct PackageCols {
@@ -56,6 +337,8 @@ struct PackageCols {
     version: Option<usize>,
     source: Option<usize>,
     available: Option<usize>,
+    /// Search results only: why the package matched, e.g. "Tag: json"
+    match_reason: Option<usize>,
 }
 
 /// Pre-computed column indices for a source table.
@@ -68,14 +351,54 @@ struct SourceCols {
 
 #[derive(Copy, Clone)]
 struct PinCols {
+    name: Option<usize>,
     id: Option<usize>,
+    source: Option<usize>,
     pinned_version: Option<usize>,
     pin_type: Option<usize>,
 }
 
 impl CliBackend {
     pub fn new() -> Self {
-        Self
+        Self {
+            program: "winget".to_string(),
+            program_args: Vec::new(),
+            timeouts: Timeouts::default(),
+            limiter: ProcessLimiter::new(DEFAULT_READ_PROCESSES, DEFAULT_WRITE_PROCESSES),
+        }
+    }
+
+    pub fn with_timeouts(mut self, timeouts: Timeouts) -> Self {
+        self.timeouts = timeouts;
+        self
+    }
+
+    fn search_args<'a>(request: &'a SearchRequest, source: Option<&'a str>) -> Vec<&'a str> {
+        let mut args = vec!["search"];
+        // --query rather than a positional keeps a leading '-' from reading as a flag
+        if !request.query.is_empty() {
+            args.extend(["--query", request.query.as_str()]);
+        }
+        for (flag, value) in [
+            ("--id", &request.id),
+            ("--name", &request.name),
+            ("--moniker", &request.moniker),
+            ("--tag", &request.tag),
+            ("--command", &request.command),
+        ] {
+            if let Some(value) = value {
+                args.extend([flag, value.as_str()]);
+            }
+        }
+        if request.exact {
+            args.push("--exact");
+        }
+        args.push("--accept-source-agreements");
+        if let Some(src) = source {
+            args.push("--source");
+            args.push(src);
+        }
+        args
     }
 
     fn list_installed_args(source: Option<&str>) -> Vec<&str> {
@@ -96,6 +419,32 @@ impl CliBackend {
         args
     }
 
+    fn install_args<'a>(
+        id: &'a str,
+        version: Option<&'a str>,
+        options: &'a InstallOptions,
+    ) -> Vec<&'a str> {
+        let mut args = vec![
+            "install",
+            "--id",
+            id,
+            "--accept-source-agreements",
+            "--accept-package-agreements",
+        ];
+        if let Some(v) = version {
+            args.push("--version");
+            args.push(v);
+        }
+        args.extend(options.args());
+        args
+    }
+
+    fn uninstall_args<'a>(id: &'a str, options: &'a UninstallOptions) -> Vec<&'a str> {
+        let mut args = vec!["uninstall", "--id", id, "--accept-source-agreements"];
+        args.extend(options.args());
+        args
+    }
+
     fn upgrade_args_by_id(query: &str) -> Vec<&str> {
         vec![
             "upgrade",
@@ -117,29 +466,75 @@ impl CliBackend {
         ]
     }
 
-    fn pin_add_args(id: &str) -> Vec<&str> {
+    fn pin_add_args<'a>(id: &'a str, pin: &'a PinState) -> Vec<&'a str> {
+        let mut args = vec!["pin", "add", "--id", id, "--exact"];
+        match pin {
+            PinState::Blocking => args.push("--blocking"),
+            PinState::Gating(range) => args.extend(["--version", range.as_str()]),
+            PinState::Pinned | PinState::None => {}
+        }
+        // Replace an existing pin so its kind can be changed
+        args.extend(["--force", "--disable-interactivity"]);
+        args
+    }
+
+    fn pin_remove_args(id: &str) -> Vec<&str> {
         vec![
             "pin",
-            "add",
+            "remove",
             "--id",
             id,
             "--exact",
-            "--blocking",
             "--disable-interactivity",
         ]
     }
 
-    fn pin_remove_args(id: &str) -> Vec<&str> {
+    fn source_add_args(source: &Source) -> Vec<&str> {
+        let mut args = vec![
+            "source",
+            "add",
+            "--name",
+            &source.name,
+            "--arg",
+            &source.url,
+        ];
+        if !source.source_type.is_empty() {
+            args.extend(["--type", source.source_type.as_str()]);
+        }
+        args.extend(["--accept-source-agreements", "--disable-interactivity"]);
+        args
+    }
+
+    fn source_remove_args(name: &str) -> Vec<&str> {
         vec![
-            "pin",
+            "source",
             "remove",
-            "--id",
-            id,
-            "--exact",
+            "--name",
+            name,
+            "--disable-interactivity",
+        ]
+    }
+
+    /// Like `pin reset`, `source reset` does nothing unless forced.
+    fn source_reset_args() -> Vec<&'static str> {
+        vec!["source", "reset", "--force", "--disable-interactivity"]
+    }
+
+    fn source_update_args(name: &str) -> Vec<&str> {
+        vec![
+            "source",
+            "update",
+            "--name",
+            name,
             "--disable-interactivity",
         ]
     }
 
+    /// `pin reset` only lists what it would remove unless forced.
+    fn pin_reset_args() -> Vec<&'static str> {
+        vec!["pin", "reset", "--force", "--disable-interactivity"]
+    }
+
     fn compare_versions_like(a: &str, b: &str) -> Ordering {
         let a = a.trim();
         let b = b.trim();
@@ -215,7 +610,7 @@ impl CliBackend {
     ///
     /// Runs `winget --version` synchronously (before the TUI starts).
     /// Returns `Ok(())` if winget responds, `Err` with a human-readable message otherwise.
-    pub fn check_winget_available() -> Result<()> {
+    pub fn check_winget_available() -> anyhow::Result<()> {
         std::process::Command::new("winget")
             .arg("--version")
             .output()
@@ -232,27 +627,66 @@ impl CliBackend {
             })
     }
 
-    async fn run_winget(&self, args: &[&str]) -> Result<String> {
-        self.run_winget_inner(args, false).await
+    async fn run_winget(&self, args: &[&str], class: CommandClass) -> Result<String> {
+        self.run_winget_inner(args, class, false, None).await
     }
 
     /// Run winget in strict mode: any non-zero exit is an error.
     /// Use for mutating operations (install, uninstall, upgrade).
-    async fn run_winget_strict(&self, args: &[&str]) -> Result<String> {
-        self.run_winget_inner(args, true).await
+    async fn run_winget_strict(&self, args: &[&str], class: CommandClass) -> Result<String> {
+        self.run_winget_inner(args, class, true, None).await
+    }
+
+    /// Strict mode that also streams output and progress while winget runs.
+    async fn run_winget_streaming(&self, args: &[&str], progress: &ProgressFn) -> Result<String> {
+        self.run_winget_inner(args, CommandClass::Operation, true, Some(progress))
+            .await
     }
 
-    async fn run_winget_inner(&self, args: &[&str], strict: bool) -> Result<String> {
-        let output = Command::new("winget")
+    async fn run_winget_inner(
+        &self,
+        args: &[&str],
+        class: CommandClass,
+        strict: bool,
+        progress: Option<&ProgressFn>,
+    ) -> Result<String> {
+        // Strict calls are exactly the mutating ones. Time spent queued for a
+        // slot does not count against the command's timeout.
+        let lane = if strict {
+            &self.limiter.write
+        } else {
+            &self.limiter.read
+        };
+        let _slot = lane.acquire().await;
+
+        let mut command = Command::new(&self.program);
+        command
+            .args(&self.program_args)
             .args(args)
-            .output()
+            .kill_on_drop(true);
+        let run = async {
+            match progress {
+                Some(progress) => Self::stream_output(command, progress).await,
+                None => command
+                    .output()
+                    .await
+                    .map(|output| (output.status, output.stdout, output.stderr)),
+            }
+        };
+        // On expiry the future is dropped, and kill_on_drop kills winget.
+        let after = self.timeouts.for_class(class);
+        let (status, stdout, stderr) = tokio::time::timeout(after, run)
             .await
-            .context("Failed to run winget. Is it installed?")?;
+            .map_err(|_| WingetError::Timeout {
+                command: args.first().copied().unwrap_or_default().to_string(),
+                after,
+            })?
+            .map_err(WingetError::Launch)?;
 
-        let stdout = String::from_utf8_lossy(&output.stdout).to_string();
-        let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+        let stdout = String::from_utf8_lossy(&stdout).to_string();
+        let stderr = String::from_utf8_lossy(&stderr).to_string();
 
-        if !output.status.success() && (strict || stdout.trim().is_empty()) {
+        if !status.success() && (strict || stdout.trim().is_empty()) {
             // In strict mode, always fail. In lenient mode, fail only if
             // there's no stdout (winget returns non-zero for "no results"
             // but still prints a table).
@@ -261,12 +695,52 @@ impl CliBackend {
             } else {
                 stderr.trim().to_string()
             };
-            bail!("winget failed: {}", detail);
+            return Err(WingetError::failed(status.code(), detail));
         }
 
         Ok(Self::clean_output(&stdout))
     }
 
+    /// Spawn `command` and forward its stdout to `progress` as it arrives,
+    /// while still capturing both streams for the final result.
+    async fn stream_output(
+        mut command: Command,
+        progress: &ProgressFn,
+    ) -> std::io::Result<(ExitStatus, Vec<u8>, Vec<u8>)> {
+        let mut child = command
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+        let mut stdout = child.stdout.take().expect("stdout is piped");
+        let mut stderr = child.stderr.take().expect("stderr is piped");
+
+        let read_stdout = async {
+            let mut captured = Vec::new();
+            let mut tracker = ProgressTracker::default();
+            let mut chunk = [0u8; 4096];
+            loop {
+                let n = stdout.read(&mut chunk).await?;
+                if n == 0 {
+                    break;
+                }
+                captured.extend_from_slice(&chunk[..n]);
+                tracker.feed(&chunk[..n], progress);
+            }
+            tracker.finish(progress);
+            Ok::<_, std::io::Error>(captured)
+        };
+        let read_stderr = async {
+            let mut captured = Vec::new();
+            stderr.read_to_end(&mut captured).await?;
+            Ok::<_, std::io::Error>(captured)
+        };
+
+        let (stdout, stderr) = tokio::try_join!(read_stdout, read_stderr)?;
+        let status = child.wait().await?;
+        Ok((status, stdout, stderr))
+    }
+
     /// Normalize winget stdout: resolve `\r\n` line endings and progress-spinner
     /// overwrites (`\r` mid-line, keeping only the segment after the last one).
     ///
@@ -453,12 +927,140 @@ impl CliBackend {
             "homepage" | "startseite" => "homepage",
             "publisher url" | "herausgeber-url" => "publisher_url",
             "release notes url" | "versionshinweise url" => "release_notes_url",
+            "release notes" | "versionshinweise" => "release_notes",
             "license" | "lizenz" | "licence" | "licencia" | "licença" | "licenza" => "license",
             "source" | "quelle" | "origen" | "fonte" | "origine" => "source",
+            "author" | "autor" | "auteur" | "autore" => "author",
+            "moniker" => "moniker",
+            "tags" => "tags",
+            "copyright" => "copyright",
+            "privacy url" => "privacy_url",
+            "publisher support url" => "support_url",
+            "documentation" | "documentations" => "documentation",
+            "release date" => "release_date",
+            "installer" | "installationsprogramm" => "installer",
+            "installer type" => "installer_type",
+            "installer url" => "installer_url",
+            "installer sha256" => "installer_sha256",
+            "dependencies" | "abhängigkeiten" => "dependencies",
+            "agreements" | "vereinbarungen" => "agreements",
             _ => "",
         }
     }
 
+    /// Consume the indented block that follows a `Key:` line with no inline value,
+    /// e.g. the entries under `Tags:` or `Installer:`.
+    fn take_show_block<'a>(lines: &mut std::iter::Peekable<std::str::Lines<'a>>) -> Vec<&'a str> {
+        let mut block = Vec::new();
+        while let Some(line) = lines.next_if(|l| l.starts_with(' ') || l.starts_with('\t')) {
+            if !line.trim().is_empty() {
+                block.push(line);
+            }
+        }
+        block
+    }
+
+    /// Capture an indented release-notes block verbatim. Blank lines inside the
+    /// block are kept as paragraph breaks, and the common indentation is removed so
+    /// nested bullets keep their relative depth.
+    fn take_release_notes(
+        first: &str,
+        lines: &mut std::iter::Peekable<std::str::Lines<'_>>,
+    ) -> String {
+        let indented = |l: &str| l.starts_with(' ') || l.starts_with('\t');
+        let mut block: Vec<&str> = Vec::new();
+        loop {
+            match lines.peek() {
+                Some(l) if indented(l) => block.push(lines.next().unwrap_or_default()),
+                // A blank line only belongs to the notes if more indented text follows
+                Some(l) if l.trim().is_empty() => {
+                    let continues = lines
+                        .clone()
+                        .find(|l| !l.trim().is_empty())
+                        .is_some_and(indented);
+                    if !continues {
+                        break;
+                    }
+                    block.push(lines.next().unwrap_or_default());
+                }
+                _ => break,
+            }
+        }
+
+        let indent = |l: &str| l.len() - l.trim_start().len();
+        let common = block
+            .iter()
+            .filter(|l| !l.trim().is_empty())
+            .map(|l| indent(l))
+            .min()
+            .unwrap_or(0);
+        let mut notes: Vec<String> = Vec::new();
+        if !first.is_empty() {
+            notes.push(sanitize_text(first));
+        }
+        for line in block {
+            let line = line.trim_end();
+            notes.push(sanitize_text(line.get(common..).unwrap_or_default()));
+        }
+        while notes.last().is_some_and(|l| l.is_empty()) {
+            notes.pop();
+        }
+        notes.join("\n")
+    }
+
+    /// Split an indented block into `Label: value` pairs. Lines indented deeper than
+    /// the first entry, or without a colon, continue the previous value.
+    fn show_block_pairs(block: &[&str]) -> Vec<(String, String)> {
+        let indent = |l: &str| l.len() - l.trim_start().len();
+        let base = block.first().map(|l| indent(l)).unwrap_or(0);
+        let mut pairs: Vec<(String, String)> = Vec::new();
+        for line in block {
+            let trimmed = line.trim();
+            let entry = (indent(line) <= base)
+                .then(|| trimmed.split_once(':'))
+                .flatten();
+            match (entry, pairs.last_mut()) {
+                (Some((label, value)), _) => {
+                    pairs.push((sanitize_text(label.trim()), value.trim().to_string()));
+                }
+                (None, Some((_, value))) => {
+                    if !value.is_empty() {
+                        value.push(' ');
+                    }
+                    value.push_str(trimmed);
+                }
+                (None, None) => pairs.push((String::new(), trimmed.to_string())),
+            }
+        }
+        pairs
+            .into_iter()
+            .map(|(label, value)| (label, sanitize_text(&value)))
+            .collect()
+    }
+
+    /// Group a `Dependencies:` block into its `- Kind:` headings and their items.
+    fn parse_show_dependencies(block: &[&str]) -> Vec<DependencyGroup> {
+        let mut groups: Vec<DependencyGroup> = Vec::new();
+        for line in block {
+            let trimmed = line.trim();
+            if let Some(kind) = trimmed.strip_prefix("- ") {
+                groups.push(DependencyGroup {
+                    kind: sanitize_text(kind.trim().trim_end_matches(':')),
+                    items: Vec::new(),
+                });
+                continue;
+            }
+            if groups.is_empty() {
+                groups.push(DependencyGroup::default());
+            }
+            if let Some(group) = groups.last_mut() {
+                group.items.push(sanitize_text(trimmed));
+            }
+        }
+        groups.retain(|g| !g.items.is_empty());
+        groups
+    }
+
     /// Pre-compute package column indices once for a table, to avoid repeated
     /// `to_lowercase()` allocations for every row.
     fn package_column_map(cols: &[(&str, usize)]) -> PackageCols {
@@ -477,6 +1079,15 @@ impl CliBackend {
                     "disponibile",
                 ],
             ),
+            match_reason: Self::find_column_ci(
+                cols,
+                &[
+                    "match",
+                    "übereinstimmung",
+                    "coincidencia",
+                    "correspondência",
+                ],
+            ),
         };
         // Positional fallback for unrecognized locales (e.g. CJK)
         if map.id.is_none() && cols.len() >= 4 {
@@ -524,6 +1135,7 @@ impl CliBackend {
             version: sanitize_text(&pcols.version.map(&field).unwrap_or_default()),
             source: sanitize_text(&pcols.source.map(&field).unwrap_or_default()),
             available_version: sanitize_text(&pcols.available.map(&field).unwrap_or_default()),
+            match_reason: sanitize_text(&pcols.match_reason.map(&field).unwrap_or_default()),
             pin_state: PinState::None,
         })
     }
@@ -587,8 +1199,49 @@ impl CliBackend {
                             detail.homepage = sanitize_text(&value);
                         }
                         "release_notes_url" => detail.release_notes_url = sanitize_text(&value),
+                        "release_notes" => {
+                            detail.release_notes = Self::take_release_notes(&value, &mut lines);
+                        }
                         "license" => detail.license = sanitize_text(&value),
                         "source" => detail.source = sanitize_text(&value),
+                        "author" => detail.author = sanitize_text(&value),
+                        "moniker" => detail.moniker = sanitize_text(&value),
+                        "copyright" => detail.copyright = sanitize_text(&value),
+                        "privacy_url" => detail.privacy_url = sanitize_text(&value),
+                        "support_url" => detail.support_url = sanitize_text(&value),
+                        "release_date" => detail.release_date = sanitize_text(&value),
+                        "tags" => {
+                            // Usually one tag per indented line; tolerate an inline value too.
+                            detail.tags = std::iter::once(value.as_str())
+                                .chain(Self::take_show_block(&mut lines))
+                                .map(|tag| sanitize_text(tag.trim()))
+                                .filter(|tag| !tag.is_empty())
+                                .collect();
+                        }
+                        "documentation" => {
+                            detail.documentation =
+                                Self::show_block_pairs(&Self::take_show_block(&mut lines));
+                        }
+                        "agreements" => {
+                            detail.agreements =
+                                Self::show_block_pairs(&Self::take_show_block(&mut lines));
+                        }
+                        "installer" => {
+                            let block = Self::take_show_block(&mut lines);
+                            for (label, value) in Self::show_block_pairs(&block) {
+                                match Self::normalize_show_key(&label) {
+                                    "installer_type" => detail.installer_type = value,
+                                    "installer_url" => detail.installer_url = value,
+                                    "installer_sha256" => detail.installer_sha256 = value,
+                                    "release_date" => detail.release_date = value,
+                                    _ => {}
+                                }
+                            }
+                        }
+                        "dependencies" => {
+                            detail.dependencies =
+                                Self::parse_show_dependencies(&Self::take_show_block(&mut lines));
+                        }
                         _ => {}
                     }
                 }
@@ -616,7 +1269,9 @@ impl CliBackend {
 
     fn pin_column_map(cols: &[(&str, usize)]) -> PinCols {
         let mut map = PinCols {
+            name: Self::find_column_ci(cols, &["name", "nombre", "nom", "nome"]),
             id: Self::find_column_ci(cols, &["id", "id."]),
+            source: Self::find_column_ci(cols, &["source", "quelle", "origen", "fonte"]),
             pinned_version: Self::find_column_ci(
                 cols,
                 &[
@@ -631,6 +1286,7 @@ impl CliBackend {
             pin_type: Self::find_column_ci(cols, &["type", "typ", "tipo"]),
         };
         if map.id.is_none() && cols.len() >= 4 {
+            map.name = Some(0);
             map.id = Some(1);
             if cols.len() >= 5 {
                 map.pinned_version = map.pinned_version.or(Some(3));
@@ -695,13 +1351,39 @@ impl CliBackend {
 
                 Some(PackagePin {
                     id: sanitize_text(&id),
+                    name: sanitize_text(&col_map.name.map(&field).unwrap_or_default()),
                     pin_state: Self::parse_pin_state(&pin_type, &pinned_version),
+                    pinned_version: sanitize_text(&pinned_version),
+                    source: sanitize_text(&col_map.source.map(&field).unwrap_or_default()),
                 })
             })
             .collect()
     }
 
-    #[allow(dead_code)]
+    /// Parse the single-column table printed by `winget show --versions`.
+    ///
+    /// The separator under a lone "Version" header is too short for
+    /// [`Self::find_table_separator`], so any all-dash line is accepted here.
+    fn parse_versions_from_table(output: &str) -> Vec<String> {
+        let lines: Vec<&str> = output.lines().collect();
+        let Some(sep_idx) = lines
+            .iter()
+            .position(|l| {
+                let trimmed = l.trim();
+                trimmed.len() >= 3 && trimmed.chars().all(|c| c == '-')
+            })
+            .filter(|&i| i > 0)
+        else {
+            return Vec::new();
+        };
+
+        lines[sep_idx + 1..]
+            .iter()
+            .filter(|l| !l.trim().is_empty() && !is_winget_footer_line(l))
+            .map(|l| sanitize_text(l.trim()))
+            .collect()
+    }
+
     fn parse_sources_from_table(&self, output: &str) -> Vec<Source> {
         let lines: Vec<&str> = output.lines().collect();
         let sep_idx = match Self::find_table_separator(&lines) {
@@ -736,13 +1418,9 @@ impl CliBackend {
 
 #[async_trait]
 impl WingetBackend for CliBackend {
-    async fn search(&self, query: &str, source: Option<&str>) -> Result<Vec<Package>> {
-        let mut args = vec!["search", query, "--accept-source-agreements"];
-        if let Some(src) = source {
-            args.push("--source");
-            args.push(src);
-        }
-        let output = self.run_winget(&args).await?;
+    async fn search(&self, request: &SearchRequest, source: Option<&str>) -> Result<Vec<Package>> {
+        let args = Self::search_args(request, source);
+        let output = self.run_winget(&args, CommandClass::Search).await?;
         Ok(Self::dedupe_packages(
             self.parse_packages_from_table(&output),
         ))
@@ -750,7 +1428,7 @@ impl WingetBackend for CliBackend {
 
     async fn list_installed(&self, source: Option<&str>) -> Result<Vec<Package>> {
         let args = Self::list_installed_args(source);
-        let output = self.run_winget(&args).await?;
+        let output = self.run_winget(&args, CommandClass::List).await?;
         Ok(Self::dedupe_packages(
             self.parse_packages_from_table(&output),
         ))
@@ -758,7 +1436,7 @@ impl WingetBackend for CliBackend {
 
     async fn list_upgrades(&self, source: Option<&str>) -> Result<Vec<Package>> {
         let args = Self::list_upgrades_args(source);
-        let output = self.run_winget(&args).await?;
+        let output = self.run_winget(&args, CommandClass::List).await?;
         Ok(Self::dedupe_packages(
             self.parse_packages_from_table(&output),
         ))
@@ -766,70 +1444,136 @@ impl WingetBackend for CliBackend {
 
     async fn show(&self, id: &str) -> Result<PackageDetail> {
         let output = self
-            .run_winget(&["show", "--id", id, "--exact", "--accept-source-agreements"])
+            .run_winget(
+                &["show", "--id", id, "--exact", "--accept-source-agreements"],
+                CommandClass::Show,
+            )
             .await?;
-        Ok(self.parse_show_output(&output))
+        let mut detail = self.parse_show_output(&output);
+        detail.raw = sanitize_text(&output);
+        Ok(detail)
     }
 
-    async fn install(&self, id: &str, version: Option<&str>) -> Result<String> {
-        let mut args = vec![
-            "install",
-            "--id",
-            id,
-            "--accept-source-agreements",
-            "--accept-package-agreements",
-        ];
-        if let Some(v) = version {
-            args.push("--version");
-            args.push(v);
-        }
-        self.run_winget_strict(&args).await
+    async fn show_versions(&self, id: &str) -> Result<Vec<String>> {
+        let output = self
+            .run_winget(
+                &[
+                    "show",
+                    "--id",
+                    id,
+                    "--exact",
+                    "--versions",
+                    "--accept-source-agreements",
+                ],
+                CommandClass::Show,
+            )
+            .await?;
+        Ok(Self::parse_versions_from_table(&output))
     }
 
-    async fn uninstall(&self, id: &str) -> Result<String> {
-        self.run_winget_strict(&["uninstall", "--id", id, "--accept-source-agreements"])
-            .await
+    async fn install(
+        &self,
+        id: &str,
+        version: Option<&str>,
+        options: &InstallOptions,
+        progress: &ProgressFn,
+    ) -> Result<String> {
+        let args = Self::install_args(id, version, options);
+        self.run_winget_streaming(&args, progress).await
+    }
+
+    async fn uninstall(
+        &self,
+        id: &str,
+        options: &UninstallOptions,
+        progress: &ProgressFn,
+    ) -> Result<String> {
+        let args = Self::uninstall_args(id, options);
+        self.run_winget_streaming(&args, progress).await
     }
 
     // Try the usual ID flow first, then fallback to an exact name match
-    // for entries whose ID was truncated in winget's tabular output.
-    async fn upgrade(&self, query: &str) -> Result<String> {
+    // for entries whose ID was truncated in winget's tabular output. Only a
+    // missing package warrants the second run: after a timeout or a failed
+    // install it would start another installer.
+    async fn upgrade(&self, query: &str, progress: &ProgressFn) -> Result<String> {
         let by_id = Self::upgrade_args_by_id(query);
-        match self.run_winget_strict(&by_id).await {
+        match self.run_winget_streaming(&by_id, progress).await {
             Ok(output) => Ok(output),
+            Err(id_err) if id_err.kind() != Some(WingetErrorKind::NoPackageFound) => Err(id_err),
             Err(id_err) => {
                 let by_name = Self::upgrade_args_by_name(query);
-                self.run_winget_strict(&by_name).await.map_err(|name_err| {
-                    anyhow::anyhow!(
-                        "upgrade by id failed: {id_err}; fallback by name failed: {name_err}"
-                    )
-                })
+                self.run_winget_streaming(&by_name, progress)
+                    .await
+                    .map_err(|name_err| {
+                        // A name miss only means the id was right after all,
+                        // so the first failure is the one worth reporting.
+                        if name_err.kind() == Some(WingetErrorKind::NoPackageFound) {
+                            id_err
+                        } else {
+                            name_err
+                        }
+                    })
             }
         }
     }
 
     async fn list_pins(&self) -> Result<Vec<PackagePin>> {
-        let output = self.run_winget(&["pin", "list"]).await?;
+        let output = self.run_winget(&["pin", "list"], CommandClass::Pin).await?;
         Ok(self.parse_pins_from_table(&output))
     }
 
-    async fn pin(&self, id: &str) -> Result<String> {
-        self.run_winget_strict(&Self::pin_add_args(id)).await
+    async fn pin(&self, id: &str, pin: &PinState) -> Result<String> {
+        self.run_winget_strict(&Self::pin_add_args(id, pin), CommandClass::Pin)
+            .await
     }
 
     async fn unpin(&self, id: &str) -> Result<String> {
-        self.run_winget_strict(&Self::pin_remove_args(id)).await
+        self.run_winget_strict(&Self::pin_remove_args(id), CommandClass::Pin)
+            .await
+    }
+
+    async fn reset_pins(&self) -> Result<String> {
+        self.run_winget_strict(&Self::pin_reset_args(), CommandClass::Pin)
+            .await
     }
 
     async fn list_sources(&self) -> Result<Vec<Source>> {
-        let output = self.run_winget(&["source", "list"]).await?;
+        let output = self
+            .run_winget(&["source", "list"], CommandClass::List)
+            .await?;
         Ok(self.parse_sources_from_table(&output))
     }
+
+    async fn add_source(&self, source: &Source) -> Result<String> {
+        self.run_winget_strict(&Self::source_add_args(source), CommandClass::List)
+            .await
+    }
+
+    async fn remove_source(&self, name: &str) -> Result<String> {
+        self.run_winget_strict(&Self::source_remove_args(name), CommandClass::List)
+            .await
+    }
+
+    async fn reset_sources(&self) -> Result<String> {
+        self.run_winget_strict(&Self::source_reset_args(), CommandClass::List)
+            .await
+    }
+
+    async fn update_source(&self, name: &str) -> Result<String> {
+        self.run_winget_strict(&Self::source_update_args(name), CommandClass::List)
+            .await
+    }
+
+    fn process_depth(&self) -> ProcessDepth {
+        self.limiter.depth()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::InstallScope;
 
     // ── find_table_separator ──────────────────────────────────────────────────
 
@@ -973,6 +1717,29 @@ Quelle: winget
         assert_eq!(detail.license, "Proprietary");
     }
 
+    #[test]
+    fn parse_versions_table() {
+        let output = "\
+-
+Found Git [Git.Git]
+Version
+-------
+2.47.1
+2.47.0.2
+2.46.0
+";
+        assert_eq!(
+            CliBackend::parse_versions_from_table(output),
+            vec!["2.47.1", "2.47.0.2", "2.46.0"]
+        );
+    }
+
+    #[test]
+    fn parse_versions_table_without_separator_is_empty() {
+        let output = "No package found matching input criteria.";
+        assert!(CliBackend::parse_versions_from_table(output).is_empty());
+    }
+
     #[test]
     fn parse_german_list_table_without_available() {
         let backend = CliBackend::new();
@@ -1007,6 +1774,46 @@ Google Chrome                  Google.Chrome               131.0.6  winget
         assert!(args.ends_with(&["--source", "winget"]));
     }
 
+    #[test]
+    fn install_args_append_options_after_version() {
+        let options = InstallOptions {
+            scope: InstallScope::Machine,
+            override_args: Some("/S".to_string()),
+            ..InstallOptions::default()
+        };
+        let args = CliBackend::install_args("Git.Git", Some("2.45.0"), &options);
+        assert_eq!(&args[..3], &["install", "--id", "Git.Git"]);
+        assert!(args.ends_with(&[
+            "--version",
+            "2.45.0",
+            "--scope",
+            "machine",
+            "--override",
+            "/S"
+        ]));
+    }
+
+    #[test]
+    fn uninstall_args_append_options() {
+        let options = UninstallOptions {
+            all_versions: true,
+            purge: true,
+            ..UninstallOptions::default()
+        };
+        let args = CliBackend::uninstall_args("Foo.Portable", &options);
+        assert_eq!(
+            args,
+            vec![
+                "uninstall",
+                "--id",
+                "Foo.Portable",
+                "--accept-source-agreements",
+                "--all-versions",
+                "--purge",
+            ]
+        );
+    }
+
     #[test]
     fn upgrade_args_by_id_use_id_flag() {
         let args = CliBackend::upgrade_args_by_id("Microsoft.Azure.FunctionsCoreTools");
@@ -1025,13 +1832,73 @@ Google Chrome                  Google.Chrome               131.0.6  winget
         assert!(!args.contains(&"--id"));
     }
 
+    #[test]
+    fn search_args_map_qualifiers_to_flags() {
+        let request = SearchRequest {
+            query: "editor".to_string(),
+            tag: Some("rust".to_string()),
+            command: Some("hx".to_string()),
+            ..SearchRequest::default()
+        };
+        assert_eq!(
+            CliBackend::search_args(&request, Some("winget")),
+            vec![
+                "search",
+                "--query",
+                "editor",
+                "--tag",
+                "rust",
+                "--command",
+                "hx",
+                "--accept-source-agreements",
+                "--source",
+                "winget",
+            ]
+        );
+    }
+
+    #[test]
+    fn search_args_exact_id_without_free_text() {
+        let request = SearchRequest {
+            id: Some("Microsoft.Edge".to_string()),
+            exact: true,
+            ..SearchRequest::default()
+        };
+        assert_eq!(
+            CliBackend::search_args(&request, None),
+            vec![
+                "search",
+                "--id",
+                "Microsoft.Edge",
+                "--exact",
+                "--accept-source-agreements",
+            ]
+        );
+    }
+
     #[test]
     fn pin_add_args_use_blocking_mode() {
-        let args = CliBackend::pin_add_args("7zip.7zip");
+        let args = CliBackend::pin_add_args("7zip.7zip", &PinState::Blocking);
         assert!(args.contains(&"--blocking"));
         assert!(!args.contains(&"--installed"));
     }
 
+    #[test]
+    fn pin_add_args_regular_pin_has_no_kind_flag() {
+        let args = CliBackend::pin_add_args("7zip.7zip", &PinState::Pinned);
+        assert!(!args.contains(&"--blocking"));
+        assert!(!args.contains(&"--version"));
+        assert!(args.contains(&"--force"));
+    }
+
+    #[test]
+    fn pin_add_args_gating_pin_passes_version_range() {
+        let gating = PinState::Gating("1.2.*".to_string());
+        let args = CliBackend::pin_add_args("7zip.7zip", &gating);
+        assert!(args.windows(2).any(|w| w == ["--version", "1.2.*"]));
+        assert!(!args.contains(&"--blocking"));
+    }
+
     #[test]
     fn pin_remove_args_do_not_use_installed_flag() {
         let args = CliBackend::pin_remove_args("7zip.7zip");
@@ -1039,6 +1906,65 @@ Google Chrome                  Google.Chrome               131.0.6  winget
         assert!(args.contains(&"--disable-interactivity"));
     }
 
+    #[test]
+    fn source_add_args_include_type_only_when_set() {
+        let mut source = Source {
+            name: "contoso".to_string(),
+            url: "https://pkgs.contoso.com/api".to_string(),
+            source_type: "Microsoft.Rest".to_string(),
+        };
+        assert_eq!(
+            CliBackend::source_add_args(&source),
+            vec![
+                "source",
+                "add",
+                "--name",
+                "contoso",
+                "--arg",
+                "https://pkgs.contoso.com/api",
+                "--type",
+                "Microsoft.Rest",
+                "--accept-source-agreements",
+                "--disable-interactivity",
+            ]
+        );
+        source.source_type.clear();
+        assert!(!CliBackend::source_add_args(&source).contains(&"--type"));
+    }
+
+    #[test]
+    fn source_remove_reset_and_update_args() {
+        assert_eq!(
+            CliBackend::source_remove_args("contoso"),
+            vec![
+                "source",
+                "remove",
+                "--name",
+                "contoso",
+                "--disable-interactivity"
+            ]
+        );
+        assert!(CliBackend::source_reset_args().contains(&"--force"));
+        assert_eq!(
+            CliBackend::source_update_args("winget"),
+            vec![
+                "source",
+                "update",
+                "--name",
+                "winget",
+                "--disable-interactivity"
+            ]
+        );
+    }
+
+    #[test]
+    fn pin_reset_args_force_the_reset() {
+        assert_eq!(
+            CliBackend::pin_reset_args(),
+            vec!["pin", "reset", "--force", "--disable-interactivity"]
+        );
+    }
+
     // ── compare_versions_like ──────────────────────────────────────────────
 
     #[test]
@@ -1130,6 +2056,7 @@ Google Chrome                  Google.Chrome               131.0.6  winget
             version: version.to_string(),
             available_version: available_version.to_string(),
             source: source.to_string(),
+            match_reason: String::new(),
             pin_state: PinState::None,
         }
     }
@@ -1197,6 +2124,7 @@ Google Chrome                  Google.Chrome               131.0.6  winget
                 version: "2.41.5".to_string(),
                 source: "winget".to_string(),
                 available_version: String::new(),
+                match_reason: String::new(),
                 pin_state: PinState::None,
             },
             Package {
@@ -1205,6 +2133,7 @@ Google Chrome                  Google.Chrome               131.0.6  winget
                 version: "2.43.1".to_string(),
                 source: "winget".to_string(),
                 available_version: String::new(),
+                match_reason: String::new(),
                 pin_state: PinState::None,
             },
         ];
@@ -1223,6 +2152,7 @@ Google Chrome                  Google.Chrome               131.0.6  winget
             version: version.to_string(),
             source: source.to_string(),
             available_version: String::new(),
+            match_reason: String::new(),
             pin_state: PinState::None,
         };
         let packages = vec![make("1.0", "winget"), make("1.1", "Winget")];
@@ -1239,6 +2169,7 @@ Google Chrome                  Google.Chrome               131.0.6  winget
             version: "1.0".to_string(),
             source: "winget".to_string(),
             available_version: String::new(),
+            match_reason: String::new(),
             pin_state: PinState::None,
         };
         let packages = vec![make("C.Pkg"), make("A.Pkg"), make("B.Pkg")];
@@ -1278,6 +2209,7 @@ Google Chrome                  Google.Chrome               131.0.6  winget
                 version: "1.0.0".to_string(),
                 available_version: String::new(),
                 source: "winget".to_string(),
+                match_reason: String::new(),
                 pin_state: PinState::None,
             },
             Package {
@@ -1286,6 +2218,7 @@ Google Chrome                  Google.Chrome               131.0.6  winget
                 version: "1.0.0".to_string(),
                 available_version: "1.1.0".to_string(),
                 source: "winget".to_string(),
+                match_reason: String::new(),
                 pin_state: PinState::None,
             },
         ];
@@ -1313,6 +2246,10 @@ Contoso App     Contoso.App             winget                    Blocking
             PinState::Gating(ref v) if v == "2.45.*"
         ));
         assert!(matches!(pins[2].pin_state, PinState::Blocking));
+        assert_eq!(pins[1].name, "Git");
+        assert_eq!(pins[1].pinned_version, "2.45.*");
+        assert_eq!(pins[2].name, "Contoso App");
+        assert_eq!(pins[2].source, "winget");
     }
 
     #[test]
@@ -1566,6 +2503,37 @@ Google Chrome               Google.Chrome       131.0.6778
         assert_eq!(packages[2].id, "Google.Chrome");
     }
 
+    #[test]
+    fn parse_search_table_with_match_column() {
+        let backend = CliBackend::new();
+        let output = "\
+Name          Id                  Version  Match         Source
+----------------------------------------------------------------
+jq            jqlang.jq           1.7.1    Command: jq   winget
+JSON Viewer   Example.JsonViewer  2.0      Tag: json     winget
+Json          Example.Json        3.1                    winget
+";
+        let packages = backend.parse_packages_from_table(output);
+        assert_eq!(packages.len(), 3);
+        assert_eq!(packages[0].match_reason, "Command: jq");
+        assert_eq!(packages[1].match_reason, "Tag: json");
+        assert_eq!(packages[1].source, "winget");
+        assert_eq!(packages[2].match_reason, "");
+    }
+
+    #[test]
+    fn parse_table_without_match_column_leaves_reason_empty() {
+        let backend = CliBackend::new();
+        let output = "\
+Name          Id              Version  Source
+---------------------------------------------
+Google Chrome Google.Chrome   131.0    winget
+";
+        let packages = backend.parse_packages_from_table(output);
+        assert_eq!(packages[0].match_reason, "");
+        assert_eq!(packages[0].source, "winget");
+    }
+
     #[test]
     fn sanitize_clean_input_fast_path() {
         // Clean ASCII — fast path returns exact content without char iteration
@@ -1773,6 +2741,12 @@ Release Notes Url: https://github.com/gitui-org/gitui/releases/tag/v0.28.1
             detail.name, "gitui",
             "name must not be overwritten by release notes"
         );
+        assert_eq!(
+            detail.release_notes,
+            "Changed\n- support proper pre-push hook (#2809)\nFixed\n\
+             - fix extremely slow status loading [@DannyStoll1] (#2823)\n\
+             - fix panic when renaming or updating remote URL [@xvchris] (#2868)"
+        );
         assert_eq!(detail.id, "StephanDilly.gitui");
         assert_eq!(detail.publisher, "Stephan Dilly");
         assert_eq!(
@@ -1781,6 +2755,134 @@ Release Notes Url: https://github.com/gitui-org/gitui/releases/tag/v0.28.1
         );
     }
 
+    #[test]
+    fn parse_show_output_manifest_metadata_and_nested_blocks() {
+        let backend = CliBackend::new();
+        let output = "\
+Found Windows Terminal [Microsoft.WindowsTerminal]
+Version: 1.21.2361.0
+Publisher: Microsoft Corporation
+Publisher Support Url: https://github.com/microsoft/terminal/issues
+Author: Microsoft Corporation
+Moniker: wt
+Description: The new Windows Terminal.
+Homepage: https://github.com/microsoft/terminal
+License: MIT
+Privacy Url: https://privacy.microsoft.com
+Copyright: Copyright (c) Microsoft Corporation
+Documentation:
+  Docs: https://learn.microsoft.com/windows/terminal
+Tags:
+  console
+  command-line
+  terminal
+Agreements:
+  Pricing: Free
+  Terms of Transaction: https://aka.ms/microsoft-store-terms-of-transaction
+Dependencies:
+  - Windows Features:
+      NetFx3
+  - Package Dependencies:
+      Microsoft.VCLibs.Desktop.14
+      Microsoft.UI.Xaml.2.8 [>= 8.2306.22001.0]
+Installer:
+  Installer Type: msix
+  Installer Url: https://github.com/microsoft/terminal/releases/download/v1.21.2361.0/x.msixbundle
+  Installer SHA256: 06b3c6b5ea1a4e0a7b7b1c4bbd8f3a7d7e4c7a59b4b5c9a1c0e8f1e2d3c4b5a6
+  Release Date: 2024-09-26
+  Offline Distribution Supported: true
+";
+        let detail = backend.parse_show_output(output);
+        assert_eq!(
+            detail.name, "Windows Terminal",
+            "bracketed dependency items must not be taken for the header"
+        );
+        assert_eq!(detail.author, "Microsoft Corporation");
+        assert_eq!(detail.moniker, "wt");
+        assert_eq!(detail.copyright, "Copyright (c) Microsoft Corporation");
+        assert_eq!(detail.privacy_url, "https://privacy.microsoft.com");
+        assert_eq!(
+            detail.support_url,
+            "https://github.com/microsoft/terminal/issues"
+        );
+        assert_eq!(
+            detail.documentation,
+            vec![(
+                "Docs".to_string(),
+                "https://learn.microsoft.com/windows/terminal".to_string()
+            )]
+        );
+        assert_eq!(detail.tags, vec!["console", "command-line", "terminal"]);
+        assert_eq!(detail.agreements.len(), 2);
+        assert_eq!(
+            detail.agreements[1].1,
+            "https://aka.ms/microsoft-store-terms-of-transaction"
+        );
+        assert_eq!(
+            detail.dependencies,
+            vec![
+                DependencyGroup {
+                    kind: "Windows Features".to_string(),
+                    items: vec!["NetFx3".to_string()],
+                },
+                DependencyGroup {
+                    kind: "Package Dependencies".to_string(),
+                    items: vec![
+                        "Microsoft.VCLibs.Desktop.14".to_string(),
+                        "Microsoft.UI.Xaml.2.8 [>= 8.2306.22001.0]".to_string(),
+                    ],
+                },
+            ]
+        );
+        assert_eq!(detail.installer_type, "msix");
+        assert!(detail.installer_url.ends_with("x.msixbundle"));
+        assert_eq!(detail.installer_sha256.len(), 64);
+        assert_eq!(detail.release_date, "2024-09-26");
+        assert_eq!(detail.license, "MIT", "top-level keys still parse");
+    }
+
+    #[test]
+    fn parse_show_output_release_notes_keep_paragraphs_and_nesting() {
+        let backend = CliBackend::new();
+        let output = "\
+Found Example [Example.App]
+Version: 2.0.0
+Release Notes:
+  Highlights
+
+  - New renderer
+    - 2x faster startup
+  - Dark mode
+
+License: MIT
+";
+        let detail = backend.parse_show_output(output);
+        assert_eq!(
+            detail.release_notes,
+            "Highlights\n\n- New renderer\n  - 2x faster startup\n- Dark mode"
+        );
+        assert_eq!(detail.license, "MIT", "trailing blank line ends the notes");
+    }
+
+    #[test]
+    fn show_block_pairs_joins_deeper_continuation_lines() {
+        let block = [
+            "  Seizure Warning: This product contains flashing lights",
+            "    that may trigger seizures.",
+            "  Pricing: Free",
+        ];
+        assert_eq!(
+            CliBackend::show_block_pairs(&block),
+            vec![
+                (
+                    "Seizure Warning".to_string(),
+                    "This product contains flashing lights that may trigger seizures.".to_string()
+                ),
+                ("Pricing".to_string(), "Free".to_string()),
+            ]
+        );
+    }
+
     #[test]
     fn parse_table_no_separator_returns_empty() {
         let backend = CliBackend::new();
@@ -2112,4 +3214,273 @@ Node.js                    OpenJS.NodeJS              20.*            Gating
         assert_eq!(pins[1].id, "OpenJS.NodeJS");
         assert_eq!(pins[1].pin_state, PinState::Gating("20.*".to_string()));
     }
+
+    // ── ProgressTracker ───────────────────────────────────────────────────────
+
+    fn track(chunks: &[&[u8]]) -> Vec<OperationProgress> {
+        let events = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = events.clone();
+        let emit = move |event| sink.lock().unwrap().push(event);
+        let mut tracker = ProgressTracker::default();
+        for chunk in chunks {
+            tracker.feed(chunk, &emit);
+        }
+        tracker.finish(&emit);
+        let events = events.lock().unwrap().clone();
+        events
+    }
+
+    #[test]
+    fn progress_tracker_reports_phases_percent_and_output() {
+        let output = "Found Git [Git.Git] Version 2.47.0\r\n\
+                      Downloading https://example.com/git.exe\r\n\
+                      \r  ██████▒▒▒▒▒▒▒▒▒▒  10.0 MB / 40.0 MB\
+                      \r  ████████████████  40.0 MB / 40.0 MB\r\n\
+                      Successfully verified installer hash\r\n\
+                      Starting package install...\r\n\
+                      \r  ████████▒▒▒▒▒▒▒▒  50%\r\n\
+                      Successfully installed\r\n";
+        let events = track(&[output.as_bytes()]);
+        assert_eq!(
+            events,
+            vec![
+                OperationProgress::Output("Found Git [Git.Git] Version 2.47.0".to_string()),
+                OperationProgress::Phase("Downloading".to_string()),
+                OperationProgress::Output("Downloading https://example.com/git.exe".to_string()),
+                OperationProgress::Percent(25),
+                OperationProgress::Percent(100),
+                OperationProgress::Phase("Verifying".to_string()),
+                OperationProgress::Output("Successfully verified installer hash".to_string()),
+                OperationProgress::Phase("Installing".to_string()),
+                OperationProgress::Output("Starting package install...".to_string()),
+                OperationProgress::Percent(50),
+                OperationProgress::Output("Successfully installed".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn progress_tracker_skips_spinner_frames() {
+        let events = track(&[b"   - \r   \\ \r   | \r   / \r\n", b"Done\n"]);
+        assert_eq!(events, vec![OperationProgress::Output("Done".to_string())]);
+    }
+
+    #[test]
+    fn progress_tracker_joins_lines_split_across_chunks() {
+        let events = track(&[b"Starting package un", b"install...\r", b"\nok"]);
+        assert_eq!(
+            events,
+            vec![
+                OperationProgress::Phase("Uninstalling".to_string()),
+                OperationProgress::Output("Starting package uninstall...".to_string()),
+                OperationProgress::Output("ok".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn progress_tracker_does_not_repeat_unchanged_percent() {
+        let events = track(&[b"\r  \xe2\x96\x88 10%\r  \xe2\x96\x88 10%\r  \xe2\x96\x88 11%"]);
+        assert_eq!(
+            events,
+            vec![
+                OperationProgress::Percent(10),
+                OperationProgress::Percent(11)
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_progress_percent_ignores_text_mentioning_percent() {
+        assert_eq!(parse_progress_percent("Saved 50% on this install"), None);
+        assert_eq!(parse_progress_percent("  ███▒▒▒  75%"), Some(75));
+        assert_eq!(parse_progress_percent("100%"), Some(100));
+    }
+
+    #[test]
+    fn parse_progress_percent_from_byte_counts() {
+        assert_eq!(parse_progress_percent("██▒▒  512 KB / 1.00 MB"), Some(50));
+        assert_eq!(parse_progress_percent("██▒▒  1 MB / 0 MB"), None);
+    }
+
+    #[test]
+    fn detect_phase_is_case_insensitive_and_prefix_only() {
+        assert_eq!(detect_phase("DOWNLOADING https://x"), Some("Downloading"));
+        assert_eq!(
+            detect_phase("Installing dependencies:"),
+            Some("Installing dependencies")
+        );
+        assert_eq!(detect_phase("Package was not downloading"), None);
+    }
+
+    // ── timeouts ──────────────────────────────────────────────────────────────
+
+    /// A scratch directory that is removed when dropped.
+    #[cfg(unix)]
+    struct StubDir(std::path::PathBuf);
+
+    #[cfg(unix)]
+    impl StubDir {
+        fn new(name: &str) -> Self {
+            let path =
+                std::env::temp_dir().join(format!("winget-tui-stub-{}-{name}", std::process::id()));
+            let _ = std::fs::remove_dir_all(&path);
+            std::fs::create_dir_all(&path).unwrap();
+            Self(path)
+        }
+    }
+
+    #[cfg(unix)]
+    impl Drop for StubDir {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
+    /// Backend that runs `script` with `sh` in place of winget. The script
+    /// lives in the returned directory, which must outlive the backend's calls.
+    #[cfg(unix)]
+    fn stub_winget(name: &str, script: &str, timeouts: Timeouts) -> (CliBackend, StubDir) {
+        let dir = StubDir::new(name);
+        let path = dir.0.join("winget.sh");
+        std::fs::write(&path, script).unwrap();
+        let backend = CliBackend {
+            program: "sh".to_string(),
+            program_args: vec![path.display().to_string()],
+            timeouts,
+            limiter: ProcessLimiter::new(DEFAULT_READ_PROCESSES, DEFAULT_WRITE_PROCESSES),
+        };
+        (backend, dir)
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn hung_show_is_killed_after_its_timeout() {
+        let marker_dir = StubDir::new("show-marker");
+        let marker = marker_dir.0.join("finished");
+        let timeouts = Timeouts {
+            show: Duration::from_millis(100),
+            ..Timeouts::default()
+        };
+        let (backend, _dir) = stub_winget(
+            "show",
+            &format!("sleep 0.3\ntouch '{}'\n", marker.display()),
+            timeouts,
+        );
+
+        let err = backend.show("Foo.Bar").await.unwrap_err();
+        assert!(
+            matches!(&err, WingetError::Timeout { command, .. } if command == "show"),
+            "expected a timeout, got {err}"
+        );
+        assert_eq!(
+            err.to_string(),
+            "winget show timed out after 0.1s and was stopped"
+        );
+
+        tokio::time::sleep(Duration::from_millis(500)).await;
+        assert!(!marker.exists(), "the stub should have been killed");
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn hung_install_times_out_while_streaming() {
+        let timeouts = Timeouts {
+            operation: Duration::from_millis(100),
+            ..Timeouts::default()
+        };
+        let (backend, _dir) = stub_winget("install", "echo Downloading\nsleep 10\n", timeouts);
+        let err = backend
+            .install("Foo.Bar", None, &InstallOptions::default(), &|_| {})
+            .await
+            .unwrap_err();
+        assert!(matches!(err, WingetError::Timeout { .. }), "got {err}");
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn timed_out_upgrade_does_not_retry_by_name() {
+        let timeouts = Timeouts {
+            operation: Duration::from_millis(100),
+            ..Timeouts::default()
+        };
+        let (backend, dir) = stub_winget("upgrade", "", timeouts);
+        let log = dir.0.join("calls");
+        std::fs::write(
+            dir.0.join("winget.sh"),
+            format!("echo \"$@\" >> '{}'\nsleep 1\n", log.display()),
+        )
+        .unwrap();
+        let err = backend.upgrade("Foo.Bar", &|_| {}).await.unwrap_err();
+        assert!(matches!(err, WingetError::Timeout { .. }), "got {err}");
+        let calls = std::fs::read_to_string(&log).unwrap();
+        assert_eq!(calls.lines().count(), 1, "only the by-id run: {calls}");
+        assert!(calls.contains("--id"));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn command_within_its_timeout_succeeds() {
+        let timeouts = Timeouts {
+            pin: Duration::from_secs(10),
+            ..Timeouts::default()
+        };
+        let (backend, _dir) = stub_winget("pin", "echo Pin added\n", timeouts);
+        assert_eq!(
+            backend.pin("Foo.Bar", &PinState::Pinned).await.unwrap(),
+            "Pin added"
+        );
+    }
+
+    // ── process limits ────────────────────────────────────────────────────────
+
+    /// Sample the backend's depth until `check` passes or a second elapses.
+    #[cfg(unix)]
+    async fn wait_for_depth(backend: &CliBackend, check: impl Fn(ProcessDepth) -> bool) {
+        for _ in 0..100 {
+            if check(backend.process_depth()) {
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        panic!("depth never matched: {:?}", backend.process_depth());
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn reads_beyond_the_limit_wait_for_a_slot() {
+        let (mut backend, _dir) = stub_winget("reads", "sleep 0.3\n", Timeouts::default());
+        backend.limiter = ProcessLimiter::new(2, 1);
+        let shows =
+            async { tokio::join!(backend.show("A"), backend.show("B"), backend.show("C"),) };
+        let observe = async {
+            wait_for_depth(&backend, |depth| {
+                depth.reads_running == 2 && depth.reads_waiting == 1
+            })
+            .await;
+        };
+        let ((a, b, c), ()) = tokio::join!(shows, observe);
+        assert!(a.is_ok() && b.is_ok() && c.is_ok());
+        assert_eq!(backend.process_depth(), ProcessDepth::default());
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn mutations_do_not_queue_behind_reads() {
+        let (mut backend, _dir) = stub_winget("lanes", "sleep 0.3\n", Timeouts::default());
+        backend.limiter = ProcessLimiter::new(1, 1);
+        let read = backend.show("A");
+        let write = async {
+            wait_for_depth(&backend, |depth| depth.reads_running == 1).await;
+            backend.pin("Foo.Bar", &PinState::Pinned).await
+        };
+        let observe = async {
+            wait_for_depth(&backend, |depth| {
+                depth.reads_running == 1 && depth.writes_running == 1 && depth.waiting() == 0
+            })
+            .await;
+        };
+        let (read, write, ()) = tokio::join!(read, write, observe);
+        assert!(read.is_ok() && write.is_ok());
+    }
 }
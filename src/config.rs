@@ -6,59 +6,201 @@
 ///
 /// Supported keys (all optional):
 /// ```toml
-/// default_view       = "installed"   # "installed" | "search" | "upgrades"
-/// default_source     = "all"         # "all" | "winget" | "msstore"
+/// default_view       = "installed"   # "installed" | "search" | "upgrades" | "pins"
+/// default_source     = "all"         # "all" or any configured source, e.g. "winget"
 /// default_sort       = "name"        # name | name_desc | id | id_desc | version | version_desc | none
 /// default_pin_filter = "all"         # "all" | "pinned" | "hide_pinned"
-/// theme              = "original"    # "original" | "retro" | "nord" | "terminal"
+/// theme              = "original"    # a preset (see `ThemeName::NAMES`), "auto", or a theme file
+///
+/// # Seconds before a winget command is killed, per command class
+/// timeout_list       = 120
+/// timeout_search     = 60
+/// timeout_show       = 30
+/// timeout_pin        = 60
+/// timeout_operation  = 1800          # install / uninstall / upgrade
+///
+/// # The same timeouts can be grouped in a table instead
+/// [timeouts]
+/// list = 120
+/// operation = 1800
+///
+/// # Instead of `theme = "..."`: a preset with some colors replaced, as hex,
+/// # a name, or an index into the terminal's 256-color palette
+/// [theme]
+/// base = "nord"
+/// accent = "#88c0d0"
+/// selection = "magenta"
+/// surface = 236
 /// ```
+///
+/// `theme = "name"` also loads `themes/name.toml` next to the config file,
+/// which holds the same keys as the `[theme]` table.
+///
+/// The file is parsed as TOML. Syntax errors, unknown keys and invalid
+/// values are reported as [`ConfigWarning`]s with their line number, and the
+/// affected setting keeps its default.
+///
+/// Command-line flags such as `--view` and `--config` take precedence for
+/// one session; see [`Overrides`] and `cli.rs`.
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use toml::de::{DeString, DeTable, DeValue};
+use toml::Spanned;
+
 use crate::app::AppMode;
+use crate::cli_backend::Timeouts;
 use crate::models::{PinFilter, SortDir, SortField, SourceFilter};
-use crate::theme::ThemeName;
+use ratatui::style::Color;
+
+use crate::theme::{self, Theme, ThemeName};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Config {
+    /// The preset the theme starts from
     pub theme: ThemeName,
+    /// Fields of the preset replaced by a `[theme]` table or theme file,
+    /// by their names in [`Theme::FIELDS`]
+    pub theme_colors: Vec<(String, Color)>,
+    /// Where `theme = "name"` looks for `name.toml`
+    pub themes_dir: Option<PathBuf>,
     pub default_view: AppMode,
     pub default_source: SourceFilter,
     pub default_sort_field: SortField,
     pub default_sort_dir: SortDir,
     pub default_pin_filter: PinFilter,
+    pub timeouts: Timeouts,
 }
 
 impl Default for Config {
     fn default() -> Self {
         Self {
             theme: ThemeName::Original,
+            theme_colors: Vec::new(),
+            themes_dir: None,
             default_view: AppMode::Installed,
             default_source: SourceFilter::All,
             default_sort_field: SortField::None,
             default_sort_dir: SortDir::Asc,
             default_pin_filter: PinFilter::All,
+            timeouts: Timeouts::default(),
         }
     }
 }
 
+/// A problem found in the config file or a theme file it loads. The setting
+/// it concerns keeps its default.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigWarning {
+    /// File name, e.g. "config.toml" or "themes/mine.toml"
+    pub file: String,
+    /// 1-based line in that file
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ConfigWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} line {}: {}", self.file, self.line, self.message)
+    }
+}
+
 impl Config {
     /// Load config from the platform config path, falling back to defaults
-    /// for any missing or unrecognised keys.  Never returns an error — a
-    /// missing or malformed file is silently ignored.
-    pub fn load() -> Self {
-        let path = Self::config_path();
-        let text = match path.and_then(|p| std::fs::read_to_string(p).ok()) {
-            Some(t) => t,
-            None => return Self::default(),
-        };
-        Self::parse(&text)
+    /// for anything missing or invalid. A missing file is not a problem; an
+    /// unreadable or malformed one is described by the returned warnings.
+    pub fn load() -> (Self, Vec<ConfigWarning>) {
+        match Self::path() {
+            Some(path) => Self::load_or_default(&path),
+            None => (Self::default(), Vec::new()),
+        }
+    }
+
+    /// [`load`] for a given path: defaults when the file does not exist, and
+    /// defaults plus a warning when it exists but cannot be read.
+    ///
+    /// [`load`]: Config::load
+    fn load_or_default(path: &Path) -> (Self, Vec<ConfigWarning>) {
+        match Self::load_from(path) {
+            Ok(loaded) => loaded,
+            Err(e) => {
+                let cfg = Self {
+                    themes_dir: themes_dir(path),
+                    ..Self::default()
+                };
+                let warnings = if e.kind() == std::io::ErrorKind::NotFound {
+                    Vec::new()
+                } else {
+                    vec![ConfigWarning {
+                        file: file_name(path),
+                        line: 1,
+                        message: format!("cannot read file: {e}; using defaults"),
+                    }]
+                };
+                (cfg, warnings)
+            }
+        }
+    }
+
+    /// Load config from a file named on the command line. Unlike [`load`],
+    /// a file that cannot be read is an error.
+    ///
+    /// [`load`]: Config::load
+    pub fn load_from(path: &Path) -> std::io::Result<(Self, Vec<ConfigWarning>)> {
+        let text = std::fs::read_to_string(path)?;
+        let file = file_name(path);
+        Ok(Self::parse_file(&text, &file, themes_dir(path)))
+    }
+
+    /// Apply command-line overrides on top of the file's settings. A theme
+    /// file named by `--theme` may add warnings; a theme that does not exist
+    /// is an error.
+    pub fn with_overrides(
+        mut self,
+        overrides: Overrides,
+        warnings: &mut Vec<ConfigWarning>,
+    ) -> Result<Self, String> {
+        if let Some(name) = overrides.theme {
+            let mut parser = Parser::new("", String::new(), self.themes_dir.as_deref());
+            if !parser.named_theme(&name, 0) {
+                return Err(format!(
+                    "unknown theme '{name}'; expected {}",
+                    parser.theme_names()
+                ));
+            }
+            self.theme = parser.cfg.theme;
+            self.theme_colors = parser.cfg.theme_colors;
+            warnings.extend(parser.warnings);
+        }
+        if let Some(view) = overrides.view {
+            self.default_view = view;
+        }
+        if let Some(source) = overrides.source {
+            self.default_source = source;
+        }
+        if let Some((field, dir)) = overrides.sort {
+            self.default_sort_field = field;
+            self.default_sort_dir = dir;
+        }
+        if let Some(pin_filter) = overrides.pin_filter {
+            self.default_pin_filter = pin_filter;
+        }
+        Ok(self)
+    }
+
+    /// The configured preset with any custom colors applied.
+    pub fn build_theme(&self) -> Theme {
+        Theme::custom(self.theme, &self.theme_colors)
     }
 
     /// Returns the platform-specific config file path, or `None` if the
     /// required environment variable is not set.
-    fn config_path() -> Option<std::path::PathBuf> {
+    pub fn path() -> Option<PathBuf> {
         // Windows: %APPDATA%\winget-tui\config.toml
         if let Ok(appdata) = std::env::var("APPDATA") {
             return Some(
-                std::path::PathBuf::from(appdata)
+                PathBuf::from(appdata)
                     .join("winget-tui")
                     .join("config.toml"),
             );
@@ -66,7 +208,7 @@ impl Config {
         // Fallback for non-Windows (dev / CI)
         if let Ok(home) = std::env::var("HOME") {
             return Some(
-                std::path::PathBuf::from(home)
+                PathBuf::from(home)
                     .join(".config")
                     .join("winget-tui")
                     .join("config.toml"),
@@ -75,72 +217,350 @@ impl Config {
         None
     }
 
-    /// Parse a minimal subset of TOML: bare `key = "value"` lines only.
-    /// Comments (`#`), blank lines, and unrecognised keys are skipped.
+    #[cfg(test)]
     fn parse(text: &str) -> Self {
-        let mut cfg = Self::default();
-        for line in text.lines() {
-            let line = line.trim();
-            // Skip comments and blank lines
-            if line.is_empty() || line.starts_with('#') {
-                continue;
+        Self::parse_with_warnings(text).0
+    }
+
+    #[cfg(test)]
+    fn parse_with_warnings(text: &str) -> (Self, Vec<ConfigWarning>) {
+        Self::parse_file(text, "config.toml", None)
+    }
+
+    /// Parse the config file named `file`. Whatever is valid is applied even
+    /// when other parts of the file have errors.
+    fn parse_file(
+        text: &str,
+        file: &str,
+        themes_dir: Option<PathBuf>,
+    ) -> (Self, Vec<ConfigWarning>) {
+        let mut parser = Parser::new(text, file.to_string(), themes_dir.as_deref());
+        let table = parser.parse_toml();
+        for (key, value) in table.get_ref() {
+            parser.top_level(key, value);
+        }
+        let Parser {
+            cfg, mut warnings, ..
+        } = parser;
+        // Problems in theme files the config loads go after its own
+        warnings.sort_by_key(|w| (w.file != file, w.line));
+        (Config { themes_dir, ..cfg }, warnings)
+    }
+}
+
+/// The name warnings use for the config file at `path`.
+fn file_name(path: &Path) -> String {
+    path.file_name().map_or_else(
+        || "config.toml".to_string(),
+        |name| name.to_string_lossy().into_owned(),
+    )
+}
+
+/// The `themes` directory next to the config file at `config_path`.
+fn themes_dir(config_path: &Path) -> Option<PathBuf> {
+    config_path.parent().map(|dir| dir.join("themes"))
+}
+
+/// Applies parsed TOML to a [`Config`], collecting warnings as it goes.
+struct Parser<'a> {
+    text: &'a str,
+    file: String,
+    themes_dir: Option<&'a Path>,
+    cfg: Config,
+    warnings: Vec<ConfigWarning>,
+}
+
+impl<'a> Parser<'a> {
+    fn new(text: &'a str, file: String, themes_dir: Option<&'a Path>) -> Self {
+        Self {
+            text,
+            file,
+            themes_dir,
+            cfg: Config::default(),
+            warnings: Vec::new(),
+        }
+    }
+
+    /// Parse the whole text, turning syntax errors into warnings.
+    fn parse_toml(&mut self) -> Spanned<DeTable<'a>> {
+        let (table, errors) = DeTable::parse_recoverable(self.text);
+        for error in errors {
+            let at = error.span().map_or(0, |span| span.start);
+            self.warn(at, error.message().to_string());
+        }
+        table
+    }
+
+    fn warn(&mut self, offset: usize, message: String) {
+        let offset = offset.min(self.text.len());
+        let line = self.text.as_bytes()[..offset]
+            .iter()
+            .filter(|&&b| b == b'\n')
+            .count()
+            + 1;
+        self.warnings.push(ConfigWarning {
+            file: self.file.clone(),
+            line,
+            message,
+        });
+    }
+
+    fn top_level(&mut self, key: &Spanned<DeString>, value: &Spanned<DeValue>) {
+        match key.get_ref().as_ref() {
+            "theme" => match value.get_ref() {
+                DeValue::String(name) => {
+                    if !self.named_theme(name, value.span().start) {
+                        let expected = self.theme_names();
+                        self.invalid(key, value, &expected);
+                    }
+                }
+                DeValue::Table(table) => self.theme_table(table, key.span().start),
+                other => self.warn(
+                    value.span().start,
+                    format!(
+                        "'theme' should be a theme name or a table, not {}",
+                        other.type_str()
+                    ),
+                ),
+            },
+            "default_view" => {
+                if let Some(name) = self.string(key, value) {
+                    match parse_view(name) {
+                        Some(view) => self.cfg.default_view = view,
+                        None => self.invalid(key, value, "installed, search, upgrades or pins"),
+                    }
+                }
             }
-            let Some((key, value)) = line.split_once('=') else {
-                continue;
-            };
-            let key = key.trim();
-            // Extract the bare value, handling both plain and inline-commented forms:
-            //   "search"            → search
-            //   "search" # comment  → search
-            //   search # comment    → search
-            let raw = value.trim();
-            let value = if let Some(inner) = raw.strip_prefix('"') {
-                inner.split_once('"').map(|(v, _)| v).unwrap_or(inner)
-            } else {
-                raw.split_once('#').map(|(v, _)| v.trim()).unwrap_or(raw)
-            };
-            match key {
-                "theme" => {
-                    cfg.theme = ThemeName::parse(value);
+            "default_source" => {
+                // Checked against `winget source list` once it has loaded
+                if let Some(name) = self.string(key, value) {
+                    self.cfg.default_source = SourceFilter::named(name);
                 }
-                "default_view" => {
-                    cfg.default_view = match value {
-                        "search" => AppMode::Search,
-                        "upgrades" => AppMode::Upgrades,
-                        _ => AppMode::Installed,
-                    };
+            }
+            "default_sort" => {
+                if let Some(name) = self.string(key, value) {
+                    match parse_sort(name) {
+                        Some((field, dir)) => {
+                            self.cfg.default_sort_field = field;
+                            self.cfg.default_sort_dir = dir;
+                        }
+                        None => self.invalid(
+                            key,
+                            value,
+                            "name, id, version or match, optionally with _desc, or none",
+                        ),
+                    }
                 }
-                "default_source" => {
-                    cfg.default_source = match value {
-                        "winget" => SourceFilter::Winget,
-                        "msstore" => SourceFilter::MsStore,
-                        _ => SourceFilter::All,
-                    };
+            }
+            "default_pin_filter" => {
+                if let Some(name) = self.string(key, value) {
+                    match parse_pin_filter(name) {
+                        Some(filter) => self.cfg.default_pin_filter = filter,
+                        None => self.invalid(key, value, "all, pinned or hide_pinned"),
+                    }
                 }
-                "default_sort" => {
-                    let (field, dir) = match value {
-                        "name" => (SortField::Name, SortDir::Asc),
-                        "name_desc" => (SortField::Name, SortDir::Desc),
-                        "id" => (SortField::Id, SortDir::Asc),
-                        "id_desc" => (SortField::Id, SortDir::Desc),
-                        "version" => (SortField::Version, SortDir::Asc),
-                        "version_desc" => (SortField::Version, SortDir::Desc),
-                        _ => (SortField::None, SortDir::Asc),
-                    };
-                    cfg.default_sort_field = field;
-                    cfg.default_sort_dir = dir;
+            }
+            "timeouts" => match value.get_ref() {
+                DeValue::Table(table) => {
+                    for (key, value) in table {
+                        self.timeout(key.get_ref(), key, value);
+                    }
                 }
-                "default_pin_filter" => {
-                    cfg.default_pin_filter = match value {
-                        "pinned" => PinFilter::PinnedOnly,
-                        "hide_pinned" => PinFilter::UnpinnedOnly,
-                        _ => PinFilter::All,
-                    };
+                other => self.warn(
+                    value.span().start,
+                    format!("'timeouts' should be a table, not {}", other.type_str()),
+                ),
+            },
+            name => match name.strip_prefix("timeout_") {
+                Some(class) => self.timeout(class, key, value),
+                None => self.warn(key.span().start, format!("unknown key '{name}'")),
+            },
+        }
+    }
+
+    /// Apply the preset or theme file called `name`, where `at` is the offset
+    /// of the name in the text. Returns false if there is no such theme.
+    fn named_theme(&mut self, name: &str, at: usize) -> bool {
+        if let Some(preset) = ThemeName::from_name(name) {
+            self.cfg.theme = preset;
+            self.cfg.theme_colors.clear();
+            return true;
+        }
+        let Some(path) = self.themes_dir.map(|dir| dir.join(format!("{name}.toml"))) else {
+            return false;
+        };
+        let text = match std::fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return false,
+            Err(e) => {
+                self.warn(at, format!("cannot read {}: {e}", path.display()));
+                return true;
+            }
+        };
+        let mut file = Parser::new(&text, format!("themes/{name}.toml"), None);
+        let table = file.parse_toml();
+        file.theme_table(table.get_ref(), 0);
+        file.warnings.sort_by_key(|w| w.line);
+        self.cfg.theme = file.cfg.theme;
+        self.cfg.theme_colors = file.cfg.theme_colors;
+        self.warnings.extend(file.warnings);
+        true
+    }
+
+    /// The accepted values of `theme`, for messages.
+    fn theme_names(&self) -> String {
+        match self.themes_dir {
+            Some(dir) => format!("{}, or a file in {}", ThemeName::NAMES, dir.display()),
+            None => ThemeName::NAMES.to_string(),
+        }
+    }
+
+    /// A `[theme]` table or theme file: `base` plus any [`Theme::FIELDS`].
+    /// `at` is where the table starts, for contrast warnings.
+    fn theme_table(&mut self, table: &DeTable, at: usize) {
+        self.cfg.theme = ThemeName::Original;
+        self.cfg.theme_colors.clear();
+        for (key, value) in table {
+            let name = key.get_ref().as_ref();
+            if name == "base" {
+                if let Some(base) = self.string(key, value) {
+                    match ThemeName::from_name(base) {
+                        Some(preset) => self.cfg.theme = preset,
+                        None => self.invalid(key, value, ThemeName::NAMES),
+                    }
                 }
-                _ => {}
+                continue;
             }
+            let Some(&field) = Theme::FIELDS.iter().find(|&&field| field == name) else {
+                self.warn(key.span().start, format!("unknown theme color '{name}'"));
+                continue;
+            };
+            match parse_color(value.get_ref()) {
+                Some(color) => self.cfg.theme_colors.push((field.to_string(), color)),
+                None => self.warn(
+                    value.span().start,
+                    format!("'{name}' should be a color like \"#88c0d0\", \"cyan\" or 0-255"),
+                ),
+            }
+        }
+        let theme = Theme::custom(self.cfg.theme, &self.cfg.theme_colors);
+        for problem in theme::unreadable_pairs(&theme) {
+            self.warn(at, format!("theme {problem}"));
         }
-        cfg
+    }
+
+    /// `class` is a field of [`Timeouts`], e.g. "list"
+    fn timeout(&mut self, class: &str, key: &Spanned<DeString>, value: &Spanned<DeValue>) {
+        let slot = match class {
+            "list" => &mut self.cfg.timeouts.list,
+            "search" => &mut self.cfg.timeouts.search,
+            "show" => &mut self.cfg.timeouts.show,
+            "pin" => &mut self.cfg.timeouts.pin,
+            "operation" => &mut self.cfg.timeouts.operation,
+            _ => {
+                let name = key.get_ref();
+                self.warn(key.span().start, format!("unknown timeout '{name}'"));
+                return;
+            }
+        };
+        // A whole, positive number of seconds; anything else keeps the default
+        let secs = match value.get_ref() {
+            DeValue::Integer(int) => u64::from_str_radix(int.as_str(), int.radix()).ok(),
+            _ => None,
+        };
+        match secs {
+            Some(secs @ 1..) => *slot = Duration::from_secs(secs),
+            _ => {
+                let name = key.get_ref();
+                self.warn(
+                    value.span().start,
+                    format!("'{name}' should be a positive whole number of seconds"),
+                );
+            }
+        }
+    }
+
+    /// The value as a string, or a warning that it has the wrong type.
+    fn string<'v>(
+        &mut self,
+        key: &Spanned<DeString>,
+        value: &'v Spanned<DeValue>,
+    ) -> Option<&'v str> {
+        let str = value.get_ref().as_str();
+        if str.is_none() {
+            let (name, kind) = (key.get_ref(), value.get_ref().type_str());
+            self.warn(
+                value.span().start,
+                format!("'{name}' should be a string, not {kind}"),
+            );
+        }
+        str
+    }
+
+    fn invalid(&mut self, key: &Spanned<DeString>, value: &Spanned<DeValue>, expected: &str) {
+        let name = key.get_ref();
+        let found = value.get_ref().as_str().unwrap_or_default();
+        self.warn(
+            value.span().start,
+            format!("unknown {name} \"{found}\"; expected {expected}"),
+        );
+    }
+}
+
+/// A color given as hex ("#88c0d0"), a name ("cyan", "light red") or a
+/// 256-color palette index.
+fn parse_color(value: &DeValue) -> Option<Color> {
+    match value {
+        DeValue::String(name) => name.parse().ok(),
+        DeValue::Integer(int) => u8::from_str_radix(int.as_str(), int.radix())
+            .ok()
+            .map(Color::Indexed),
+        _ => None,
+    }
+}
+
+/// Settings given as command-line flags, which take precedence over the
+/// config file for one session.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Overrides {
+    /// A preset or theme file name
+    pub theme: Option<String>,
+    pub view: Option<AppMode>,
+    pub source: Option<SourceFilter>,
+    pub sort: Option<(SortField, SortDir)>,
+    pub pin_filter: Option<PinFilter>,
+}
+
+pub fn parse_view(value: &str) -> Option<AppMode> {
+    match value {
+        "installed" => Some(AppMode::Installed),
+        "search" => Some(AppMode::Search),
+        "upgrades" => Some(AppMode::Upgrades),
+        "pins" => Some(AppMode::Pins),
+        _ => None,
+    }
+}
+
+pub fn parse_sort(value: &str) -> Option<(SortField, SortDir)> {
+    match value {
+        "name" => Some((SortField::Name, SortDir::Asc)),
+        "name_desc" => Some((SortField::Name, SortDir::Desc)),
+        "id" => Some((SortField::Id, SortDir::Asc)),
+        "id_desc" => Some((SortField::Id, SortDir::Desc)),
+        "version" => Some((SortField::Version, SortDir::Asc)),
+        "version_desc" => Some((SortField::Version, SortDir::Desc)),
+        "match" | "relevance" => Some((SortField::Relevance, SortDir::Asc)),
+        "none" => Some((SortField::None, SortDir::Asc)),
+        _ => None,
+    }
+}
+
+pub fn parse_pin_filter(value: &str) -> Option<PinFilter> {
+    match value {
+        "all" => Some(PinFilter::All),
+        "pinned" => Some(PinFilter::PinnedOnly),
+        "hide_pinned" => Some(PinFilter::UnpinnedOnly),
+        _ => None,
     }
 }
 
@@ -177,6 +597,12 @@ mod tests {
             Config::parse(r#"theme = "system""#).theme,
             ThemeName::Terminal
         );
+        assert_eq!(Config::parse(r#"theme = "Light""#).theme, ThemeName::Light);
+        assert_eq!(
+            Config::parse(r#"theme = "high_contrast""#).theme,
+            ThemeName::HighContrast
+        );
+        assert_eq!(Config::parse(r#"theme = "auto""#).theme, ThemeName::Auto);
     }
 
     #[test]
@@ -208,16 +634,31 @@ mod tests {
         assert_eq!(cfg.default_view, AppMode::Upgrades);
     }
 
+    #[test]
+    fn parse_default_view_pins() {
+        let cfg = Config::parse(r#"default_view = "pins""#);
+        assert_eq!(cfg.default_view, AppMode::Pins);
+    }
+
     #[test]
     fn parse_default_source_winget() {
         let cfg = Config::parse(r#"default_source = "winget""#);
-        assert_eq!(cfg.default_source, SourceFilter::Winget);
+        assert_eq!(cfg.default_source, SourceFilter::named("winget"));
+    }
+
+    #[test]
+    fn parse_default_source_custom_name() {
+        let cfg = Config::parse(r#"default_source = "contoso""#);
+        assert_eq!(
+            cfg.default_source,
+            SourceFilter::Named("contoso".to_string())
+        );
     }
 
     #[test]
     fn parse_default_source_msstore() {
         let cfg = Config::parse(r#"default_source = "msstore""#);
-        assert_eq!(cfg.default_source, SourceFilter::MsStore);
+        assert_eq!(cfg.default_source, SourceFilter::named("msstore"));
     }
 
     #[test]
@@ -225,7 +666,7 @@ mod tests {
         let input = "default_view = \"upgrades\"\ndefault_source = \"winget\"\n";
         let cfg = Config::parse(input);
         assert_eq!(cfg.default_view, AppMode::Upgrades);
-        assert_eq!(cfg.default_source, SourceFilter::Winget);
+        assert_eq!(cfg.default_source, SourceFilter::named("winget"));
     }
 
     #[test]
@@ -239,7 +680,7 @@ default_source = \"msstore\"
 ";
         let cfg = Config::parse(input);
         assert_eq!(cfg.default_view, AppMode::Search);
-        assert_eq!(cfg.default_source, SourceFilter::MsStore);
+        assert_eq!(cfg.default_source, SourceFilter::named("msstore"));
     }
 
     #[test]
@@ -296,6 +737,13 @@ default_source = \"msstore\"
         assert_eq!(cfg.default_sort_dir, SortDir::Desc);
     }
 
+    #[test]
+    fn parse_default_sort_match() {
+        let cfg = Config::parse(r#"default_sort = "match""#);
+        assert_eq!(cfg.default_sort_field, SortField::Relevance);
+        assert_eq!(cfg.default_sort_dir, SortDir::Asc);
+    }
+
     #[test]
     fn parse_default_sort_none_explicit() {
         let cfg = Config::parse(r#"default_sort = "none""#);
@@ -315,7 +763,7 @@ default_source = \"msstore\"
         let input = "default_view = \"upgrades\"\ndefault_source = \"winget\"\ndefault_sort = \"version_desc\"\n";
         let cfg = Config::parse(input);
         assert_eq!(cfg.default_view, AppMode::Upgrades);
-        assert_eq!(cfg.default_source, SourceFilter::Winget);
+        assert_eq!(cfg.default_source, SourceFilter::named("winget"));
         assert_eq!(cfg.default_sort_field, SortField::Version);
         assert_eq!(cfg.default_sort_dir, SortDir::Desc);
     }
@@ -365,7 +813,7 @@ default_pin_filter = \"pinned\" # pinned only
 ";
         let cfg = Config::parse(input);
         assert_eq!(cfg.default_view, AppMode::Upgrades);
-        assert_eq!(cfg.default_source, SourceFilter::Winget);
+        assert_eq!(cfg.default_source, SourceFilter::named("winget"));
         assert_eq!(cfg.default_sort_field, SortField::Name);
         assert_eq!(cfg.default_sort_dir, SortDir::Desc);
         assert_eq!(cfg.default_pin_filter, PinFilter::PinnedOnly);
@@ -376,16 +824,283 @@ default_pin_filter = \"pinned\" # pinned only
         let input = "default_view = \"upgrades\"\ndefault_source = \"winget\"\ndefault_sort = \"version_desc\"\ndefault_pin_filter = \"pinned\"\n";
         let cfg = Config::parse(input);
         assert_eq!(cfg.default_view, AppMode::Upgrades);
-        assert_eq!(cfg.default_source, SourceFilter::Winget);
+        assert_eq!(cfg.default_source, SourceFilter::named("winget"));
         assert_eq!(cfg.default_sort_field, SortField::Version);
         assert_eq!(cfg.default_sort_dir, SortDir::Desc);
         assert_eq!(cfg.default_pin_filter, PinFilter::PinnedOnly);
     }
 
+    // ── timeouts ──────────────────────────────────────────────────────────────
+
+    #[test]
+    fn parse_timeouts_in_seconds() {
+        let input = "timeout_show = 5\ntimeout_operation = 3600 # an hour\n";
+        let cfg = Config::parse(input);
+        assert_eq!(cfg.timeouts.show, Duration::from_secs(5));
+        assert_eq!(cfg.timeouts.operation, Duration::from_secs(3600));
+        assert_eq!(cfg.timeouts.list, Timeouts::default().list);
+    }
+
+    #[test]
+    fn parse_invalid_timeout_keeps_default() {
+        let cfg = Config::parse("timeout_search = 0\ntimeout_pin = \"soon\"\n");
+        assert_eq!(cfg.timeouts, Timeouts::default());
+    }
+
     #[test]
     fn default_sort_field_is_none_and_dir_is_asc() {
         let cfg = Config::default();
         assert_eq!(cfg.default_sort_field, SortField::None);
         assert_eq!(cfg.default_sort_dir, SortDir::Asc);
     }
+
+    #[test]
+    fn overrides_replace_only_the_given_settings() {
+        let file = Config::parse(
+            "default_view = \"installed\"\ntheme = \"retro\"\ndefault_pin_filter = \"pinned\"",
+        );
+        let cfg = file
+            .with_overrides(
+                Overrides {
+                    view: Some(AppMode::Upgrades),
+                    source: Some(SourceFilter::named("winget")),
+                    ..Overrides::default()
+                },
+                &mut Vec::new(),
+            )
+            .unwrap();
+        assert_eq!(cfg.default_view, AppMode::Upgrades);
+        assert_eq!(cfg.default_source, SourceFilter::named("winget"));
+        assert_eq!(cfg.theme, ThemeName::Retro);
+        assert_eq!(cfg.default_pin_filter, PinFilter::PinnedOnly);
+    }
+
+    // ── warnings ──────────────────────────────────────────────────────────────
+
+    #[test]
+    fn valid_file_has_no_warnings() {
+        let input = "theme = \"nord\"\ndefault_view = \"pins\"\n\n[timeouts]\nshow = 10\n";
+        let (cfg, warnings) = Config::parse_with_warnings(input);
+        assert!(warnings.is_empty(), "{warnings:?}");
+        assert_eq!(cfg.theme, ThemeName::Nord);
+        assert_eq!(cfg.timeouts.show, Duration::from_secs(10));
+    }
+
+    #[test]
+    fn typo_in_value_is_reported_with_its_line() {
+        let (cfg, warnings) =
+            Config::parse_with_warnings("# views\n\ndefault_view = \"upgrade\"\n");
+        assert_eq!(cfg.default_view, AppMode::Installed);
+        assert_eq!(
+            warnings,
+            vec![ConfigWarning {
+                file: "config.toml".to_string(),
+                line: 3,
+                message:
+                    "unknown default_view \"upgrade\"; expected installed, search, upgrades or pins"
+                        .to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn unknown_keys_and_wrong_types_are_reported() {
+        let input = "\
+default_veiw = \"search\"
+theme = true
+timeout_list = \"soon\"
+
+[timeouts]
+operation = 600
+later = 5
+";
+        let (cfg, warnings) = Config::parse_with_warnings(input);
+        assert_eq!(cfg.timeouts.operation, Duration::from_secs(600));
+        let lines: Vec<usize> = warnings.iter().map(|w| w.line).collect();
+        assert_eq!(lines, vec![1, 2, 3, 7]);
+        assert_eq!(warnings[0].message, "unknown key 'default_veiw'");
+        assert_eq!(
+            warnings[1].message,
+            "'theme' should be a theme name or a table, not boolean"
+        );
+        assert!(warnings[2].message.contains("whole number of seconds"));
+        assert_eq!(warnings[3].message, "unknown timeout 'later'");
+    }
+
+    #[test]
+    fn syntax_error_keeps_the_valid_settings() {
+        let input = "theme = \"retro\"\ndefault_view = search\ndefault_pin_filter = \"pinned\"\n";
+        let (cfg, warnings) = Config::parse_with_warnings(input);
+        assert_eq!(cfg.theme, ThemeName::Retro);
+        assert_eq!(cfg.default_pin_filter, PinFilter::PinnedOnly);
+        assert!(!warnings.is_empty());
+        assert!(warnings.iter().all(|w| w.line == 2), "{warnings:?}");
+    }
+
+    #[test]
+    fn warning_displays_line_and_message() {
+        let warning = ConfigWarning {
+            file: "config.toml".to_string(),
+            line: 4,
+            message: "unknown key 'x'".to_string(),
+        };
+        assert_eq!(warning.to_string(), "config.toml line 4: unknown key 'x'");
+    }
+
+    // ── custom themes ─────────────────────────────────────────────────────────
+
+    /// A config file path in a fresh directory, with `themes` written into
+    /// its `themes` subdirectory.
+    fn config_with_themes(name: &str, themes: &[(&str, &str)]) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("winget-tui-config-{}-{name}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(dir.join("themes")).unwrap();
+        for (theme, text) in themes {
+            std::fs::write(dir.join("themes").join(format!("{theme}.toml")), text).unwrap();
+        }
+        dir.join("config.toml")
+    }
+
+    #[test]
+    fn missing_config_file_is_not_a_problem() {
+        let path = config_with_themes("absent", &[]);
+        let (cfg, warnings) = Config::load_or_default(&path);
+        assert!(warnings.is_empty());
+        assert_eq!(cfg.theme, ThemeName::Original);
+    }
+
+    #[test]
+    fn unreadable_config_file_is_reported() {
+        let path = config_with_themes("not-utf8", &[]);
+        std::fs::write(&path, b"theme = \"nord\"\n\xff\xfe\n").unwrap();
+        let (cfg, warnings) = Config::load_or_default(&path);
+        assert_eq!(cfg.theme, ThemeName::Original);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].file, "config.toml");
+        assert_eq!(warnings[0].line, 1);
+        assert!(warnings[0].message.starts_with("cannot read file"));
+    }
+
+    #[test]
+    fn theme_table_replaces_colors_of_its_base() {
+        let input = "\
+[theme]
+base = \"nord\"
+accent = \"#88c0d0\"
+selection = \"magenta\"
+surface = 236
+";
+        let (cfg, warnings) = Config::parse_with_warnings(input);
+        assert!(warnings.is_empty(), "{warnings:?}");
+        let theme = cfg.build_theme();
+        assert_eq!(cfg.theme, ThemeName::Nord);
+        assert_eq!(theme.accent, Color::Rgb(0x88, 0xc0, 0xd0));
+        assert_eq!(theme.selection, Color::Magenta);
+        assert_eq!(theme.surface, Color::Indexed(236));
+        assert_eq!(theme.background, Theme::nord().background);
+    }
+
+    #[test]
+    fn theme_table_reports_bad_fields_and_colors() {
+        let input =
+            "[theme]\nbase = \"nord\"\naccnet = \"red\"\ninfo = \"blueish\"\nsuccess = 300\n";
+        let (cfg, warnings) = Config::parse_with_warnings(input);
+        assert!(cfg.theme_colors.is_empty());
+        let messages: Vec<(usize, &str)> = warnings
+            .iter()
+            .map(|w| (w.line, w.message.as_str()))
+            .collect();
+        assert_eq!(
+            messages,
+            vec![
+                (3, "unknown theme color 'accnet'"),
+                (
+                    4,
+                    "'info' should be a color like \"#88c0d0\", \"cyan\" or 0-255"
+                ),
+                (
+                    5,
+                    "'success' should be a color like \"#88c0d0\", \"cyan\" or 0-255"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn unreadable_theme_colors_are_reported() {
+        let input =
+            "default_view = \"pins\"\n\n[theme]\nbase = \"original\"\ntext_primary = \"#2a2a2a\"\n";
+        let (cfg, warnings) = Config::parse_with_warnings(input);
+        // The colors still apply; readability is only a warning
+        assert_eq!(cfg.build_theme().text_primary, Color::Rgb(0x2a, 0x2a, 0x2a));
+        assert!(!warnings.is_empty());
+        assert!(warnings.iter().all(|w| w.line == 3), "{warnings:?}");
+        assert!(warnings[0]
+            .message
+            .starts_with("theme root text contrast 1."));
+    }
+
+    #[test]
+    fn theme_name_loads_a_file_from_the_themes_directory() {
+        let path = config_with_themes(
+            "file",
+            &[(
+                "dusk",
+                "base = \"retro\"\n# comment\nacent = \"red\"\naccent = 120\n",
+            )],
+        );
+        std::fs::write(&path, "theme = \"dusk\"\n").unwrap();
+        let (cfg, warnings) = Config::load_from(&path).unwrap();
+        assert_eq!(cfg.theme, ThemeName::Retro);
+        assert_eq!(
+            cfg.theme_colors,
+            vec![("accent".to_string(), Color::Indexed(120))]
+        );
+        assert_eq!(
+            warnings,
+            vec![ConfigWarning {
+                file: "themes/dusk.toml".to_string(),
+                line: 3,
+                message: "unknown theme color 'acent'".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn missing_theme_file_is_reported() {
+        let path = config_with_themes("missing", &[]);
+        std::fs::write(&path, "theme = \"dawn\"\n").unwrap();
+        let (cfg, warnings) = Config::load_from(&path).unwrap();
+        assert_eq!(cfg.theme, ThemeName::Original);
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].message.starts_with("unknown theme \"dawn\""));
+    }
+
+    #[test]
+    fn theme_override_selects_a_preset_or_file_for_the_session() {
+        let path = config_with_themes("override", &[("dusk", "base = \"nord\"\n")]);
+        std::fs::write(&path, "[theme]\nbase = \"retro\"\naccent = \"cyan\"\n").unwrap();
+        let (cfg, mut warnings) = Config::load_from(&path).unwrap();
+
+        let theme = |name: &str| Overrides {
+            theme: Some(name.to_string()),
+            ..Overrides::default()
+        };
+        let file = cfg
+            .clone()
+            .with_overrides(theme("dusk"), &mut warnings)
+            .unwrap();
+        assert_eq!(file.theme, ThemeName::Nord);
+        assert!(file.theme_colors.is_empty());
+
+        let preset = cfg
+            .clone()
+            .with_overrides(theme("terminal"), &mut warnings)
+            .unwrap();
+        assert_eq!(preset.build_theme(), Theme::terminal());
+
+        assert!(cfg.with_overrides(theme("neon"), &mut warnings).is_err());
+        assert!(warnings.is_empty(), "{warnings:?}");
+    }
 }
@@ -0,0 +1,283 @@
+//! Typed errors returned by [`WingetBackend`](crate::backend::WingetBackend).
+//!
+//! winget exits with an HRESULT in the `0x8A15xxxx` range when something goes
+//! wrong. [`WingetErrorKind::from_exit_code`] decodes the documented codes the
+//! TUI can react to; everything else is reported as [`WingetErrorKind::Other`]
+//! with winget's own output.
+
+use std::fmt;
+use std::time::Duration;
+
+pub type Result<T> = std::result::Result<T, WingetError>;
+
+/// What went wrong, decoded from winget's exit code (or its output when the
+/// code alone is not specific enough).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WingetErrorKind {
+    DownloadFailed,
+    NoApplicableInstaller,
+    HashMismatch,
+    NoPackageFound,
+    NoApplicableUpdate,
+    AlreadyInstalled,
+    PackageInUse,
+    InstallInProgress,
+    DiskFull,
+    NoNetwork,
+    RebootRequired,
+    BlockedByPolicy,
+    BlockedByPin,
+    Other,
+}
+
+impl WingetErrorKind {
+    /// Map a winget process exit code to an error kind.
+    ///
+    /// Windows reports the HRESULT as a negative `i32`; the bit pattern is
+    /// what winget's documentation lists.
+    pub fn from_exit_code(code: i32) -> Self {
+        match code as u32 {
+            0x8A15_0008 => Self::DownloadFailed,
+            0x8A15_0010 => Self::NoApplicableInstaller,
+            0x8A15_0011 => Self::HashMismatch,
+            0x8A15_0014 => Self::NoPackageFound,
+            0x8A15_002B => Self::NoApplicableUpdate,
+            0x8A15_0061 | 0x8A15_010D => Self::AlreadyInstalled,
+            0x8A15_0101 | 0x8A15_0103 | 0x8A15_0111 => Self::PackageInUse,
+            0x8A15_0102 => Self::InstallInProgress,
+            0x8A15_0105 => Self::DiskFull,
+            0x8A15_0107 => Self::NoNetwork,
+            0x8A15_0109 | 0x8A15_010A => Self::RebootRequired,
+            0x8A15_010F => Self::BlockedByPolicy,
+            _ => Self::Other,
+        }
+    }
+
+    /// Classify a failure from its exit code, falling back to winget's output.
+    ///
+    /// winget explains a pin that blocks an upgrade in its output rather than
+    /// with a dedicated exit code, so pins are recognised from its wording:
+    /// "the package is pinned", "pins that prevent upgrade" or the
+    /// `--include-pinned` hint. The bare word "pinned" also turns up in
+    /// "unpinned" and in package descriptions.
+    pub fn classify(code: Option<i32>, output: &str) -> Self {
+        let kind = code.map_or(Self::Other, Self::from_exit_code);
+        if !matches!(kind, Self::Other | Self::NoApplicableUpdate) {
+            return kind;
+        }
+        let lower = output.to_lowercase();
+        if lower.contains("package is pinned")
+            || lower.contains("pins that prevent upgrade")
+            || lower.contains("--include-pinned")
+        {
+            Self::BlockedByPin
+        } else if lower.contains("no package found") || lower.contains("no installed package") {
+            Self::NoPackageFound
+        } else if lower.contains("hash does not match") {
+            Self::HashMismatch
+        } else {
+            kind
+        }
+    }
+
+    /// One-line, user-facing description of the failure.
+    pub fn message(self) -> &'static str {
+        match self {
+            Self::DownloadFailed => "Downloading the installer failed",
+            Self::NoApplicableInstaller => "No installer matches this system",
+            Self::HashMismatch => "Installer hash does not match the manifest",
+            Self::NoPackageFound => "No package found matching the request",
+            Self::NoApplicableUpdate => "No applicable update found",
+            Self::AlreadyInstalled => "The package is already installed",
+            Self::PackageInUse => "The application or its files are in use",
+            Self::InstallInProgress => "Another installation is already in progress",
+            Self::DiskFull => "Not enough disk space",
+            Self::NoNetwork => "No network connection",
+            Self::RebootRequired => "A restart is required to finish",
+            Self::BlockedByPolicy => "Blocked by system policy",
+            Self::BlockedByPin => "The package is pinned",
+            Self::Other => "winget failed",
+        }
+    }
+
+    /// Suggested next step, when there is an obvious one.
+    pub fn suggestion(self) -> Option<&'static str> {
+        match self {
+            Self::DownloadFailed | Self::NoNetwork => {
+                Some("check your network connection and try again")
+            }
+            Self::NoApplicableInstaller => Some("try a different version, scope, or architecture"),
+            Self::HashMismatch => Some("the manifest may be updating; try again later"),
+            Self::NoPackageFound => Some("refresh the list; the package id may have changed"),
+            Self::AlreadyInstalled => Some("upgrade it instead"),
+            Self::PackageInUse => Some("close the application and try again"),
+            Self::InstallInProgress => Some("wait for the other installer to finish"),
+            Self::DiskFull => Some("free up disk space and try again"),
+            Self::RebootRequired => Some("restart Windows to complete the change"),
+            Self::BlockedByPin => Some("remove the pin to upgrade it"),
+            Self::NoApplicableUpdate | Self::BlockedByPolicy | Self::Other => None,
+        }
+    }
+}
+
+/// Error returned by backend calls.
+#[derive(Debug)]
+pub enum WingetError {
+    /// winget could not be started at all.
+    Launch(std::io::Error),
+    /// winget did not finish within its timeout and was killed.
+    Timeout {
+        /// The winget subcommand, e.g. `"show"`
+        command: String,
+        after: Duration,
+    },
+    /// winget ran and reported a failure.
+    Failed {
+        kind: WingetErrorKind,
+        /// Raw process exit code, if the process exited normally
+        code: Option<i32>,
+        /// winget's own explanation (stderr, or stdout when stderr is empty)
+        output: String,
+    },
+}
+
+impl WingetError {
+    /// Build a [`WingetError::Failed`] from a finished winget process.
+    pub fn failed(code: Option<i32>, output: impl Into<String>) -> Self {
+        let output = output.into();
+        Self::Failed {
+            kind: WingetErrorKind::classify(code, &output),
+            code,
+            output,
+        }
+    }
+
+    pub fn kind(&self) -> Option<WingetErrorKind> {
+        match self {
+            Self::Launch(_) | Self::Timeout { .. } => None,
+            Self::Failed { kind, .. } => Some(*kind),
+        }
+    }
+}
+
+impl fmt::Display for WingetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Launch(e) => write!(f, "Failed to run winget. Is it installed? ({e})"),
+            Self::Timeout { command, after } => write!(
+                f,
+                "winget {command} timed out after {}s and was stopped",
+                after.as_secs_f32()
+            ),
+            Self::Failed {
+                kind: WingetErrorKind::Other,
+                output,
+                ..
+            } => write!(f, "winget failed: {output}"),
+            Self::Failed { kind, code, .. } => {
+                write!(f, "{}", kind.message())?;
+                if let Some(code) = code {
+                    write!(f, " (0x{:08X})", *code as u32)?;
+                }
+                if let Some(suggestion) = kind.suggestion() {
+                    write!(f, " — {suggestion}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for WingetError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Launch(e) => Some(e),
+            Self::Timeout { .. } | Self::Failed { .. } => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// winget exit codes as Windows reports them (negative `i32`).
+    fn hresult(code: u32) -> i32 {
+        code as i32
+    }
+
+    #[test]
+    fn documented_exit_codes_map_to_kinds() {
+        for (code, kind) in [
+            (0x8A15_0010, WingetErrorKind::NoApplicableInstaller),
+            (0x8A15_0011, WingetErrorKind::HashMismatch),
+            (0x8A15_0014, WingetErrorKind::NoPackageFound),
+            (0x8A15_010D, WingetErrorKind::AlreadyInstalled),
+            (0x8A15_0107, WingetErrorKind::NoNetwork),
+            (0x8A15_0109, WingetErrorKind::RebootRequired),
+            (0x8A15_010A, WingetErrorKind::RebootRequired),
+        ] {
+            assert_eq!(WingetErrorKind::from_exit_code(hresult(code)), kind);
+        }
+        assert_eq!(WingetErrorKind::from_exit_code(1), WingetErrorKind::Other);
+    }
+
+    #[test]
+    fn classify_detects_pins_from_output() {
+        let output = "A newer version was found, but the package is pinned.";
+        assert_eq!(
+            WingetErrorKind::classify(Some(hresult(0x8A15_002B)), output),
+            WingetErrorKind::BlockedByPin
+        );
+        assert_eq!(
+            WingetErrorKind::classify(None, "No installed package found matching input criteria."),
+            WingetErrorKind::NoPackageFound
+        );
+        let output = "1 package(s) have pins that prevent upgrade. Using the \
+                      --include-pinned argument may show more results.";
+        assert_eq!(
+            WingetErrorKind::classify(Some(1), output),
+            WingetErrorKind::BlockedByPin
+        );
+    }
+
+    #[test]
+    fn classify_ignores_pinned_in_unrelated_text() {
+        for output in [
+            "Installer failed; the package was unpinned earlier.",
+            "Keeps your pinned tabs across sessions.",
+        ] {
+            assert_eq!(
+                WingetErrorKind::classify(Some(1), output),
+                WingetErrorKind::Other
+            );
+        }
+    }
+
+    #[test]
+    fn classify_prefers_a_specific_exit_code_over_output() {
+        assert_eq!(
+            WingetErrorKind::classify(Some(hresult(0x8A15_0109)), "pinned"),
+            WingetErrorKind::RebootRequired
+        );
+    }
+
+    #[test]
+    fn display_for_known_kind_is_friendly_with_code_and_suggestion() {
+        let err = WingetError::failed(Some(hresult(0x8A15_0011)), "raw output");
+        assert_eq!(
+            err.to_string(),
+            "Installer hash does not match the manifest (0x8A150011) — the manifest may be updating; try again later"
+        );
+    }
+
+    #[test]
+    fn display_for_unknown_failure_keeps_winget_output() {
+        let err = WingetError::failed(Some(1), "Installer failed with exit code: 1603");
+        assert_eq!(err.kind(), Some(WingetErrorKind::Other));
+        assert_eq!(
+            err.to_string(),
+            "winget failed: Installer failed with exit code: 1603"
+        );
+    }
+}
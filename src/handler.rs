@@ -2,8 +2,13 @@ use crossterm::event::{
     self, Event, KeyCode, KeyEventKind, KeyModifiers, MouseButton, MouseEventKind,
 };
 
-use crate::app::{App, AppMode, ConfirmDialog, FocusZone, InputMode};
-use crate::models::{Operation, SortDir, SortField};
+use crate::app::{
+    App, AppMode, ConfirmDialog, DetailTab, FocusZone, InputMode, InstallDialog, PinDialog,
+    SourceDialog, SourceField, UninstallDialog,
+};
+use crate::models::{
+    InstallOptions, Operation, PinState, SearchRequest, SortDir, SortField, SourceFilter,
+};
 
 /// Handle the next crossterm event, waiting up to 50 ms for one to arrive.
 ///
@@ -17,12 +22,42 @@ pub fn handle_events(app: &mut App) -> anyhow::Result<bool> {
 
     match event::read()? {
         Event::Key(key) if key.kind == KeyEventKind::Press => {
+            // Cancel prompt for the running operation takes priority
+            if app.confirm_cancel {
+                handle_cancel_confirm(app, key.code);
+                return Ok(true);
+            }
+
             // Confirm dialog takes priority
             if app.confirm.is_some() {
                 handle_confirm(app, key.code)?;
                 return Ok(true);
             }
 
+            // Install options dialog
+            if app.install_dialog.is_some() {
+                handle_install_dialog(app, key.code);
+                return Ok(true);
+            }
+
+            // Uninstall options dialog
+            if app.uninstall_dialog.is_some() {
+                handle_uninstall_dialog(app, key.code);
+                return Ok(true);
+            }
+
+            // Pin dialog
+            if app.pin_dialog.is_some() {
+                handle_pin_dialog(app, key.code);
+                return Ok(true);
+            }
+
+            // Add-source dialog
+            if app.source_dialog.is_some() {
+                handle_source_dialog(app, key.code);
+                return Ok(true);
+            }
+
             // Version input prompt takes priority after confirm
             if app.input_mode == InputMode::VersionInput {
                 handle_version_input(app, key.code)?;
@@ -35,6 +70,26 @@ pub fn handle_events(app: &mut App) -> anyhow::Result<bool> {
                 return Ok(true);
             }
 
+            // Queue view
+            if app.show_queue {
+                handle_queue_input(app, key.code);
+                return Ok(true);
+            }
+
+            // Sources view
+            if app.show_sources {
+                handle_sources_input(app, key.code);
+                return Ok(true);
+            }
+
+            // Operation output overlay
+            if app.show_output {
+                if matches!(key.code, KeyCode::Char('L') | KeyCode::Esc) {
+                    app.show_output = false;
+                }
+                return Ok(true);
+            }
+
             match app.input_mode {
                 InputMode::Search => handle_search_input(app, key.code)?,
                 InputMode::LocalFilter => handle_local_filter_input(app, key.code)?,
@@ -82,9 +137,7 @@ fn handle_confirm(app: &mut App, key: KeyCode) -> anyhow::Result<bool> {
     match key {
         KeyCode::Char('y') | KeyCode::Char('Y') => {
             if let Some(confirm) = app.confirm.take() {
-                app.set_status(format!("{}...", confirm.operation));
-                app.loading = true;
-                app.execute_operation(confirm.operation);
+                app.enqueue_operation(confirm.operation);
             }
         }
         KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
@@ -96,47 +149,295 @@ fn handle_confirm(app: &mut App, key: KeyCode) -> anyhow::Result<bool> {
     Ok(false)
 }
 
+fn handle_queue_input(app: &mut App, key: KeyCode) {
+    let last = app.queue.len().saturating_sub(1);
+    match key {
+        KeyCode::Char('Q') | KeyCode::Esc => {
+            app.show_queue = false;
+        }
+        KeyCode::Up | KeyCode::Char('k') => {
+            app.queue_selected = app.queue_selected.saturating_sub(1);
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            app.queue_selected = (app.queue_selected + 1).min(last);
+        }
+        // Reorder pending entries
+        KeyCode::Char('K') if app.move_queue_entry(app.queue_selected, -1) => {
+            app.queue_selected -= 1;
+        }
+        KeyCode::Char('J') if app.move_queue_entry(app.queue_selected, 1) => {
+            app.queue_selected += 1;
+        }
+        KeyCode::Char('d') | KeyCode::Delete => match app.remove_queue_entry(app.queue_selected) {
+            Some(entry) => app.set_status(format!("Removed from queue: {}", entry.operation)),
+            None => app.set_status("Only pending operations can be removed"),
+        },
+        KeyCode::Char('c') => {
+            app.clear_finished_queue_entries();
+        }
+        KeyCode::Char('C') => {
+            if app.operation_running() {
+                app.confirm_cancel = true;
+            } else {
+                app.set_status("No operation is running");
+            }
+        }
+        _ => {}
+    }
+}
+
+fn handle_sources_input(app: &mut App, key: KeyCode) {
+    let last = app.sources.len().saturating_sub(1);
+    let selected = app
+        .sources
+        .get(app.sources_selected)
+        .map(|s| s.name.clone());
+    match key {
+        KeyCode::Char('F') | KeyCode::Esc => {
+            app.show_sources = false;
+        }
+        KeyCode::Up | KeyCode::Char('k') => {
+            app.sources_selected = app.sources_selected.saturating_sub(1);
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            app.sources_selected = (app.sources_selected + 1).min(last);
+        }
+        KeyCode::Char('a') => {
+            app.source_dialog = Some(SourceDialog::new());
+        }
+        KeyCode::Char('d') | KeyCode::Delete => {
+            if let Some(name) = selected {
+                app.confirm = Some(ConfirmDialog {
+                    message: format!("Remove source {}?", name),
+                    operation: Operation::RemoveSource { name },
+                });
+            }
+        }
+        KeyCode::Char('R') => {
+            app.confirm = Some(ConfirmDialog {
+                message: "Reset sources to the defaults? Added sources will be removed."
+                    .to_string(),
+                operation: Operation::ResetSources,
+            });
+        }
+        KeyCode::Char('u') => {
+            if app.sources.is_empty() {
+                app.set_status("No sources to update");
+            } else {
+                app.set_status("Updating sources...");
+                app.update_sources();
+            }
+        }
+        KeyCode::Char('r') => app.load_sources(),
+        _ => {}
+    }
+}
+
+fn handle_source_dialog(app: &mut App, key: KeyCode) {
+    let Some(dialog) = app.source_dialog.as_mut() else {
+        return;
+    };
+    match key {
+        KeyCode::Esc => {
+            app.source_dialog = None;
+            app.set_status("Cancelled");
+        }
+        KeyCode::Enter => match dialog.operation() {
+            Some(operation) => {
+                let message = match &operation {
+                    Operation::AddSource { source } => format!(
+                        "Add {} source {} at {}?",
+                        source.source_type, source.name, source.url
+                    ),
+                    _ => unreachable!("the source dialog only builds source additions"),
+                };
+                app.source_dialog = None;
+                app.confirm = Some(ConfirmDialog { message, operation });
+            }
+            None => app.set_status("Enter a name and URL for the new source"),
+        },
+        KeyCode::Up | KeyCode::BackTab => dialog.move_field(-1),
+        KeyCode::Down | KeyCode::Tab => dialog.move_field(1),
+        KeyCode::Left | KeyCode::Right => dialog.cycle_current(),
+        KeyCode::Char(' ') if dialog.current_field() == SourceField::Type => dialog.cycle_current(),
+        KeyCode::Backspace => dialog.pop_char(),
+        KeyCode::Char(c) => dialog.push_char(c),
+        _ => {}
+    }
+}
+
+fn handle_cancel_confirm(app: &mut App, key: KeyCode) {
+    match key {
+        KeyCode::Char('y') | KeyCode::Char('Y') => {
+            app.confirm_cancel = false;
+            if !app.cancel_operation() {
+                app.set_status("Nothing to cancel: the operation already finished");
+            }
+        }
+        KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+            app.confirm_cancel = false;
+            app.set_status("Operation continues");
+        }
+        _ => {}
+    }
+}
+
 fn handle_version_input(app: &mut App, key: KeyCode) -> anyhow::Result<bool> {
     match key {
         KeyCode::Esc => {
-            app.input_mode = InputMode::Normal;
-            app.version_input.clear();
+            app.close_version_picker();
             app.set_status("Cancelled");
         }
         KeyCode::Enter => {
-            let version = app.version_input.trim().to_string();
-            app.input_mode = InputMode::Normal;
-            app.version_input.clear();
+            let version = app.picked_version();
+            app.close_version_picker();
             if let Some(pkg) = app.selected_package() {
                 let id = pkg.id.clone();
-                let (msg, ver) = if version.is_empty() {
-                    (format!("Install {}?", id), None)
-                } else {
-                    (format!("Install {} v{}?", id, version), Some(version))
+                let msg = match &version {
+                    Some(v) => format!("Install {} v{}?", id, v),
+                    None => format!("Install {}?", id),
                 };
                 app.confirm = Some(ConfirmDialog {
                     message: msg,
-                    operation: Operation::Install { id, version: ver },
+                    operation: Operation::Install {
+                        id,
+                        version,
+                        options: InstallOptions::default(),
+                    },
                 });
             }
         }
+        KeyCode::Up => app.move_version_selection(-1),
+        KeyCode::Down => app.move_version_selection(1),
+        KeyCode::PageUp => app.move_version_selection(-10),
+        KeyCode::PageDown => app.move_version_selection(10),
         KeyCode::Backspace => {
             app.version_input.pop();
+            app.picker_selected = 0;
         }
         KeyCode::Char(c) => {
             app.version_input.push(c);
+            app.picker_selected = 0;
         }
         _ => {}
     }
     Ok(false)
 }
 
+fn handle_install_dialog(app: &mut App, key: KeyCode) {
+    let Some(dialog) = app.install_dialog.as_mut() else {
+        return;
+    };
+    let text_field = dialog.current_field().is_text();
+    match key {
+        KeyCode::Esc => {
+            app.install_dialog = None;
+            app.set_status("Cancelled");
+        }
+        KeyCode::Enter => {
+            if let Some(dialog) = app.install_dialog.take() {
+                let operation = dialog.operation();
+                let message = match &operation {
+                    Operation::Install { id, options, .. } if options.args().is_empty() => {
+                        format!("Install {}?", id)
+                    }
+                    Operation::Install { id, options, .. } => {
+                        format!("Install {} with {}?", id, options.describe())
+                    }
+                    _ => unreachable!("the install dialog only builds installs"),
+                };
+                app.confirm = Some(ConfirmDialog { message, operation });
+            }
+        }
+        KeyCode::Up | KeyCode::BackTab => dialog.move_field(-1),
+        KeyCode::Down | KeyCode::Tab => dialog.move_field(1),
+        KeyCode::Left | KeyCode::Right => dialog.cycle_current(),
+        KeyCode::Char(' ') if !text_field => dialog.cycle_current(),
+        KeyCode::Backspace => dialog.pop_char(),
+        KeyCode::Char(c) => dialog.push_char(c),
+        _ => {}
+    }
+}
+
+fn handle_uninstall_dialog(app: &mut App, key: KeyCode) {
+    let Some(dialog) = app.uninstall_dialog.as_mut() else {
+        return;
+    };
+    match key {
+        KeyCode::Esc => {
+            app.uninstall_dialog = None;
+            app.set_status("Cancelled");
+        }
+        KeyCode::Enter => {
+            if let Some(dialog) = app.uninstall_dialog.take() {
+                let operation = dialog.operation();
+                if let Operation::Uninstall { id, options } = &operation {
+                    app.confirm = Some(ConfirmDialog {
+                        message: format!("Uninstall {}?", options.describe_removal(id)),
+                        operation,
+                    });
+                }
+            }
+        }
+        KeyCode::Up | KeyCode::BackTab => dialog.move_field(-1),
+        KeyCode::Down | KeyCode::Tab => dialog.move_field(1),
+        KeyCode::Left | KeyCode::Right | KeyCode::Char(' ') => dialog.toggle_current(),
+        KeyCode::Backspace => dialog.pop_char(),
+        KeyCode::Char(c) => dialog.push_char(c),
+        _ => {}
+    }
+}
+
+fn handle_pin_dialog(app: &mut App, key: KeyCode) {
+    let Some(dialog) = app.pin_dialog.as_mut() else {
+        return;
+    };
+    match key {
+        KeyCode::Esc => {
+            app.pin_dialog = None;
+            app.set_status("Cancelled");
+        }
+        KeyCode::Enter => match dialog.operation() {
+            Some(operation) => {
+                let message = match &operation {
+                    Operation::Pin { id, pin } => match pin {
+                        PinState::Blocking => {
+                            format!("Pin {} and block upgrades until unpinned?", id)
+                        }
+                        PinState::Gating(range) => {
+                            format!("Pin {} to {} and only upgrade within it?", id, range)
+                        }
+                        PinState::Pinned | PinState::None => {
+                            format!("Pin {} so upgrade --all skips it?", id)
+                        }
+                    },
+                    Operation::Unpin { id } => format!("Remove pin for {}?", id),
+                    _ => unreachable!("the pin dialog only builds pin operations"),
+                };
+                app.pin_dialog = None;
+                app.confirm = Some(ConfirmDialog { message, operation });
+            }
+            None => app.set_status("Enter a version range for the gating pin, e.g. 1.2.*"),
+        },
+        KeyCode::Up | KeyCode::BackTab => dialog.move_selection(-1),
+        KeyCode::Down | KeyCode::Tab => dialog.move_selection(1),
+        KeyCode::Backspace => dialog.pop_char(),
+        KeyCode::Char(c) => dialog.push_char(c),
+        _ => {}
+    }
+}
+
 fn handle_search_input(app: &mut App, key: KeyCode) -> anyhow::Result<bool> {
     match key {
         KeyCode::Esc => {
             app.input_mode = InputMode::Normal;
         }
         KeyCode::Enter => {
+            // Keep editing until the query parses; the bar marks the bad part
+            if let Err(e) = SearchRequest::parse(&app.search_query) {
+                app.set_status(format!("Invalid search: {e}"));
+                return Ok(false);
+            }
             app.input_mode = InputMode::Normal;
             if !app.search_query.is_empty() {
                 app.mode = AppMode::Search;
@@ -236,6 +537,29 @@ fn handle_normal_mode(
         KeyCode::Char('?') => {
             app.show_help = !app.show_help;
         }
+        KeyCode::Char('L') => {
+            app.show_output = true;
+        }
+
+        KeyCode::Char('Q') => {
+            app.show_queue = true;
+            app.queue_selected = app.queue_selected.min(app.queue.len().saturating_sub(1));
+        }
+
+        // Sources view (Shift+F)
+        KeyCode::Char('F') => {
+            app.show_sources = true;
+            app.load_sources();
+        }
+
+        // Cancel the running install / uninstall / upgrade / batch
+        KeyCode::Char('C') => {
+            if app.operation_running() {
+                app.confirm_cancel = true;
+            } else {
+                app.set_status("No operation is running");
+            }
+        }
 
         // Left/Right switch views (Search/Installed/Upgrades)
         KeyCode::Left => {
@@ -245,6 +569,14 @@ fn handle_normal_mode(
             switch_view(app, app.mode.cycle());
         }
 
+        // [ / ] switch detail panel tabs
+        KeyCode::Char('[') => {
+            app.select_detail_tab(app.detail_tab.cycle_back());
+        }
+        KeyCode::Char(']') => {
+            app.select_detail_tab(app.detail_tab.cycle());
+        }
+
         // Tab toggles focus between package list and detail panel
         KeyCode::Tab => {
             app.focus = app.focus.toggle();
@@ -272,7 +604,7 @@ fn handle_normal_mode(
         }
         KeyCode::PageUp => {
             if app.focus == FocusZone::DetailPanel {
-                let page = app.layout.detail_panel.height.saturating_sub(3) as isize;
+                let page = app.detail_viewport_height() as isize;
                 app.scroll_detail(-page);
             } else if !app.filtered_packages.is_empty() {
                 let page = list_page_size(app);
@@ -283,7 +615,7 @@ fn handle_normal_mode(
         }
         KeyCode::PageDown => {
             if app.focus == FocusZone::DetailPanel {
-                let page = app.layout.detail_panel.height.saturating_sub(3) as isize;
+                let page = app.detail_viewport_height() as isize;
                 app.scroll_detail(page);
             } else if !app.filtered_packages.is_empty() {
                 let page = list_page_size(app);
@@ -304,7 +636,7 @@ fn handle_normal_mode(
         }
         KeyCode::End => {
             if app.focus == FocusZone::DetailPanel {
-                let viewport = app.layout.detail_panel.height.saturating_sub(3) as usize;
+                let viewport = app.detail_viewport_height();
                 app.detail_scroll = app.detail_content_lines.saturating_sub(viewport);
             } else if !app.filtered_packages.is_empty() {
                 app.selected = app.filtered_packages.len() - 1;
@@ -329,7 +661,7 @@ fn handle_normal_mode(
 
         // Filter
         KeyCode::Char('f') => {
-            app.source_filter = app.source_filter.cycle();
+            app.source_filter = app.source_filter.cycle(&app.source_names());
             app.selected = 0;
             app.loading = true;
             app.set_status(format!("Filter: {} -- loading...", app.source_filter));
@@ -338,7 +670,7 @@ fn handle_normal_mode(
 
         // Pin filter
         KeyCode::Char('P') => {
-            if app.mode == AppMode::Search {
+            if matches!(app.mode, AppMode::Search | AppMode::Pins) {
                 app.set_status("Pinned filter is available in Installed and Upgrades");
             } else {
                 app.cycle_pin_filter();
@@ -381,13 +713,30 @@ fn handle_normal_mode(
                     let id = pkg.id.clone();
                     app.confirm = Some(ConfirmDialog {
                         message: format!("Install {}?", id),
-                        operation: Operation::Install { id, version: None },
+                        operation: Operation::Install {
+                            id,
+                            version: None,
+                            options: InstallOptions::default(),
+                        },
                     });
                 }
             }
         }
 
-        // Pin / unpin the selected installed package
+        // Install with options (Shift+O)
+        KeyCode::Char('O') => {
+            if let Some(pkg) = app.selected_package() {
+                if pkg.is_truncated() {
+                    app.set_status(
+                        "Cannot install: package ID was truncated by winget — use winget directly",
+                    );
+                } else {
+                    app.install_dialog = Some(InstallDialog::new(pkg.id.clone()));
+                }
+            }
+        }
+
+        // Pin, re-pin or unpin the selected installed package
         KeyCode::Char('p') => {
             if app.mode == AppMode::Search {
                 app.set_status("Pinning applies to installed packages, not search results");
@@ -397,20 +746,34 @@ fn handle_normal_mode(
                         "Cannot pin: package ID was truncated by winget — use winget directly",
                     );
                 } else {
-                    let id = pkg.id.clone();
-                    let (message, operation) = if pkg.pin_state.is_pinned() {
-                        (format!("Remove pin for {}?", id), Operation::Unpin { id })
-                    } else {
-                        (
-                            format!("Pin {} and block upgrades until unpinned?", id),
-                            Operation::Pin { id },
-                        )
-                    };
-                    app.confirm = Some(ConfirmDialog { message, operation });
+                    app.pin_dialog =
+                        Some(PinDialog::new(pkg.id.clone(), &pkg.version, &pkg.pin_state));
                 }
             }
         }
 
+        // Reset all pins (Shift+R, Pins view)
+        KeyCode::Char('R') if app.mode == AppMode::Pins => {
+            // `winget pin reset` has no source option, so the count shown
+            // must be every pin, not just the filtered ones
+            if app.source_filter != SourceFilter::All {
+                app.set_status(
+                    "Reset removes the pins of every source; press f to show all sources first",
+                );
+            } else if app.packages.is_empty() {
+                app.set_status("No pins to reset");
+            } else {
+                app.confirm = Some(ConfirmDialog {
+                    message: format!(
+                        "Remove all {} pin{}?",
+                        app.packages.len(),
+                        if app.packages.len() == 1 { "" } else { "s" }
+                    ),
+                    operation: Operation::ResetPins,
+                });
+            }
+        }
+
         // Install specific version (Shift+I)
         KeyCode::Char('I') => {
             if let Some(pkg) = app.selected_package() {
@@ -419,14 +782,8 @@ fn handle_normal_mode(
                         "Cannot install: package ID was truncated by winget — use winget directly",
                     );
                 } else {
-                    // Pre-fill with available_version if present, else current version
-                    let prefill = if !pkg.available_version.is_empty() {
-                        pkg.available_version.clone()
-                    } else {
-                        pkg.version.clone()
-                    };
-                    app.version_input = prefill;
-                    app.input_mode = InputMode::VersionInput;
+                    let id = pkg.id.clone();
+                    app.open_version_picker(&id);
                 }
             }
         }
@@ -439,11 +796,7 @@ fn handle_normal_mode(
                         "Cannot uninstall: package ID was truncated by winget — use winget directly",
                     );
                 } else {
-                    let id = pkg.id.clone();
-                    app.confirm = Some(ConfirmDialog {
-                        message: format!("Uninstall {}?", id),
-                        operation: Operation::Uninstall { id },
-                    });
+                    app.uninstall_dialog = Some(UninstallDialog::new(pkg.id.clone()));
                 }
             }
         }
@@ -551,6 +904,20 @@ fn handle_normal_mode(
             "Opening changelog ",
         ),
 
+        // Expand or collapse the inline release notes
+        KeyCode::Char('n') => {
+            let has_notes = app
+                .detail
+                .as_ref()
+                .is_some_and(|d| !d.release_notes.is_empty());
+            if has_notes {
+                app.release_notes_expanded = !app.release_notes_expanded;
+                app.select_detail_tab(DetailTab::Overview);
+            } else {
+                app.status_message = "No release notes available for this package".to_string();
+            }
+        }
+
         // Sort: cycle through Name↑ → Name↓ → ID↑ → ID↓ → Version↑ → Version↓ → None
         KeyCode::Char('S') => {
             app.cycle_sort();
@@ -575,7 +942,10 @@ fn switch_view(app: &mut App, new_mode: AppMode) {
     app.detail_loading = false;
     // Invalidate any in-flight detail requests from the previous view
     app.detail_generation += 1;
+    app.cancel_detail_loads();
     app.focus = FocusZone::PackageList;
+    app.stale_list_age = None;
+    app.show_cached_list();
     app.loading = true;
     app.set_status("Loading...");
     app.refresh_view();
@@ -597,7 +967,7 @@ fn load_detail_for_selected(app: &mut App) {
     if let Some(pkg) = pkg {
         let id = pkg.id.clone();
         app.detail_scroll = 0;
-        app.load_detail(&id);
+        app.browse_detail(&id);
     }
 }
 
@@ -611,7 +981,8 @@ fn load_detail_for_selected(app: &mut App) {
 /// - If it is already the active sort field, toggles Asc ↔ Desc.
 /// - Otherwise, activates that field in Asc order.
 ///
-/// Clicking an unsortable column (Source, Available) is a no-op.
+/// The Search view's Match column sorts by relevance. Clicking an unsortable
+/// column (Source, Available) is a no-op.
 fn click_sort_header(app: &mut App, col: u16) {
     let list = app.layout.package_list;
     // Usable content width: strip left border (1), right border/scrollbar (2)
@@ -629,7 +1000,24 @@ fn click_sort_header(app: &mut App, col: u16) {
     // Determine the sort field based on column percentages defined in ui.rs.
     // Non-Upgrades: Name 25%, ID 35%, Version 20%, Source 20% (unsortable)
     // Upgrades:     Name 25%, ID 30%, Version 15%, Available 15% (unsortable), Source 15% (unsortable)
-    let field = if app.mode == AppMode::Upgrades {
+    // Search with a Match column: Name 22%, ID 28%, Version 14%, Match 22%, Source 14% (unsortable)
+    let field = if app.shows_match_column() {
+        let boundary_name = content_width * 22 / 100;
+        let boundary_id = boundary_name + content_width * 28 / 100;
+        let boundary_version = boundary_id + content_width * 14 / 100;
+        let boundary_match = boundary_version + content_width * 22 / 100;
+        if offset < boundary_name {
+            SortField::Name
+        } else if offset < boundary_id {
+            SortField::Id
+        } else if offset < boundary_version {
+            SortField::Version
+        } else if offset < boundary_match {
+            SortField::Relevance
+        } else {
+            return; // Source — not sortable
+        }
+    } else if app.mode == AppMode::Upgrades {
         let boundary_name = content_width * 25 / 100;
         let boundary_id = boundary_name + content_width * 30 / 100;
         let boundary_version = boundary_id + content_width * 15 / 100;
@@ -703,6 +1091,18 @@ fn handle_mouse(app: &mut App, mouse: crossterm::event::MouseEvent) -> anyhow::R
                 app.set_status("Cancelled");
                 return Ok(false);
             }
+            if app.install_dialog.is_some()
+                || app.uninstall_dialog.is_some()
+                || app.pin_dialog.is_some()
+                || app.source_dialog.is_some()
+            {
+                app.install_dialog = None;
+                app.uninstall_dialog = None;
+                app.pin_dialog = None;
+                app.source_dialog = None;
+                app.set_status("Cancelled");
+                return Ok(false);
+            }
 
             // Click on tab bar — switch views
             if in_rect(col, row, app.layout.tab_bar) {
@@ -741,9 +1141,12 @@ fn handle_mouse(app: &mut App, mouse: crossterm::event::MouseEvent) -> anyhow::R
                 return Ok(false);
             }
 
-            // Click on detail panel
+            // Click on detail panel, switching tabs on the tab strip
             if in_rect(col, row, app.layout.detail_panel) {
                 app.focus = FocusZone::DetailPanel;
+                if row == app.layout.detail_tab_y {
+                    handle_detail_tab_click(app, col);
+                }
                 return Ok(false);
             }
         }
@@ -879,19 +1282,35 @@ fn handle_tab_click(app: &mut App, col: u16) {
     }
 }
 
+/// Determine which detail tab was clicked based on x position
+fn handle_detail_tab_click(app: &mut App, col: u16) {
+    let clicked = app
+        .layout
+        .detail_tab_regions
+        .iter()
+        .find(|&&(start_x, end_x, _)| col >= start_x && col < end_x)
+        .map(|&(_, _, tab)| tab);
+    if let Some(tab) = clicked {
+        app.select_detail_tab(tab);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::sync::Arc;
 
-    use anyhow::Result;
     use async_trait::async_trait;
     use crossterm::event::{KeyCode, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
     use ratatui::layout::Rect;
 
     use super::*;
-    use crate::app::{App, ConfirmDialog, InputMode};
-    use crate::backend::WingetBackend;
-    use crate::models::{Operation, Package, PackageDetail, PackagePin, PinState, Source};
+    use crate::app::{App, ConfirmDialog, InputMode, InstallDialog, UninstallDialog};
+    use crate::backend::{ProgressFn, WingetBackend};
+    use crate::error::Result;
+    use crate::models::{
+        InstallMode, InstallScope, Operation, Package, PackageDetail, PackagePin, PinState, Source,
+        UninstallOptions,
+    };
 
     // ── helpers ──────────────────────────────────────────────────────────────
 
@@ -908,7 +1327,7 @@ mod tests {
 
     #[async_trait]
     impl WingetBackend for NoopBackend {
-        async fn search(&self, _: &str, _: Option<&str>) -> Result<Vec<Package>> {
+        async fn search(&self, _: &SearchRequest, _: Option<&str>) -> Result<Vec<Package>> {
             Ok(vec![])
         }
         async fn list_installed(&self, _: Option<&str>) -> Result<Vec<Package>> {
@@ -920,24 +1339,48 @@ mod tests {
         async fn show(&self, _: &str) -> Result<PackageDetail> {
             Ok(PackageDetail::default())
         }
-        async fn install(&self, _: &str, _: Option<&str>) -> Result<String> {
+        async fn show_versions(&self, _: &str) -> Result<Vec<String>> {
+            Ok(vec![])
+        }
+        async fn install(
+            &self,
+            _: &str,
+            _: Option<&str>,
+            _: &InstallOptions,
+            _: &ProgressFn,
+        ) -> Result<String> {
             Ok(String::new())
         }
-        async fn uninstall(&self, _: &str) -> Result<String> {
+        async fn uninstall(&self, _: &str, _: &UninstallOptions, _: &ProgressFn) -> Result<String> {
             Ok(String::new())
         }
-        async fn upgrade(&self, _: &str) -> Result<String> {
+        async fn upgrade(&self, _: &str, _: &ProgressFn) -> Result<String> {
             Ok(String::new())
         }
         async fn list_pins(&self) -> Result<Vec<PackagePin>> {
             Ok(vec![])
         }
-        async fn pin(&self, _: &str) -> Result<String> {
+        async fn pin(&self, _: &str, _: &PinState) -> Result<String> {
             Ok(String::new())
         }
         async fn unpin(&self, _: &str) -> Result<String> {
             Ok(String::new())
         }
+        async fn reset_pins(&self) -> Result<String> {
+            Ok(String::new())
+        }
+        async fn add_source(&self, _: &Source) -> Result<String> {
+            Ok(String::new())
+        }
+        async fn remove_source(&self, _: &str) -> Result<String> {
+            Ok(String::new())
+        }
+        async fn reset_sources(&self) -> Result<String> {
+            Ok(String::new())
+        }
+        async fn update_source(&self, _: &str) -> Result<String> {
+            Ok(String::new())
+        }
         async fn list_sources(&self) -> Result<Vec<Source>> {
             Ok(vec![])
         }
@@ -955,6 +1398,7 @@ mod tests {
             version: version.to_string(),
             source: "winget".to_string(),
             available_version: available.to_string(),
+            match_reason: String::new(),
             pin_state: PinState::None,
         }];
         app.filtered_packages = app.packages.clone();
@@ -971,6 +1415,7 @@ mod tests {
                 version: "1.0.0".to_string(),
                 source: "winget".to_string(),
                 available_version: String::new(),
+                match_reason: String::new(),
                 pin_state: PinState::None,
             })
             .collect();
@@ -1153,6 +1598,17 @@ mod tests {
         assert!(app.search_query.is_empty());
     }
 
+    #[test]
+    fn search_input_enter_with_invalid_syntax_keeps_editing() {
+        let mut app = make_app();
+        app.input_mode = InputMode::Search;
+        app.search_query = "tag:".to_string();
+        let _ = handle_search_input(&mut app, KeyCode::Enter);
+        assert_eq!(app.input_mode, InputMode::Search);
+        assert!(!app.loading, "no search should start");
+        assert_eq!(app.status_message, "Invalid search: 'tag:' needs a value");
+    }
+
     #[test]
     fn slash_key_in_installed_view_enters_local_filter_mode() {
         let mut app = make_app();
@@ -1184,6 +1640,7 @@ mod tests {
                 version: "1.0".to_string(),
                 source: "winget".to_string(),
                 available_version: String::new(),
+                match_reason: String::new(),
                 pin_state: PinState::None,
             },
             Package {
@@ -1192,6 +1649,7 @@ mod tests {
                 version: "1.0".to_string(),
                 source: "winget".to_string(),
                 available_version: String::new(),
+                match_reason: String::new(),
                 pin_state: PinState::None,
             },
         ];
@@ -1337,7 +1795,7 @@ mod tests {
         assert!(confirm.message.contains("Test.App"));
         assert!(confirm.message.contains("2.0.1"));
         match confirm.operation {
-            Operation::Install { id, version } => {
+            Operation::Install { id, version, .. } => {
                 assert_eq!(id, "Test.App");
                 assert_eq!(version, Some("2.0.1".to_string()));
             }
@@ -1424,6 +1882,7 @@ mod tests {
                 version: "1.0".to_string(),
                 source: "winget".to_string(),
                 available_version: "1.1".to_string(),
+                match_reason: String::new(),
                 pin_state: PinState::None,
             },
             Package {
@@ -1432,6 +1891,7 @@ mod tests {
                 version: "1.0".to_string(),
                 source: "winget".to_string(),
                 available_version: "1.1".to_string(),
+                match_reason: String::new(),
                 pin_state: PinState::None,
             },
         ];
@@ -1459,6 +1919,7 @@ mod tests {
             version: "1.0".to_string(),
             source: "winget".to_string(),
             available_version: "1.1".to_string(),
+            match_reason: String::new(),
             pin_state: PinState::None,
         }];
         app.filtered_packages = app.packages.clone();
@@ -1472,6 +1933,140 @@ mod tests {
             .contains("all selected packages have truncated IDs"));
     }
 
+    #[test]
+    fn version_picker_enter_installs_highlighted_version() {
+        let mut app = make_app_with_pkg("Test.App", "1.0", "");
+        app.input_mode = InputMode::VersionInput;
+        app.picker_versions = vec!["2.1.0".into(), "2.0.1".into(), "1.0".into()];
+        let _ = handle_version_input(&mut app, KeyCode::Down);
+        let _ = handle_version_input(&mut app, KeyCode::Enter);
+        assert_eq!(app.input_mode, InputMode::Normal);
+        assert!(app.picker_versions.is_empty());
+        match app.confirm.expect("confirm dialog should be set").operation {
+            Operation::Install { version, .. } => assert_eq!(version.as_deref(), Some("2.0.1")),
+            _ => panic!("expected Install operation"),
+        }
+    }
+
+    #[test]
+    fn version_picker_typing_filters_and_resets_highlight() {
+        let mut app = make_app_with_pkg("Test.App", "1.0", "");
+        app.input_mode = InputMode::VersionInput;
+        app.picker_versions = vec!["2.1.0".into(), "2.0.1".into(), "1.0".into()];
+        app.picker_selected = 2;
+        let _ = handle_version_input(&mut app, KeyCode::Char('2'));
+        let _ = handle_version_input(&mut app, KeyCode::Char('.'));
+        assert_eq!(app.picker_selected, 0);
+        assert_eq!(app.filtered_versions(), vec!["2.", "2.1.0", "2.0.1"]);
+        for _ in 0..3 {
+            let _ = handle_version_input(&mut app, KeyCode::Down);
+        }
+        assert_eq!(app.picker_selected, 2, "highlight stops at the last match");
+    }
+
+    #[test]
+    fn version_picker_enter_with_no_match_uses_typed_version() {
+        let mut app = make_app_with_pkg("Test.App", "1.0", "");
+        app.input_mode = InputMode::VersionInput;
+        app.picker_versions = vec!["2.1.0".into()];
+        app.version_input = "3.0-beta".to_string();
+        let _ = handle_version_input(&mut app, KeyCode::Enter);
+        match app.confirm.expect("confirm dialog should be set").operation {
+            Operation::Install { version, .. } => assert_eq!(version.as_deref(), Some("3.0-beta")),
+            _ => panic!("expected Install operation"),
+        }
+    }
+
+    // ── handle_install_dialog ────────────────────────────────────────────────
+
+    #[test]
+    fn shift_o_opens_install_dialog_for_selected_package() {
+        let mut app = make_app_with_pkg("Git.Git", "1.0", "");
+        let _ = handle_normal_mode(&mut app, KeyCode::Char('O'), KeyModifiers::NONE);
+        let dialog = app.install_dialog.expect("dialog should open");
+        assert_eq!(dialog.id, "Git.Git");
+        assert!(dialog.options.args().is_empty());
+    }
+
+    #[test]
+    fn shift_o_on_truncated_id_shows_status_not_dialog() {
+        let mut app = make_app_with_pkg("Truncated...", "1.0", "");
+        let _ = handle_normal_mode(&mut app, KeyCode::Char('O'), KeyModifiers::NONE);
+        assert!(app.status_message.contains("truncated"));
+        assert!(app.install_dialog.is_none());
+    }
+
+    #[test]
+    fn install_dialog_cycles_choices_and_edits_text() {
+        let mut app = make_app_with_pkg("Git.Git", "1.0", "");
+        app.install_dialog = Some(InstallDialog::new("Git.Git".to_string()));
+        // Scope: any → user → machine
+        handle_install_dialog(&mut app, KeyCode::Right);
+        handle_install_dialog(&mut app, KeyCode::Char(' '));
+        // Architecture: any → x64
+        handle_install_dialog(&mut app, KeyCode::Down);
+        handle_install_dialog(&mut app, KeyCode::Right);
+        // Location accepts spaces and backspace
+        handle_install_dialog(&mut app, KeyCode::Down);
+        handle_install_dialog(&mut app, KeyCode::Down);
+        handle_install_dialog(&mut app, KeyCode::Down);
+        for c in "C:\\My Apps!".chars() {
+            handle_install_dialog(&mut app, KeyCode::Char(c));
+        }
+        handle_install_dialog(&mut app, KeyCode::Backspace);
+        // Mode: default → interactive
+        handle_install_dialog(&mut app, KeyCode::Tab);
+        handle_install_dialog(&mut app, KeyCode::Right);
+
+        let options = &app.install_dialog.as_ref().unwrap().options;
+        assert_eq!(options.scope, InstallScope::Machine);
+        assert_eq!(options.architecture.as_deref(), Some("x64"));
+        assert_eq!(options.location.as_deref(), Some("C:\\My Apps"));
+        assert_eq!(options.mode, InstallMode::Interactive);
+    }
+
+    #[test]
+    fn install_dialog_up_wraps_to_last_field() {
+        let mut app = make_app();
+        app.install_dialog = Some(InstallDialog::new("Git.Git".to_string()));
+        handle_install_dialog(&mut app, KeyCode::Up);
+        assert_eq!(
+            app.install_dialog.unwrap().current_field(),
+            crate::app::InstallField::Override
+        );
+    }
+
+    #[test]
+    fn install_dialog_enter_confirms_install_with_options() {
+        let mut app = make_app();
+        let mut dialog = InstallDialog::new("Git.Git".to_string());
+        dialog.options.scope = InstallScope::User;
+        dialog.options.override_args = Some("  ".to_string());
+        app.install_dialog = Some(dialog);
+        handle_install_dialog(&mut app, KeyCode::Enter);
+
+        assert!(app.install_dialog.is_none());
+        let confirm = app.confirm.expect("confirm dialog should open");
+        assert_eq!(confirm.message, "Install Git.Git with --scope user?");
+        match confirm.operation {
+            Operation::Install { id, options, .. } => {
+                assert_eq!(id, "Git.Git");
+                assert_eq!(options.scope, InstallScope::User);
+                assert_eq!(options.override_args, None, "blank text is left to winget");
+            }
+            _ => panic!("expected Install operation"),
+        }
+    }
+
+    #[test]
+    fn install_dialog_escape_cancels() {
+        let mut app = make_app();
+        app.install_dialog = Some(InstallDialog::new("Git.Git".to_string()));
+        handle_install_dialog(&mut app, KeyCode::Esc);
+        assert!(app.install_dialog.is_none());
+        assert!(app.confirm.is_none());
+    }
+
     // ── open homepage / changelog feedback ───────────────────────────────────
 
     #[test]
@@ -1517,6 +2112,31 @@ mod tests {
         );
     }
 
+    #[test]
+    fn release_notes_toggle_expands_and_collapses() {
+        let mut app = make_app();
+        app.detail = Some(PackageDetail {
+            release_notes: "- Fixed a crash".to_string(),
+            ..PackageDetail::default()
+        });
+        let _ = handle_normal_mode(&mut app, KeyCode::Char('n'), KeyModifiers::NONE);
+        assert!(app.release_notes_expanded);
+        let _ = handle_normal_mode(&mut app, KeyCode::Char('n'), KeyModifiers::NONE);
+        assert!(!app.release_notes_expanded);
+    }
+
+    #[test]
+    fn release_notes_toggle_without_notes_shows_status() {
+        let mut app = make_app();
+        app.detail = Some(PackageDetail::default());
+        let _ = handle_normal_mode(&mut app, KeyCode::Char('n'), KeyModifiers::NONE);
+        assert!(!app.release_notes_expanded);
+        assert_eq!(
+            app.status_message,
+            "No release notes available for this package"
+        );
+    }
+
     #[test]
     fn export_empty_list_shows_status() {
         let mut app = make_app();
@@ -1555,6 +2175,67 @@ mod tests {
         assert!(!app.show_help, "? should toggle the help overlay off");
     }
 
+    #[test]
+    fn shift_l_opens_operation_output_overlay() {
+        let mut app = make_app();
+        let _ = handle_normal_mode(&mut app, KeyCode::Char('L'), KeyModifiers::NONE);
+        assert!(
+            app.show_output,
+            "L should open the operation output overlay"
+        );
+    }
+
+    #[test]
+    fn shift_c_without_running_operation_reports_nothing_to_cancel() {
+        let mut app = make_app();
+        let _ = handle_normal_mode(&mut app, KeyCode::Char('C'), KeyModifiers::NONE);
+        assert!(!app.confirm_cancel);
+        assert_eq!(app.status_message, "No operation is running");
+    }
+
+    #[test]
+    fn cancel_prompt_n_keeps_operation_running() {
+        let mut app = make_app();
+        app.confirm_cancel = true;
+        handle_cancel_confirm(&mut app, KeyCode::Char('n'));
+        assert!(!app.confirm_cancel);
+        assert_eq!(app.status_message, "Operation continues");
+    }
+
+    #[test]
+    fn shift_q_opens_queue_view() {
+        let mut app = make_app();
+        let _ = handle_normal_mode(&mut app, KeyCode::Char('Q'), KeyModifiers::NONE);
+        assert!(app.show_queue);
+        handle_queue_input(&mut app, KeyCode::Esc);
+        assert!(!app.show_queue);
+    }
+
+    #[test]
+    fn queue_view_reorders_selected_pending_entry() {
+        use crate::app::{QueueEntry, QueueStatus};
+        let mut app = make_app();
+        app.queue = ["A", "B"]
+            .iter()
+            .map(|id| QueueEntry {
+                operation: Operation::Uninstall {
+                    id: id.to_string(),
+                    options: UninstallOptions::default(),
+                },
+                status: QueueStatus::Pending,
+                message: String::new(),
+            })
+            .collect();
+        app.queue_selected = 1;
+        handle_queue_input(&mut app, KeyCode::Char('K'));
+        assert_eq!(app.queue_selected, 0, "selection follows the moved entry");
+        assert!(matches!(&app.queue[0].operation, Operation::Uninstall { id, .. } if id == "B"));
+
+        handle_queue_input(&mut app, KeyCode::Char('d'));
+        assert_eq!(app.queue.len(), 1);
+        assert!(app.status_message.starts_with("Removed from queue"));
+    }
+
     #[test]
     fn tab_moves_focus_to_detail_panel() {
         let mut app = make_app();
@@ -1604,37 +2285,173 @@ mod tests {
     }
 
     #[test]
-    fn p_on_unpinned_pkg_creates_pin_confirm() {
+    fn p_on_unpinned_pkg_opens_pin_dialog_defaulting_to_blocking() {
         let mut app = make_app_with_pkg("Valid.Package", "1.0", "");
         app.mode = AppMode::Installed;
         let _ = handle_normal_mode(&mut app, KeyCode::Char('p'), KeyModifiers::NONE);
-        assert!(
-            app.confirm.is_some(),
-            "p on unpinned package should open a pin confirm dialog"
-        );
+        assert!(app.pin_dialog.is_some(), "p should open the pin dialog");
+        handle_pin_dialog(&mut app, KeyCode::Enter);
+        assert!(app.pin_dialog.is_none());
+        let confirm = app.confirm.expect("confirm dialog should open");
         assert!(matches!(
-            app.confirm.unwrap().operation,
-            Operation::Pin { .. }
+            confirm.operation,
+            Operation::Pin {
+                pin: PinState::Blocking,
+                ..
+            }
         ));
     }
 
     #[test]
-    fn p_on_pinned_pkg_creates_unpin_confirm() {
+    fn p_on_pinned_pkg_offers_unpin() {
         let mut app = make_app_with_pkg("Valid.Package", "1.0", "");
         app.mode = AppMode::Installed;
         app.packages[0].pin_state = PinState::Pinned;
         app.filtered_packages[0].pin_state = PinState::Pinned;
         let _ = handle_normal_mode(&mut app, KeyCode::Char('p'), KeyModifiers::NONE);
-        assert!(
-            app.confirm.is_some(),
-            "p on pinned package should open an unpin confirm dialog"
-        );
+        // Regular is highlighted; Up wraps round to "Remove pin"
+        handle_pin_dialog(&mut app, KeyCode::Up);
+        handle_pin_dialog(&mut app, KeyCode::Enter);
+        let confirm = app.confirm.expect("confirm dialog should open");
+        assert_eq!(confirm.message, "Remove pin for Valid.Package?");
+        assert!(matches!(confirm.operation, Operation::Unpin { .. }));
+    }
+
+    // ── sources view (F) ──────────────────────────────────────────────────────
+
+    fn app_with_sources() -> App {
+        let mut app = make_app();
+        app.show_sources = true;
+        app.sources = ["winget", "contoso"]
+            .map(|name| Source {
+                name: name.to_string(),
+                ..Source::default()
+            })
+            .to_vec();
+        app
+    }
+
+    #[test]
+    fn shift_f_opens_sources_view_and_loads_sources() {
+        let rt = test_runtime();
+        let _guard = rt.enter();
+        let mut app = make_app();
+        let _ = handle_normal_mode(&mut app, KeyCode::Char('F'), KeyModifiers::NONE);
+        assert!(app.show_sources);
+        assert!(app.sources_loading);
+        handle_sources_input(&mut app, KeyCode::Esc);
+        assert!(!app.show_sources);
+    }
+
+    #[test]
+    fn sources_view_d_confirms_removal_of_highlighted_source() {
+        let mut app = app_with_sources();
+        handle_sources_input(&mut app, KeyCode::Down);
+        handle_sources_input(&mut app, KeyCode::Char('d'));
+        let confirm = app.confirm.expect("confirm dialog should open");
+        assert_eq!(confirm.message, "Remove source contoso?");
         assert!(matches!(
-            app.confirm.unwrap().operation,
-            Operation::Unpin { .. }
+            confirm.operation,
+            Operation::RemoveSource { ref name } if name == "contoso"
         ));
     }
 
+    #[test]
+    fn sources_view_shift_r_confirms_reset() {
+        let mut app = app_with_sources();
+        handle_sources_input(&mut app, KeyCode::Char('R'));
+        let confirm = app.confirm.expect("confirm dialog should open");
+        assert!(matches!(confirm.operation, Operation::ResetSources));
+    }
+
+    #[test]
+    fn add_source_dialog_builds_confirmed_operation() {
+        let mut app = app_with_sources();
+        handle_sources_input(&mut app, KeyCode::Char('a'));
+        assert!(app.source_dialog.is_some());
+        handle_source_dialog(&mut app, KeyCode::Enter);
+        assert!(app.confirm.is_none(), "name and URL are required");
+        for c in "corp".chars() {
+            handle_source_dialog(&mut app, KeyCode::Char(c));
+        }
+        handle_source_dialog(&mut app, KeyCode::Tab);
+        for c in "https://corp/api".chars() {
+            handle_source_dialog(&mut app, KeyCode::Char(c));
+        }
+        handle_source_dialog(&mut app, KeyCode::Enter);
+        assert!(app.source_dialog.is_none());
+        let confirm = app.confirm.expect("confirm dialog should open");
+        assert_eq!(
+            confirm.message,
+            "Add Microsoft.Rest source corp at https://corp/api?"
+        );
+    }
+
+    #[test]
+    fn shift_r_in_pins_view_confirms_reset_of_all_pins() {
+        let mut app = make_app_with_pkgs(3);
+        app.mode = AppMode::Pins;
+        let _ = handle_normal_mode(&mut app, KeyCode::Char('R'), KeyModifiers::NONE);
+        let confirm = app.confirm.expect("confirm dialog should open");
+        assert_eq!(confirm.message, "Remove all 3 pins?");
+        assert!(matches!(confirm.operation, Operation::ResetPins));
+    }
+
+    #[test]
+    fn shift_r_with_source_filter_refuses_reset() {
+        let mut app = make_app_with_pkgs(3);
+        app.mode = AppMode::Pins;
+        app.source_filter = SourceFilter::named("winget");
+        let _ = handle_normal_mode(&mut app, KeyCode::Char('R'), KeyModifiers::NONE);
+        assert!(app.confirm.is_none());
+        assert!(app.status_message.contains("every source"));
+    }
+
+    #[test]
+    fn shift_r_outside_pins_view_does_nothing() {
+        let mut app = make_app_with_pkgs(3);
+        app.mode = AppMode::Installed;
+        let _ = handle_normal_mode(&mut app, KeyCode::Char('R'), KeyModifiers::NONE);
+        assert!(app.confirm.is_none());
+    }
+
+    #[test]
+    fn shift_p_in_pins_view_leaves_pin_filter_alone() {
+        let mut app = make_app();
+        app.mode = AppMode::Pins;
+        let before = app.pin_filter;
+        let _ = handle_normal_mode(&mut app, KeyCode::Char('P'), KeyModifiers::NONE);
+        assert_eq!(app.pin_filter, before);
+        assert!(app.status_message.contains("Installed and Upgrades"));
+    }
+
+    #[test]
+    fn pin_dialog_gating_pin_uses_typed_range() {
+        let mut app = make_app_with_pkg("Valid.Package", "1.4.2", "");
+        app.mode = AppMode::Installed;
+        let _ = handle_normal_mode(&mut app, KeyCode::Char('p'), KeyModifiers::NONE);
+        handle_pin_dialog(&mut app, KeyCode::Down);
+        for _ in 0.."1.4.*".len() {
+            handle_pin_dialog(&mut app, KeyCode::Backspace);
+        }
+        handle_pin_dialog(&mut app, KeyCode::Enter);
+        assert!(
+            app.pin_dialog.is_some(),
+            "an empty range keeps the dialog open"
+        );
+        assert!(app.confirm.is_none());
+
+        for c in "1.*".chars() {
+            handle_pin_dialog(&mut app, KeyCode::Char(c));
+        }
+        handle_pin_dialog(&mut app, KeyCode::Enter);
+        let confirm = app.confirm.expect("confirm dialog should open");
+        assert_eq!(
+            confirm.message,
+            "Pin Valid.Package to 1.* and only upgrade within it?"
+        );
+    }
+
     #[test]
     fn capital_p_in_search_mode_shows_informational_status() {
         let mut app = make_app();
@@ -1689,14 +2506,54 @@ mod tests {
     }
 
     #[test]
-    fn x_on_valid_pkg_creates_uninstall_confirm() {
+    fn x_on_valid_pkg_opens_uninstall_dialog_then_confirm() {
         let mut app = make_app_with_pkg("Valid.Package", "1.0", "");
         let _ = handle_normal_mode(&mut app, KeyCode::Char('x'), KeyModifiers::NONE);
-        assert!(app.confirm.is_some());
-        assert!(matches!(
-            app.confirm.unwrap().operation,
-            Operation::Uninstall { .. }
-        ));
+        assert!(app.uninstall_dialog.is_some());
+        assert!(app.confirm.is_none());
+
+        handle_uninstall_dialog(&mut app, KeyCode::Enter);
+        assert!(app.uninstall_dialog.is_none());
+        let confirm = app.confirm.expect("confirm dialog should open");
+        assert_eq!(confirm.message, "Uninstall Valid.Package?");
+        assert!(matches!(confirm.operation, Operation::Uninstall { .. }));
+    }
+
+    #[test]
+    fn uninstall_dialog_confirm_states_what_is_removed() {
+        let mut app = make_app_with_pkg("Valid.Package", "1.0", "");
+        app.uninstall_dialog = Some(UninstallDialog::new("Valid.Package".to_string()));
+        // Type a version
+        handle_uninstall_dialog(&mut app, KeyCode::Char('1'));
+        // All versions → yes, which wins over the typed version
+        handle_uninstall_dialog(&mut app, KeyCode::Down);
+        handle_uninstall_dialog(&mut app, KeyCode::Char(' '));
+        // Force → yes
+        handle_uninstall_dialog(&mut app, KeyCode::Up);
+        handle_uninstall_dialog(&mut app, KeyCode::Up);
+        handle_uninstall_dialog(&mut app, KeyCode::Right);
+        handle_uninstall_dialog(&mut app, KeyCode::Enter);
+
+        let confirm = app.confirm.expect("confirm dialog should open");
+        assert_eq!(
+            confirm.message,
+            "Uninstall every installed version of Valid.Package, forcing removal?"
+        );
+        match confirm.operation {
+            Operation::Uninstall { options, .. } => {
+                assert_eq!(options.args(), vec!["--all-versions", "--force"]);
+            }
+            _ => panic!("expected Uninstall operation"),
+        }
+    }
+
+    #[test]
+    fn uninstall_dialog_escape_cancels() {
+        let mut app = make_app();
+        app.uninstall_dialog = Some(UninstallDialog::new("Foo.Bar".to_string()));
+        handle_uninstall_dialog(&mut app, KeyCode::Esc);
+        assert!(app.uninstall_dialog.is_none());
+        assert!(app.confirm.is_none());
     }
 
     #[test]
@@ -1731,19 +2588,16 @@ mod tests {
     }
 
     #[test]
-    fn shift_i_prefills_available_version_when_present() {
+    fn shift_i_opens_version_picker_with_empty_filter() {
+        let rt = test_runtime();
+        let _guard = rt.enter();
         let mut app = make_app_with_pkg("Valid.Package", "1.0", "2.0");
+        app.version_input = "stale".to_string();
         let _ = handle_normal_mode(&mut app, KeyCode::Char('I'), KeyModifiers::NONE);
         assert_eq!(app.input_mode, InputMode::VersionInput);
-        assert_eq!(app.version_input, "2.0");
-    }
-
-    #[test]
-    fn shift_i_falls_back_to_current_version_when_no_available() {
-        let mut app = make_app_with_pkg("Valid.Package", "3.5", "");
-        let _ = handle_normal_mode(&mut app, KeyCode::Char('I'), KeyModifiers::NONE);
-        assert_eq!(app.input_mode, InputMode::VersionInput);
-        assert_eq!(app.version_input, "3.5");
+        assert_eq!(app.version_input, "");
+        assert_eq!(app.picker_id, "Valid.Package");
+        assert!(app.picker_loading);
     }
 
     // ── handle_normal_mode: multi-select (Space / a) ─────────────────────────
@@ -1782,7 +2636,23 @@ mod tests {
         let mut app = make_app();
         assert_eq!(app.source_filter, SourceFilter::All);
         let _ = handle_normal_mode(&mut app, KeyCode::Char('f'), KeyModifiers::NONE);
-        assert_eq!(app.source_filter, SourceFilter::Winget);
+        assert_eq!(app.source_filter, SourceFilter::named("winget"));
+    }
+
+    #[test]
+    fn f_key_cycles_through_listed_custom_sources() {
+        use crate::models::SourceFilter;
+        let rt = test_runtime();
+        let _guard = rt.enter();
+        let mut app = make_app();
+        app.sources = vec![Source {
+            name: "contoso".to_string(),
+            ..Source::default()
+        }];
+        let _ = handle_normal_mode(&mut app, KeyCode::Char('f'), KeyModifiers::NONE);
+        assert_eq!(app.source_filter, SourceFilter::named("contoso"));
+        let _ = handle_normal_mode(&mut app, KeyCode::Char('f'), KeyModifiers::NONE);
+        assert_eq!(app.source_filter, SourceFilter::All);
     }
 
     #[test]
@@ -1898,6 +2768,53 @@ mod tests {
         assert_eq!(app.table_state.offset(), 2); // scrolled up by 3
     }
 
+    #[tokio::test]
+    async fn mouse_click_on_detail_tab_switches_tab() {
+        let mut app = make_app();
+        app.layout.detail_panel = rect(60, 10, 60, 20);
+        app.layout.detail_tab_y = 11;
+        app.layout.detail_tab_regions = vec![
+            (62, 72, DetailTab::Overview),
+            (73, 83, DetailTab::Versions),
+            (84, 95, DetailTab::Installer),
+        ];
+
+        let _ = handle_mouse(
+            &mut app,
+            MouseEvent {
+                kind: MouseEventKind::Down(MouseButton::Left),
+                column: 86,
+                row: 11,
+                modifiers: KeyModifiers::NONE,
+            },
+        );
+
+        assert_eq!(app.detail_tab, DetailTab::Installer);
+        assert_eq!(app.focus, FocusZone::DetailPanel);
+    }
+
+    #[test]
+    fn brackets_cycle_detail_tabs() {
+        let mut app = make_app();
+        let _ = handle_normal_mode(&mut app, KeyCode::Char(']'), KeyModifiers::NONE);
+        assert_eq!(app.detail_tab, DetailTab::Versions);
+        let _ = handle_normal_mode(&mut app, KeyCode::Char('['), KeyModifiers::NONE);
+        let _ = handle_normal_mode(&mut app, KeyCode::Char('['), KeyModifiers::NONE);
+        assert_eq!(app.detail_tab, DetailTab::Raw, "[ wraps to the last tab");
+    }
+
+    #[test]
+    fn expanding_release_notes_returns_to_overview() {
+        let mut app = make_app();
+        app.detail_tab = DetailTab::Raw;
+        app.detail = Some(PackageDetail {
+            release_notes: "- Fixed a crash".to_string(),
+            ..PackageDetail::default()
+        });
+        let _ = handle_normal_mode(&mut app, KeyCode::Char('n'), KeyModifiers::NONE);
+        assert_eq!(app.detail_tab, DetailTab::Overview);
+    }
+
     // ── switch_view state reset ───────────────────────────────────────────────
 
     #[test]
@@ -2347,6 +3264,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn click_sort_header_match_column_sets_relevance_sort() {
+        let mut app = make_app_with_list_layout();
+        app.mode = AppMode::Search;
+        app.filtered_packages[0].match_reason = "Tag: json".to_string();
+        // Content width=97; Match spans 61..82 (Name 22%, ID 28%, Version 14%)
+        click_sort_header(&mut app, 70);
+        assert_eq!(app.sort_field, SortField::Relevance);
+        click_sort_header(&mut app, 90); // Source
+        assert_eq!(app.sort_field, SortField::Relevance);
+    }
+
     #[test]
     fn click_sort_header_zero_width_is_noop() {
         let mut app = make_app_with_list_layout();
@@ -0,0 +1,807 @@
+//! Headless subcommands that print winget data for scripts instead of
+//! starting the TUI.
+//!
+//! ```text
+//! winget-tui list               installed packages
+//! winget-tui upgrades           packages with an available upgrade
+//! winget-tui search <query>     search, with the TUI's tag:/cmd:/id: syntax
+//! winget-tui show <id>          details of one package
+//! winget-tui pins               configured pins
+//! winget-tui check              like upgrades, exiting 100 when any exist
+//! ```
+//!
+//! Each takes `--format table|json|csv` (or `--json` / `--csv`) and
+//! `--source <name>`. The terminal is never switched to raw mode.
+
+use std::io::Write;
+
+use serde::Serialize;
+use unicode_width::UnicodeWidthStr;
+
+use crate::app::{csv_escape, App};
+use crate::backend::WingetBackend;
+use crate::models::{Package, PackageDetail, PackagePin, PinState, SearchRequest};
+
+/// Exit code of `check` when upgrades are available, as `dnf check-update` does
+pub const UPGRADES_AVAILABLE_EXIT: i32 = 100;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    List,
+    Upgrades,
+    Search(String),
+    Show(String),
+    Pins,
+    Check,
+    Help,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    #[default]
+    Table,
+    Json,
+    Csv,
+}
+
+impl Format {
+    fn parse(value: &str) -> Result<Self, String> {
+        match value.to_ascii_lowercase().as_str() {
+            "table" => Ok(Self::Table),
+            "json" => Ok(Self::Json),
+            "csv" => Ok(Self::Csv),
+            other => Err(format!(
+                "unknown format '{other}' -- use table, json or csv"
+            )),
+        }
+    }
+}
+
+/// A parsed headless command line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Invocation {
+    pub command: Command,
+    pub format: Format,
+    pub source: Option<String>,
+}
+
+impl Invocation {
+    pub fn help() -> Self {
+        Self {
+            command: Command::Help,
+            format: Format::default(),
+            source: None,
+        }
+    }
+}
+
+/// Parse a subcommand and its options. Returns `Ok(None)` when they
+/// name no subcommand, meaning the TUI should start.
+pub fn parse_args(args: &[String]) -> Result<Option<Invocation>, String> {
+    let Some((name, rest)) = args.split_first() else {
+        return Ok(None);
+    };
+    let takes_operand = match name.as_str() {
+        "list" | "upgrades" | "pins" | "check" => false,
+        "search" | "show" => true,
+        "help" => return Ok(Some(Invocation::help())),
+        other => return Err(format!("unknown command '{other}'")),
+    };
+
+    let mut format = Format::default();
+    let mut source = None;
+    let mut operands: Vec<&str> = Vec::new();
+    let mut rest = rest.iter();
+    while let Some(arg) = rest.next() {
+        let mut value_of = |flag: &str| {
+            rest.next()
+                .map(String::as_str)
+                .ok_or_else(|| format!("'{flag}' needs a value"))
+        };
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(Some(Invocation::help())),
+            "--json" => format = Format::Json,
+            "--csv" => format = Format::Csv,
+            "-f" | "--format" => format = Format::parse(value_of(arg)?)?,
+            "-s" | "--source" => source = Some(value_of(arg)?.to_string()),
+            flag if flag.starts_with("--format=") => {
+                format = Format::parse(&flag["--format=".len()..])?;
+            }
+            flag if flag.starts_with("--source=") => {
+                source = Some(flag["--source=".len()..].to_string());
+            }
+            flag if flag.starts_with('-') && flag.len() > 1 => {
+                return Err(format!("unknown option '{flag}'"));
+            }
+            operand => operands.push(operand),
+        }
+    }
+
+    let command = match (name.as_str(), operands.is_empty()) {
+        (_, false) if !takes_operand => {
+            return Err(format!("'{name}' takes no arguments"));
+        }
+        ("search", true) => return Err("'search' needs a query".to_string()),
+        ("show", true) => return Err("'show' needs a package id".to_string()),
+        ("search", false) => Command::Search(operands.join(" ")),
+        ("show", false) if operands.len() == 1 => Command::Show(operands[0].to_string()),
+        ("show", false) => return Err("'show' takes a single package id".to_string()),
+        ("list", _) => Command::List,
+        ("upgrades", _) => Command::Upgrades,
+        ("pins", _) => Command::Pins,
+        _ => Command::Check,
+    };
+    Ok(Some(Invocation {
+        command,
+        format,
+        source,
+    }))
+}
+
+/// Run `invocation` against `backend`, writing the result to `out`.
+/// Returns the process exit code.
+pub async fn run(
+    invocation: &Invocation,
+    backend: &dyn WingetBackend,
+    out: &mut dyn Write,
+) -> anyhow::Result<i32> {
+    let source = invocation.source.as_deref();
+    let format = invocation.format;
+    match &invocation.command {
+        Command::Help => {
+            writeln!(out, "{}", crate::cli::USAGE)?;
+            Ok(0)
+        }
+        Command::List => {
+            let packages = with_pins(backend, backend.list_installed(source).await?).await;
+            write_packages(out, &packages, Columns::Installed, format)?;
+            Ok(0)
+        }
+        Command::Upgrades | Command::Check => {
+            let packages = with_pins(backend, backend.list_upgrades(source).await?).await;
+            write_packages(out, &packages, Columns::Upgrades, format)?;
+            let upgrades_found = !packages.is_empty();
+            Ok(match invocation.command {
+                Command::Check if upgrades_found => UPGRADES_AVAILABLE_EXIT,
+                _ => 0,
+            })
+        }
+        Command::Search(query) => {
+            let request = SearchRequest::parse(query).map_err(|e| anyhow::anyhow!("{e}"))?;
+            let packages = backend.search(&request, source).await?;
+            write_packages(out, &packages, Columns::Search, format)?;
+            Ok(0)
+        }
+        Command::Pins => {
+            let packages: Vec<Package> = backend
+                .list_pins()
+                .await?
+                .into_iter()
+                .filter(|pin| source.is_none_or(|src| pin.source.eq_ignore_ascii_case(src)))
+                .map(PackagePin::into_package)
+                .collect();
+            write_packages(out, &packages, Columns::Pins, format)?;
+            Ok(0)
+        }
+        Command::Show(id) => {
+            let detail = backend.show(id).await?;
+            write_detail(out, &detail, format)?;
+            Ok(0)
+        }
+    }
+}
+
+/// Mark pinned packages, as the Installed and Upgrades views do. Missing pin
+/// information is not worth failing the command over.
+async fn with_pins(backend: &dyn WingetBackend, mut packages: Vec<Package>) -> Vec<Package> {
+    if let Ok(pins) = backend.list_pins().await {
+        App::annotate_pins(&mut packages, pins);
+    }
+    packages
+}
+
+/// Which columns a package listing prints, matching the TUI's views.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Columns {
+    Installed,
+    Upgrades,
+    Search,
+    Pins,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Column {
+    Name,
+    Id,
+    Version,
+    Source,
+    Available,
+    Match,
+    Pin,
+}
+
+impl Column {
+    fn header(self) -> &'static str {
+        match self {
+            Self::Name => "Name",
+            Self::Id => "Id",
+            Self::Version => "Version",
+            Self::Source => "Source",
+            Self::Available => "AvailableVersion",
+            Self::Match => "Match",
+            Self::Pin => "Pin",
+        }
+    }
+
+    fn value(self, pkg: &Package) -> String {
+        match self {
+            Self::Name => pkg.name.clone(),
+            Self::Id => pkg.id.clone(),
+            Self::Version => pkg.version.clone(),
+            Self::Source => pkg.source.clone(),
+            Self::Available => pkg.available_version.clone(),
+            Self::Match => pkg.match_reason.clone(),
+            Self::Pin => pkg.pin_state.kind().to_string(),
+        }
+    }
+}
+
+impl Columns {
+    /// Optional columns only appear when some package has a value for them.
+    fn for_packages(self, packages: &[Package]) -> Vec<Column> {
+        let mut columns = vec![Column::Name, Column::Id, Column::Version, Column::Source];
+        match self {
+            Self::Upgrades => columns.push(Column::Available),
+            Self::Search if packages.iter().any(|p| !p.match_reason.is_empty()) => {
+                columns.push(Column::Match)
+            }
+            _ => {}
+        }
+        if self == Self::Pins || packages.iter().any(|p| p.pin_state.is_pinned()) {
+            columns.push(Column::Pin);
+        }
+        columns
+    }
+}
+
+/// One package as printed by `--format json`.
+#[derive(Serialize)]
+struct PackageRecord<'a> {
+    id: &'a str,
+    name: &'a str,
+    version: &'a str,
+    source: &'a str,
+    #[serde(skip_serializing_if = "str::is_empty")]
+    available_version: &'a str,
+    #[serde(rename = "match", skip_serializing_if = "str::is_empty")]
+    match_reason: &'a str,
+    /// "Pinning", "Blocking" or "Gating", as `winget pin list` names them
+    #[serde(skip_serializing_if = "str::is_empty")]
+    pin: &'a str,
+    /// The gated version range, for gating pins
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pin_range: Option<&'a str>,
+}
+
+impl<'a> From<&'a Package> for PackageRecord<'a> {
+    fn from(pkg: &'a Package) -> Self {
+        Self {
+            id: &pkg.id,
+            name: &pkg.name,
+            version: &pkg.version,
+            source: &pkg.source,
+            available_version: &pkg.available_version,
+            match_reason: &pkg.match_reason,
+            pin: pkg.pin_state.kind(),
+            pin_range: match &pkg.pin_state {
+                PinState::Gating(range) => Some(range),
+                _ => None,
+            },
+        }
+    }
+}
+
+/// A package's details as printed by `show --format json`. Kept apart from
+/// [`PackageDetail`], whose serialized form is the disk cache's format.
+#[derive(Serialize)]
+struct DetailRecord<'a> {
+    id: &'a str,
+    name: &'a str,
+    version: &'a str,
+    source: &'a str,
+    #[serde(skip_serializing_if = "str::is_empty")]
+    publisher: &'a str,
+    #[serde(skip_serializing_if = "str::is_empty")]
+    author: &'a str,
+    #[serde(skip_serializing_if = "str::is_empty")]
+    moniker: &'a str,
+    #[serde(skip_serializing_if = "str::is_empty")]
+    description: &'a str,
+    #[serde(skip_serializing_if = "str::is_empty")]
+    homepage: &'a str,
+    #[serde(skip_serializing_if = "str::is_empty")]
+    license: &'a str,
+    #[serde(skip_serializing_if = "str::is_empty")]
+    copyright: &'a str,
+    #[serde(skip_serializing_if = "str::is_empty")]
+    privacy_url: &'a str,
+    #[serde(skip_serializing_if = "str::is_empty")]
+    support_url: &'a str,
+    tags: &'a [String],
+    #[serde(skip_serializing_if = "str::is_empty")]
+    release_notes: &'a str,
+    #[serde(skip_serializing_if = "str::is_empty")]
+    release_notes_url: &'a str,
+    #[serde(skip_serializing_if = "str::is_empty")]
+    release_date: &'a str,
+    #[serde(skip_serializing_if = "str::is_empty")]
+    installer_type: &'a str,
+    #[serde(skip_serializing_if = "str::is_empty")]
+    installer_url: &'a str,
+    #[serde(skip_serializing_if = "str::is_empty")]
+    installer_sha256: &'a str,
+    documentation: Vec<LinkRecord<'a>>,
+    dependencies: Vec<DependencyRecord<'a>>,
+    agreements: Vec<AgreementRecord<'a>>,
+    /// Same as [`PackageRecord::pin`]
+    #[serde(skip_serializing_if = "str::is_empty")]
+    pin: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pin_range: Option<&'a str>,
+}
+
+#[derive(Serialize)]
+struct LinkRecord<'a> {
+    label: &'a str,
+    url: &'a str,
+}
+
+#[derive(Serialize)]
+struct DependencyRecord<'a> {
+    kind: &'a str,
+    items: &'a [String],
+}
+
+#[derive(Serialize)]
+struct AgreementRecord<'a> {
+    label: &'a str,
+    /// The agreement's text or URL
+    text: &'a str,
+}
+
+impl<'a> From<&'a PackageDetail> for DetailRecord<'a> {
+    fn from(detail: &'a PackageDetail) -> Self {
+        Self {
+            id: &detail.id,
+            name: &detail.name,
+            version: &detail.version,
+            source: &detail.source,
+            publisher: &detail.publisher,
+            author: &detail.author,
+            moniker: &detail.moniker,
+            description: &detail.description,
+            homepage: &detail.homepage,
+            license: &detail.license,
+            copyright: &detail.copyright,
+            privacy_url: &detail.privacy_url,
+            support_url: &detail.support_url,
+            tags: &detail.tags,
+            release_notes: &detail.release_notes,
+            release_notes_url: &detail.release_notes_url,
+            release_date: &detail.release_date,
+            installer_type: &detail.installer_type,
+            installer_url: &detail.installer_url,
+            installer_sha256: &detail.installer_sha256,
+            documentation: detail
+                .documentation
+                .iter()
+                .map(|(label, url)| LinkRecord { label, url })
+                .collect(),
+            dependencies: detail
+                .dependencies
+                .iter()
+                .map(|group| DependencyRecord {
+                    kind: &group.kind,
+                    items: &group.items,
+                })
+                .collect(),
+            agreements: detail
+                .agreements
+                .iter()
+                .map(|(label, text)| AgreementRecord { label, text })
+                .collect(),
+            pin: detail.pin_state.kind(),
+            pin_range: match &detail.pin_state {
+                PinState::Gating(range) => Some(range),
+                _ => None,
+            },
+        }
+    }
+}
+
+fn write_packages(
+    out: &mut dyn Write,
+    packages: &[Package],
+    columns: Columns,
+    format: Format,
+) -> std::io::Result<()> {
+    match format {
+        Format::Json => {
+            let records: Vec<PackageRecord> = packages.iter().map(PackageRecord::from).collect();
+            serde_json::to_writer_pretty(&mut *out, &records)?;
+            writeln!(out)
+        }
+        Format::Csv | Format::Table => {
+            let columns = columns.for_packages(packages);
+            let headers: Vec<&str> = columns.iter().map(|c| c.header()).collect();
+            let rows: Vec<Vec<String>> = packages
+                .iter()
+                .map(|pkg| columns.iter().map(|c| c.value(pkg)).collect())
+                .collect();
+            if format == Format::Csv {
+                write_csv(out, &headers, &rows)
+            } else {
+                write_table(out, &headers, &rows)
+            }
+        }
+    }
+}
+
+fn write_detail(
+    out: &mut dyn Write,
+    detail: &PackageDetail,
+    format: Format,
+) -> std::io::Result<()> {
+    if format == Format::Json {
+        serde_json::to_writer_pretty(&mut *out, &DetailRecord::from(detail))?;
+        return writeln!(out);
+    }
+    let fields = detail_fields(detail);
+    match format {
+        Format::Csv => {
+            let rows: Vec<Vec<String>> = fields
+                .into_iter()
+                .map(|(label, value)| vec![label, value])
+                .collect();
+            write_csv(out, &["Field", "Value"], &rows)
+        }
+        _ => {
+            let label_width = fields.iter().map(|(l, _)| l.width()).max().unwrap_or(0);
+            for (label, value) in fields {
+                let mut lines = value.lines();
+                let first = lines.next().unwrap_or_default();
+                let pad = label_width - label.width();
+                writeln!(out, "{label}:{} {first}", " ".repeat(pad))?;
+                for line in lines {
+                    writeln!(out, "{} {line}", " ".repeat(label_width + 1))?;
+                }
+            }
+            Ok(())
+        }
+    }
+}
+
+/// The non-empty fields of `detail` as (label, value) pairs, in the order
+/// the detail panel shows them.
+fn detail_fields(detail: &PackageDetail) -> Vec<(String, String)> {
+    let mut fields: Vec<(String, String)> = [
+        ("Id", detail.id.clone()),
+        ("Name", detail.name.clone()),
+        ("Version", detail.version.clone()),
+        ("Publisher", detail.publisher.clone()),
+        ("Author", detail.author.clone()),
+        ("Moniker", detail.moniker.clone()),
+        ("Source", detail.source.clone()),
+        ("Description", detail.description.clone()),
+        ("Homepage", detail.homepage.clone()),
+        ("License", detail.license.clone()),
+        ("Copyright", detail.copyright.clone()),
+        ("Privacy", detail.privacy_url.clone()),
+        ("Support", detail.support_url.clone()),
+        ("Tags", detail.tags.join(", ")),
+        ("Pin", detail.pin_state.kind().to_string()),
+        ("Release Notes", detail.release_notes.clone()),
+        ("Release Notes URL", detail.release_notes_url.clone()),
+        ("Released", detail.release_date.clone()),
+        ("Installer Type", detail.installer_type.clone()),
+        ("Installer URL", detail.installer_url.clone()),
+        ("Installer SHA256", detail.installer_sha256.clone()),
+    ]
+    .into_iter()
+    .map(|(label, value)| (label.to_string(), value))
+    .collect();
+    fields.extend(detail.documentation.iter().cloned());
+    fields.extend(
+        detail
+            .dependencies
+            .iter()
+            .map(|group| (group.kind.clone(), group.items.join(", "))),
+    );
+    fields.extend(detail.agreements.iter().cloned());
+    fields.retain(|(_, value)| !value.is_empty());
+    fields
+}
+
+fn write_csv(out: &mut dyn Write, headers: &[&str], rows: &[Vec<String>]) -> std::io::Result<()> {
+    writeln!(out, "{}", headers.join(","))?;
+    for row in rows {
+        let cells: Vec<String> = row.iter().map(|cell| csv_escape(cell)).collect();
+        writeln!(out, "{}", cells.join(","))?;
+    }
+    Ok(())
+}
+
+/// Columns padded to their widest cell, with a dashed rule under the
+/// header like winget's own tables.
+fn write_table(out: &mut dyn Write, headers: &[&str], rows: &[Vec<String>]) -> std::io::Result<()> {
+    let mut widths: Vec<usize> = headers.iter().map(|h| h.width()).collect();
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.width());
+        }
+    }
+    let line = |cells: &mut dyn Iterator<Item = &str>| {
+        let padded: Vec<String> = cells
+            .zip(&widths)
+            .map(|(cell, &width)| format!("{cell}{}", " ".repeat(width - cell.width())))
+            .collect();
+        padded.join(" ").trim_end().to_string()
+    };
+    writeln!(out, "{}", line(&mut headers.iter().copied()))?;
+    let rule = widths.iter().sum::<usize>() + widths.len().saturating_sub(1);
+    writeln!(out, "{}", "-".repeat(rule))?;
+    for row in rows {
+        writeln!(out, "{}", line(&mut row.iter().map(String::as_str)))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+
+    use crate::backend::ProgressFn;
+    use crate::error::Result;
+    use crate::models::{InstallOptions, Source, UninstallOptions};
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(str::to_string).collect()
+    }
+
+    fn pkg(id: &str, name: &str, version: &str) -> Package {
+        Package {
+            id: id.to_string(),
+            name: name.to_string(),
+            version: version.to_string(),
+            source: "winget".to_string(),
+            available_version: String::new(),
+            match_reason: String::new(),
+            pin_state: PinState::None,
+        }
+    }
+
+    /// Serves fixed upgrades and pins; everything else is empty.
+    struct FixedBackend {
+        upgrades: Vec<Package>,
+        pins: Vec<PackagePin>,
+    }
+
+    #[async_trait]
+    impl WingetBackend for FixedBackend {
+        async fn search(&self, request: &SearchRequest, _: Option<&str>) -> Result<Vec<Package>> {
+            let mut found = pkg("Found.Pkg", "Found", "1.0");
+            found.match_reason = format!("Tag: {}", request.query);
+            Ok(vec![found])
+        }
+        async fn list_installed(&self, _: Option<&str>) -> Result<Vec<Package>> {
+            Ok(vec![])
+        }
+        async fn list_upgrades(&self, _: Option<&str>) -> Result<Vec<Package>> {
+            Ok(self.upgrades.clone())
+        }
+        async fn show(&self, id: &str) -> Result<PackageDetail> {
+            Ok(PackageDetail {
+                id: id.to_string(),
+                name: "Shown".to_string(),
+                description: "First line\nSecond line".to_string(),
+                documentation: vec![("FAQ".to_string(), "https://example.com/faq".to_string())],
+                agreements: vec![("Pricing".to_string(), "Free".to_string())],
+                pin_state: PinState::Gating("1.*".to_string()),
+                raw: "Found Shown [Some.Id]".to_string(),
+                ..PackageDetail::default()
+            })
+        }
+        async fn show_versions(&self, _: &str) -> Result<Vec<String>> {
+            Ok(vec![])
+        }
+        async fn install(
+            &self,
+            _: &str,
+            _: Option<&str>,
+            _: &InstallOptions,
+            _: &ProgressFn,
+        ) -> Result<String> {
+            Ok(String::new())
+        }
+        async fn uninstall(&self, _: &str, _: &UninstallOptions, _: &ProgressFn) -> Result<String> {
+            Ok(String::new())
+        }
+        async fn upgrade(&self, _: &str, _: &ProgressFn) -> Result<String> {
+            Ok(String::new())
+        }
+        async fn list_pins(&self) -> Result<Vec<PackagePin>> {
+            Ok(self.pins.clone())
+        }
+        async fn pin(&self, _: &str, _: &PinState) -> Result<String> {
+            Ok(String::new())
+        }
+        async fn unpin(&self, _: &str) -> Result<String> {
+            Ok(String::new())
+        }
+        async fn reset_pins(&self) -> Result<String> {
+            Ok(String::new())
+        }
+        async fn add_source(&self, _: &Source) -> Result<String> {
+            Ok(String::new())
+        }
+        async fn remove_source(&self, _: &str) -> Result<String> {
+            Ok(String::new())
+        }
+        async fn reset_sources(&self) -> Result<String> {
+            Ok(String::new())
+        }
+        async fn update_source(&self, _: &str) -> Result<String> {
+            Ok(String::new())
+        }
+        async fn list_sources(&self) -> Result<Vec<Source>> {
+            Ok(vec![])
+        }
+    }
+
+    fn backend_with_upgrade() -> FixedBackend {
+        let mut upgrade = pkg("Git.Git", "Git", "2.40.0");
+        upgrade.available_version = "2.45.1".to_string();
+        FixedBackend {
+            upgrades: vec![upgrade],
+            pins: vec![PackagePin {
+                id: "Git.Git".to_string(),
+                name: "Git".to_string(),
+                pinned_version: "2.*".to_string(),
+                source: "winget".to_string(),
+                pin_state: PinState::Gating("2.*".to_string()),
+            }],
+        }
+    }
+
+    async fn run_to_string(line: &str, backend: &FixedBackend) -> (i32, String) {
+        let invocation = parse_args(&args(line)).unwrap().unwrap();
+        let mut out = Vec::new();
+        let code = run(&invocation, backend, &mut out).await.unwrap();
+        (code, String::from_utf8(out).unwrap())
+    }
+
+    #[test]
+    fn parse_args_without_command_starts_tui() {
+        assert_eq!(parse_args(&[]), Ok(None));
+    }
+
+    #[test]
+    fn parse_args_reads_format_and_source() {
+        let inv = parse_args(&args("upgrades --format csv -s msstore"))
+            .unwrap()
+            .unwrap();
+        assert_eq!(inv.command, Command::Upgrades);
+        assert_eq!(inv.format, Format::Csv);
+        assert_eq!(inv.source.as_deref(), Some("msstore"));
+
+        let inv = parse_args(&args("list --json --source=winget"))
+            .unwrap()
+            .unwrap();
+        assert_eq!(inv.format, Format::Json);
+        assert_eq!(inv.source.as_deref(), Some("winget"));
+    }
+
+    #[test]
+    fn parse_args_joins_search_words() {
+        let inv = parse_args(&args("search tag:editor --json vim"))
+            .unwrap()
+            .unwrap();
+        assert_eq!(inv.command, Command::Search("tag:editor vim".to_string()));
+    }
+
+    #[test]
+    fn parse_args_rejects_bad_input() {
+        assert!(parse_args(&args("frobnicate")).is_err());
+        assert!(parse_args(&args("list extra")).is_err());
+        assert!(parse_args(&args("search")).is_err());
+        assert!(parse_args(&args("show a b")).is_err());
+        assert!(parse_args(&args("list --format yaml")).is_err());
+        assert!(parse_args(&args("list --source")).is_err());
+        assert!(parse_args(&args("list --verbose")).is_err());
+    }
+
+    #[tokio::test]
+    async fn check_exits_with_upgrades_available_code() {
+        let (code, _) = run_to_string("check", &backend_with_upgrade()).await;
+        assert_eq!(code, UPGRADES_AVAILABLE_EXIT);
+
+        let empty = FixedBackend {
+            upgrades: vec![],
+            pins: vec![],
+        };
+        let (code, out) = run_to_string("check --json", &empty).await;
+        assert_eq!(code, 0);
+        assert_eq!(out.trim(), "[]");
+    }
+
+    #[tokio::test]
+    async fn upgrades_table_is_aligned_with_pin_column() {
+        let (code, out) = run_to_string("upgrades", &backend_with_upgrade()).await;
+        assert_eq!(code, 0);
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(lines[0], "Name Id      Version Source AvailableVersion Pin");
+        assert!(lines[1].chars().all(|c| c == '-'));
+        assert_eq!(lines[1].len(), lines[2].len());
+        assert_eq!(
+            lines[2],
+            "Git  Git.Git 2.40.0  winget 2.45.1           Gating"
+        );
+    }
+
+    #[tokio::test]
+    async fn upgrades_json_includes_pin_and_range() {
+        let (_, out) = run_to_string("upgrades --json", &backend_with_upgrade()).await;
+        let value: serde_json::Value = serde_json::from_str(&out).unwrap();
+        let record = &value[0];
+        assert_eq!(record["id"], "Git.Git");
+        assert_eq!(record["available_version"], "2.45.1");
+        assert_eq!(record["pin"], "Gating");
+        assert_eq!(record["pin_range"], "2.*");
+        assert!(record.get("match").is_none());
+    }
+
+    #[tokio::test]
+    async fn search_csv_has_match_column() {
+        let (_, out) = run_to_string("search --csv json", &backend_with_upgrade()).await;
+        assert_eq!(
+            out,
+            "Name,Id,Version,Source,Match\nFound,Found.Pkg,1.0,winget,Tag: json\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn pins_filter_by_source() {
+        let (_, out) = run_to_string("pins --csv --source msstore", &backend_with_upgrade()).await;
+        assert_eq!(out, "Name,Id,Version,Source,Pin\n");
+    }
+
+    #[tokio::test]
+    async fn show_json_matches_the_list_format() {
+        let (_, out) = run_to_string("show Some.Id --json", &backend_with_upgrade()).await;
+        let value: serde_json::Value = serde_json::from_str(&out).unwrap();
+        assert_eq!(value["id"], "Some.Id");
+        assert_eq!(value["pin"], "Gating");
+        assert_eq!(value["pin_range"], "1.*");
+        assert!(value.get("pin_state").is_none());
+        assert!(value.get("raw").is_none());
+        assert_eq!(value["documentation"][0]["label"], "FAQ");
+        assert_eq!(value["documentation"][0]["url"], "https://example.com/faq");
+        assert_eq!(value["agreements"][0]["label"], "Pricing");
+        assert_eq!(value["agreements"][0]["text"], "Free");
+    }
+
+    #[tokio::test]
+    async fn show_table_indents_continuation_lines() {
+        let (_, out) = run_to_string("show Some.Id", &backend_with_upgrade()).await;
+        assert_eq!(
+            out,
+            "Id:          Some.Id\n\
+             Name:        Shown\n\
+             Description: First line\n             Second line\n\
+             Pin:         Gating\n\
+             FAQ:         https://example.com/faq\n\
+             Pricing:     Free\n"
+        );
+    }
+}
@@ -1,13 +1,19 @@
 mod app;
 mod backend;
+mod cache;
+mod cli;
 mod cli_backend;
 mod config;
+mod error;
 mod handler;
+mod headless;
 mod models;
+mod terminal_color;
 mod theme;
 mod ui;
 
-use std::io;
+use std::io::{self, Write};
+use std::path::Path;
 use std::sync::Arc;
 
 use anyhow::Result;
@@ -19,17 +25,79 @@ use crossterm::{
 use ratatui::{backend::CrosstermBackend, Terminal};
 
 use app::App;
+use cache::DiskCache;
 use cli_backend::CliBackend;
-use config::Config;
+use config::{Config, ConfigWarning};
+use theme::ThemeName;
 
 #[tokio::main]
 async fn main() -> Result<()> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let args = match cli::parse(&args) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Error: {e}\n\n{}", cli::USAGE);
+            std::process::exit(2);
+        }
+    };
+    if args
+        .command
+        .as_ref()
+        .is_some_and(|inv| inv.command == headless::Command::Help)
+    {
+        println!("{}", cli::USAGE);
+        return Ok(());
+    }
+    let config_path = args.config_path.clone().or_else(Config::path);
+    let (cfg, mut config_warnings) = match &args.config_path {
+        Some(path) => match Config::load_from(path) {
+            Ok(loaded) => loaded,
+            Err(e) => {
+                eprintln!("Error: cannot read {}: {e}", path.display());
+                std::process::exit(2);
+            }
+        },
+        None => Config::load(),
+    };
+    let mut cfg = match cfg.with_overrides(args.overrides, &mut config_warnings) {
+        Ok(cfg) => cfg,
+        Err(e) => {
+            eprintln!("Error: {e}");
+            std::process::exit(2);
+        }
+    };
+    if args.check_config {
+        std::process::exit(check_config(config_path.as_deref(), &config_warnings));
+    }
+
     // Verify winget is on PATH before touching the terminal.
     if let Err(e) = CliBackend::check_winget_available() {
         eprintln!("Error: {e}");
         std::process::exit(1);
     }
 
+    // Subcommands print their result and exit without entering raw mode.
+    if let Some(invocation) = args.command {
+        for warning in &config_warnings {
+            eprintln!("Warning: {warning}");
+        }
+        let backend = CliBackend::new().with_timeouts(cfg.timeouts);
+        let code = match headless::run(&invocation, &backend, &mut io::stdout().lock()).await {
+            Ok(code) => code,
+            Err(e) => {
+                eprintln!("Error: {e}");
+                1
+            }
+        };
+        io::stdout().flush()?;
+        std::process::exit(code);
+    }
+
+    // Ask the terminal for its background before the UI takes over its input
+    if cfg.theme == ThemeName::Auto {
+        cfg.theme = terminal_color::auto_theme();
+    }
+
     // Set panic hook to restore terminal
     let default_hook = std::panic::take_hook();
     std::panic::set_hook(Box::new(move |info| {
@@ -45,7 +113,7 @@ async fn main() -> Result<()> {
     let mut terminal = Terminal::new(backend)?;
 
     // Run app
-    let result = run_app(&mut terminal).await;
+    let result = run_app(&mut terminal, cfg, &config_warnings, args.query).await;
 
     // Restore terminal
     restore_terminal()?;
@@ -53,20 +121,54 @@ async fn main() -> Result<()> {
     result
 }
 
+/// Print the problems found in the config file for `--check-config` and
+/// return the exit code: 0 when the file is valid or absent, 1 otherwise.
+fn check_config(path: Option<&Path>, warnings: &[ConfigWarning]) -> i32 {
+    let Some(path) = path.filter(|p| p.exists()) else {
+        match path {
+            Some(path) => println!("No config file at {}; using defaults", path.display()),
+            None => println!("No config file; using defaults"),
+        }
+        return 0;
+    };
+    if warnings.is_empty() {
+        println!("{}: OK", path.display());
+        return 0;
+    }
+    println!("{}:", path.display());
+    for warning in warnings {
+        println!("  {warning}");
+    }
+    1
+}
+
 fn restore_terminal() -> Result<()> {
     disable_raw_mode()?;
     execute!(io::stdout(), LeaveAlternateScreen, DisableMouseCapture)?;
     Ok(())
 }
 
-async fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Result<()> {
-    let backend = Arc::new(CliBackend::new());
-    let cfg = Config::load();
-    let mut app = App::new(backend, cfg);
+async fn run_app(
+    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+    cfg: Config,
+    config_warnings: &[ConfigWarning],
+    query: Option<String>,
+) -> Result<()> {
+    let backend = Arc::new(CliBackend::new().with_timeouts(cfg.timeouts));
+    let mut app = App::new(backend, cfg).with_disk_cache(DiskCache::load());
+    app.report_config_warnings(config_warnings);
+
+    // `--query` opens the Search view with the query already submitted
+    if let Some(query) = query {
+        app.search_query = query;
+    }
 
-    // Initial load — show installed packages
+    // Initial load — show the last known list at once, then revalidate it
+    app.show_cached_list();
     app.loading = true;
     app.refresh_view();
+    // Sources drive the `f` filter cycle and validate `default_source`
+    app.load_sources();
 
     loop {
         // Process any pending messages from background tasks.
@@ -80,8 +182,15 @@ async fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Resul
 
         // Skip the render when nothing changed and no animation is in flight.
         // During active loads the spinner advances every tick, so we always
-        // redraw then to keep the animation smooth.
-        if had_message || had_event || app.loading || app.detail_loading {
+        // redraw then to keep the animation smooth. The same goes while winget
+        // processes run, so the status bar's process count stays current.
+        if had_message
+            || had_event
+            || app.loading
+            || app.detail_loading
+            || app.running_op.is_some()
+            || app.backend.process_depth().running() > 0
+        {
             terminal.draw(|f| ui::draw(f, &mut app))?;
         }
 
@@ -89,6 +198,8 @@ async fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Resul
             break;
         }
     }
+    // The disk cache saves itself when `app` is dropped, so an error or a
+    // panic above does not lose it either.
 
     Ok(())
 }
@@ -1,6 +1,8 @@
 use std::fmt;
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+
+use crate::error::WingetErrorKind;
 
 /// Column to sort the package list by.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -11,6 +13,8 @@ pub enum SortField {
     Name,
     Id,
     Version,
+    /// Search relevance: exact ID and name matches first, tag matches last.
+    Relevance,
 }
 
 impl SortField {
@@ -24,6 +28,7 @@ impl fmt::Display for SortField {
             Self::Name => write!(f, "Name"),
             Self::Id => write!(f, "ID"),
             Self::Version => write!(f, "Version"),
+            Self::Relevance => write!(f, "Match"),
         }
     }
 }
@@ -45,7 +50,7 @@ impl SortDir {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum PinState {
     #[default]
     None,
@@ -75,6 +80,16 @@ impl PinState {
             Self::Gating(version) => format!("Pinned to {version}"),
         }
     }
+
+    /// The pin type as `winget pin list` names it.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::None => "",
+            Self::Pinned => "Pinning",
+            Self::Blocking => "Blocking",
+            Self::Gating(_) => "Gating",
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -113,29 +128,49 @@ impl fmt::Display for PinFilter {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub enum SourceFilter {
+    #[default]
     All,
-    Winget,
-    MsStore,
+    /// Only packages from the named source
+    Named(String),
 }
 
 impl SourceFilter {
-    pub fn cycle(&self) -> Self {
-        match self {
-            Self::All => Self::Winget,
-            Self::Winget => Self::MsStore,
-            Self::MsStore => Self::All,
+    /// Sources to cycle through before `winget source list` has answered.
+    pub const DEFAULT_SOURCES: [&'static str; 2] = ["winget", "msstore"];
+
+    /// Filter by `name`, where an empty name or "all" means every source.
+    pub fn named(name: &str) -> Self {
+        let name = name.trim();
+        if name.is_empty() || name.eq_ignore_ascii_case("all") {
+            Self::All
+        } else {
+            Self::Named(name.to_string())
         }
     }
 
+    /// The next filter in All → each of `sources` → All. A filter naming a
+    /// source that is no longer configured goes back to All.
+    pub fn cycle(&self, sources: &[String]) -> Self {
+        let next = match self {
+            Self::All => 0,
+            Self::Named(name) => match sources.iter().position(|s| s.eq_ignore_ascii_case(name)) {
+                Some(i) => i + 1,
+                None => return Self::All,
+            },
+        };
+        sources
+            .get(next)
+            .map_or(Self::All, |name| Self::Named(name.clone()))
+    }
+
     /// Returns the winget `--source` argument value for this filter,
     /// or `None` when all sources should be included.
-    pub fn as_arg(&self) -> Option<&'static str> {
+    pub fn as_arg(&self) -> Option<&str> {
         match self {
             Self::All => None,
-            Self::Winget => Some("winget"),
-            Self::MsStore => Some("msstore"),
+            Self::Named(name) => Some(name),
         }
     }
 }
@@ -144,13 +179,12 @@ impl fmt::Display for SourceFilter {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::All => write!(f, "All"),
-            Self::Winget => write!(f, "winget"),
-            Self::MsStore => write!(f, "msstore"),
+            Self::Named(name) => write!(f, "{name}"),
         }
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Package {
     #[serde(alias = "PackageIdentifier", alias = "Id")]
     pub id: String,
@@ -163,7 +197,11 @@ pub struct Package {
     /// Only present in upgrade listings
     #[serde(alias = "AvailableVersion", default)]
     pub available_version: String,
+    /// Why a search result matched, e.g. "Tag: json"; empty when the name or
+    /// ID matched, and outside search results
     #[serde(skip, default)]
+    pub match_reason: String,
+    #[serde(default)]
     pub pin_state: PinState,
 }
 
@@ -177,9 +215,54 @@ impl Package {
     pub fn is_truncated(&self) -> bool {
         self.id.ends_with('…') || self.id.ends_with("...")
     }
+
+    /// How well this search result matches `request`; lower ranks first.
+    ///
+    /// An exact ID beats an exact name, which beats a plain name or ID match
+    /// (no "Match" reason), then moniker, command and tag matches.
+    pub fn match_rank(&self, request: &SearchRequest) -> u8 {
+        let terms = [
+            Some(request.query.as_str()),
+            request.id.as_deref(),
+            request.name.as_deref(),
+            request.moniker.as_deref(),
+        ];
+        let exact = |value: &str| {
+            terms
+                .iter()
+                .flatten()
+                .any(|term| !term.is_empty() && term.eq_ignore_ascii_case(value))
+        };
+        if exact(&self.id) {
+            return 0;
+        }
+        if exact(&self.name) {
+            return 1;
+        }
+        let kind = self
+            .match_reason
+            .split_once(':')
+            .map_or("", |(kind, _)| kind.trim())
+            .to_ascii_lowercase();
+        match kind.as_str() {
+            _ if self.match_reason.is_empty() => 2,
+            "moniker" => 3,
+            "command" => 4,
+            "tag" => 5,
+            _ => 6,
+        }
+    }
 }
 
-#[derive(Debug, Clone, Default)]
+/// One kind of dependency listed by `winget show`, e.g. "Package Dependencies"
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DependencyGroup {
+    pub kind: String,
+    pub items: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct PackageDetail {
     pub id: String,
     pub name: String,
@@ -190,7 +273,27 @@ pub struct PackageDetail {
     pub license: String,
     pub source: String,
     pub release_notes_url: String,
+    /// Release notes as printed by winget, with their indentation, paragraphs and
+    /// bullet lists preserved
+    pub release_notes: String,
+    pub author: String,
+    pub moniker: String,
+    pub tags: Vec<String>,
+    pub copyright: String,
+    pub privacy_url: String,
+    pub support_url: String,
+    /// Labelled documentation links, e.g. ("FAQ", "https://...")
+    pub documentation: Vec<(String, String)>,
+    pub release_date: String,
+    pub installer_type: String,
+    pub installer_url: String,
+    pub installer_sha256: String,
+    pub dependencies: Vec<DependencyGroup>,
+    /// Agreement label and text or URL, e.g. ("Pricing", "Free")
+    pub agreements: Vec<(String, String)>,
     pub pin_state: PinState,
+    /// The unparsed `winget show` text, for the Raw detail tab
+    pub raw: String,
 }
 
 impl PackageDetail {
@@ -199,7 +302,8 @@ impl PackageDetail {
     ///
     /// This pattern is used when `winget show` completes: the stub from the package list
     /// provides instant `id`, `name`, `version`, and `source` before the async call returns,
-    /// while the full response fills in `publisher`, `description`, `homepage`, and `license`.
+    /// while the full response fills in `publisher`, `description`, `homepage`, `license`
+    /// and the rest of the manifest metadata.
     /// If winget returns empty values for any field, the stub's values are preserved.
     pub fn merge_over(self, base: &PackageDetail) -> PackageDetail {
         let pick = |fresh: String, fallback: &String| -> String {
@@ -209,6 +313,13 @@ impl PackageDetail {
                 fresh
             }
         };
+        fn pick_list<T: Clone>(fresh: Vec<T>, fallback: &[T]) -> Vec<T> {
+            if fresh.is_empty() {
+                fallback.to_vec()
+            } else {
+                fresh
+            }
+        }
         PackageDetail {
             id: pick(self.id, &base.id),
             name: pick(self.name, &base.name),
@@ -219,11 +330,26 @@ impl PackageDetail {
             homepage: pick(self.homepage, &base.homepage),
             license: pick(self.license, &base.license),
             release_notes_url: pick(self.release_notes_url, &base.release_notes_url),
+            release_notes: pick(self.release_notes, &base.release_notes),
+            author: pick(self.author, &base.author),
+            moniker: pick(self.moniker, &base.moniker),
+            tags: pick_list(self.tags, &base.tags),
+            copyright: pick(self.copyright, &base.copyright),
+            privacy_url: pick(self.privacy_url, &base.privacy_url),
+            support_url: pick(self.support_url, &base.support_url),
+            documentation: pick_list(self.documentation, &base.documentation),
+            release_date: pick(self.release_date, &base.release_date),
+            installer_type: pick(self.installer_type, &base.installer_type),
+            installer_url: pick(self.installer_url, &base.installer_url),
+            installer_sha256: pick(self.installer_sha256, &base.installer_sha256),
+            dependencies: pick_list(self.dependencies, &base.dependencies),
+            agreements: pick_list(self.agreements, &base.agreements),
             pin_state: if self.pin_state.is_pinned() {
                 self.pin_state
             } else {
                 base.pin_state.clone()
             },
+            raw: pick(self.raw, &base.raw),
         }
     }
 }
@@ -231,11 +357,39 @@ impl PackageDetail {
 #[derive(Debug, Clone, Default)]
 pub struct PackagePin {
     pub id: String,
+    pub name: String,
+    /// The version column of `winget pin list`, e.g. the gated range
+    pub pinned_version: String,
+    pub source: String,
     pub pin_state: PinState,
 }
 
-#[derive(Debug, Clone, Deserialize)]
-#[allow(dead_code)]
+impl PackagePin {
+    /// A list row for the Pins view. The version column holds the pinned
+    /// version, left blank when winget only reports "Latest".
+    pub fn into_package(self) -> Package {
+        let version = if self.pinned_version.eq_ignore_ascii_case("latest") {
+            String::new()
+        } else {
+            self.pinned_version
+        };
+        Package {
+            name: if self.name.is_empty() {
+                self.id.clone()
+            } else {
+                self.name
+            },
+            id: self.id,
+            version,
+            source: self.source,
+            available_version: String::new(),
+            match_reason: String::new(),
+            pin_state: self.pin_state,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
 pub struct Source {
     #[serde(alias = "Name")]
     pub name: String,
@@ -245,41 +399,427 @@ pub struct Source {
     pub source_type: String,
 }
 
+/// Source types offered by `winget source add --type`.
+pub const SOURCE_TYPES: &[&str] = &["Microsoft.Rest", "Microsoft.PreIndexed.Package"];
+
+/// Outcome of `winget source update` for one source.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SourceUpdate {
+    Updating,
+    Updated,
+    Failed(String),
+}
+
+/// Installer scope passed to `winget install --scope`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum InstallScope {
+    /// Let winget and the installer decide.
+    #[default]
+    Any,
+    User,
+    Machine,
+}
+
+impl InstallScope {
+    pub fn cycle(&self) -> Self {
+        match self {
+            Self::Any => Self::User,
+            Self::User => Self::Machine,
+            Self::Machine => Self::Any,
+        }
+    }
+
+    pub fn as_arg(&self) -> Option<&'static str> {
+        match self {
+            Self::Any => None,
+            Self::User => Some("user"),
+            Self::Machine => Some("machine"),
+        }
+    }
+}
+
+/// Whether the installer shows its own UI.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum InstallMode {
+    /// winget's default, which is usually silent.
+    #[default]
+    Default,
+    Interactive,
+    Silent,
+}
+
+impl InstallMode {
+    pub fn cycle(&self) -> Self {
+        match self {
+            Self::Default => Self::Interactive,
+            Self::Interactive => Self::Silent,
+            Self::Silent => Self::Default,
+        }
+    }
+
+    pub fn as_flag(&self) -> Option<&'static str> {
+        match self {
+            Self::Default => None,
+            Self::Interactive => Some("--interactive"),
+            Self::Silent => Some("--silent"),
+        }
+    }
+}
+
+/// Architectures offered by the install options dialog.
+pub const INSTALL_ARCHITECTURES: &[&str] = &["x64", "x86", "arm64", "arm"];
+
+/// Installer types offered by the install options dialog.
+pub const INSTALLER_TYPES: &[&str] = &[
+    "msix", "msi", "appx", "exe", "zip", "inno", "nullsoft", "wix", "burn", "portable",
+];
+
+/// Extra `winget install` settings chosen in the install options dialog.
+/// `None` and the default variants leave the choice to winget.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct InstallOptions {
+    pub scope: InstallScope,
+    pub architecture: Option<String>,
+    pub installer_type: Option<String>,
+    pub locale: Option<String>,
+    pub location: Option<String>,
+    pub mode: InstallMode,
+    /// Replaces the installer's own arguments (`--override`)
+    pub override_args: Option<String>,
+}
+
+impl InstallOptions {
+    /// The winget flags for these options, in a stable order.
+    pub fn args(&self) -> Vec<&str> {
+        let mut args = Vec::new();
+        if let Some(scope) = self.scope.as_arg() {
+            args.extend(["--scope", scope]);
+        }
+        let valued = [
+            ("--architecture", &self.architecture),
+            ("--installer-type", &self.installer_type),
+            ("--locale", &self.locale),
+            ("--location", &self.location),
+        ];
+        for (flag, value) in valued {
+            if let Some(value) = value {
+                args.extend([flag, value.as_str()]);
+            }
+        }
+        if let Some(flag) = self.mode.as_flag() {
+            args.push(flag);
+        }
+        if let Some(value) = &self.override_args {
+            args.extend(["--override", value.as_str()]);
+        }
+        args
+    }
+
+    /// The flags as a user would type them, quoting values with spaces.
+    pub fn describe(&self) -> String {
+        self.args()
+            .iter()
+            .map(|arg| {
+                if arg.contains(' ') {
+                    format!("\"{arg}\"")
+                } else {
+                    arg.to_string()
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+/// Extra `winget uninstall` settings chosen in the uninstall options dialog.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct UninstallOptions {
+    /// Remove only this installed version; ignored with `all_versions`
+    pub version: Option<String>,
+    pub all_versions: bool,
+    /// Delete all files and settings too (portable packages)
+    pub purge: bool,
+    pub force: bool,
+}
+
+impl UninstallOptions {
+    /// The winget flags for these options, in a stable order.
+    pub fn args(&self) -> Vec<&str> {
+        let mut args = Vec::new();
+        if self.all_versions {
+            args.push("--all-versions");
+        } else if let Some(version) = &self.version {
+            args.extend(["--version", version.as_str()]);
+        }
+        if self.purge {
+            args.push("--purge");
+        }
+        if self.force {
+            args.push("--force");
+        }
+        args
+    }
+
+    /// What uninstalling `id` with these options removes, for confirmation.
+    pub fn describe_removal(&self, id: &str) -> String {
+        let mut text = if self.all_versions {
+            format!("every installed version of {id}")
+        } else if let Some(version) = &self.version {
+            format!("{id} v{version} only")
+        } else {
+            id.to_string()
+        };
+        if self.purge {
+            text.push_str(", including all its files and settings");
+        }
+        if self.force {
+            text.push_str(", forcing removal");
+        }
+        text
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum Operation {
-    Install { id: String, version: Option<String> },
-    Uninstall { id: String },
-    Upgrade { id: String },
-    Pin { id: String },
-    Unpin { id: String },
-    BatchUpgrade { ids: Vec<String> },
+    Install {
+        id: String,
+        version: Option<String>,
+        options: InstallOptions,
+    },
+    Uninstall {
+        id: String,
+        options: UninstallOptions,
+    },
+    Upgrade {
+        id: String,
+    },
+    /// Add a pin of the given kind, replacing any existing pin
+    Pin {
+        id: String,
+        pin: PinState,
+    },
+    Unpin {
+        id: String,
+    },
+    /// Remove every configured pin
+    ResetPins,
+    AddSource {
+        source: Source,
+    },
+    RemoveSource {
+        name: String,
+    },
+    /// Restore the default sources, removing any added ones
+    ResetSources,
+    BatchUpgrade {
+        ids: Vec<String>,
+    },
 }
 
 impl fmt::Display for Operation {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Self::Install { id, version } => {
+            Self::Install { id, version, .. } => {
                 if let Some(v) = version {
                     write!(f, "Installing {id} v{v}")
                 } else {
                     write!(f, "Installing {id}")
                 }
             }
-            Self::Uninstall { id } => write!(f, "Uninstalling {id}"),
+            Self::Uninstall { id, options } => {
+                if options.all_versions {
+                    write!(f, "Uninstalling all versions of {id}")
+                } else if let Some(v) = &options.version {
+                    write!(f, "Uninstalling {id} v{v}")
+                } else {
+                    write!(f, "Uninstalling {id}")
+                }
+            }
             Self::Upgrade { id } => write!(f, "Upgrading {id}"),
-            Self::Pin { id } => write!(f, "Pinning {id}"),
+            Self::Pin { id, pin } => match pin {
+                PinState::Blocking => write!(f, "Pinning {id} (blocking)"),
+                PinState::Gating(range) => write!(f, "Pinning {id} to {range}"),
+                PinState::Pinned | PinState::None => write!(f, "Pinning {id}"),
+            },
             Self::Unpin { id } => write!(f, "Unpinning {id}"),
+            Self::ResetPins => write!(f, "Resetting all pins"),
+            Self::AddSource { source } => write!(f, "Adding source {}", source.name),
+            Self::RemoveSource { name } => write!(f, "Removing source {name}"),
+            Self::ResetSources => write!(f, "Resetting sources"),
             Self::BatchUpgrade { ids } => write!(f, "Batch upgrading {} packages", ids.len()),
         }
     }
 }
 
+/// Live progress reported while a mutating winget command is running.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OperationProgress {
+    /// A finished line of winget output, with progress-bar redraws resolved.
+    Output(String),
+    /// winget moved on to a new phase, e.g. "Downloading" or "Installing".
+    Phase(String),
+    /// Completion of the current progress bar, 0–100.
+    Percent(u8),
+}
+
+/// How many winget processes a backend is running, and how many are queued
+/// behind its concurrency limit, split into read-only and mutating lanes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ProcessDepth {
+    pub reads_running: usize,
+    pub reads_waiting: usize,
+    pub writes_running: usize,
+    pub writes_waiting: usize,
+}
+
+impl ProcessDepth {
+    pub fn running(&self) -> usize {
+        self.reads_running + self.writes_running
+    }
+
+    pub fn waiting(&self) -> usize {
+        self.reads_waiting + self.writes_waiting
+    }
+}
+
 /// Result of a completed operation
 #[derive(Debug, Clone)]
 pub struct OpResult {
     pub operation: Operation,
     pub success: bool,
     pub message: String,
+    /// The user cancelled the operation before winget finished
+    pub cancelled: bool,
+    /// What went wrong, when winget reported a recognised failure
+    pub error_kind: Option<WingetErrorKind>,
+}
+
+/// A Search view query split into winget's search filters.
+///
+/// Bare words form the free-text query; `tag:`, `cmd:` (or `command:`),
+/// `id:`, `name:` and `moniker:` target one field each; any other `word:` is
+/// free text, so URLs and the like search as typed. Double quotes keep spaces
+/// together, and quoted free text asks for an exact match, e.g.
+/// `"Visual Studio Code"`. A quoted qualifier value such as
+/// `name:"Windows Terminal"` only keeps its spaces together.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SearchRequest {
+    /// Free text matched against id, name, moniker and tags (`--query`)
+    pub query: String,
+    pub id: Option<String>,
+    pub name: Option<String>,
+    pub moniker: Option<String>,
+    pub tag: Option<String>,
+    pub command: Option<String>,
+    /// Match case-sensitively and in full (`--exact`)
+    pub exact: bool,
+}
+
+/// Why a search query could not be parsed, and which bytes of it are at fault.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchSyntaxError {
+    pub message: String,
+    pub span: std::ops::Range<usize>,
+}
+
+impl fmt::Display for SearchSyntaxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl SearchRequest {
+    pub fn parse(input: &str) -> Result<Self, SearchSyntaxError> {
+        let mut request = Self::default();
+        let mut words: Vec<String> = Vec::new();
+        let mut rest = input.char_indices().peekable();
+
+        while let Some(&(start, ch)) = rest.peek() {
+            if ch.is_whitespace() {
+                rest.next();
+                continue;
+            }
+
+            // One token runs to the next whitespace outside quotes
+            let mut text = String::new();
+            let mut key_end = None;
+            let mut quoted = false;
+            let mut in_quote = false;
+            let mut end = input.len();
+            while let Some(&(i, c)) = rest.peek() {
+                if c.is_whitespace() && !in_quote {
+                    end = i;
+                    break;
+                }
+                rest.next();
+                match c {
+                    '"' => {
+                        in_quote = !in_quote;
+                        quoted = true;
+                    }
+                    ':' if !quoted && key_end.is_none() => {
+                        key_end = Some(text.len());
+                        text.push(c);
+                    }
+                    _ => text.push(c),
+                }
+            }
+            if in_quote {
+                return Err(SearchSyntaxError {
+                    message: "Unclosed quote".to_string(),
+                    span: start..input.len(),
+                });
+            }
+
+            let key = key_end.map(|k| &text[..k]);
+            let slot = match key.map(str::to_ascii_lowercase).as_deref() {
+                Some("tag") => &mut request.tag,
+                Some("cmd" | "command") => &mut request.command,
+                Some("id") => &mut request.id,
+                Some("name") => &mut request.name,
+                Some("moniker") => &mut request.moniker,
+                _ => {
+                    request.exact |= quoted;
+                    if !text.is_empty() {
+                        words.push(text);
+                    }
+                    continue;
+                }
+            };
+            let key = key.unwrap_or_default();
+            let value = text[key.len() + 1..].to_string();
+            if value.is_empty() {
+                return Err(SearchSyntaxError {
+                    message: format!("'{key}:' needs a value"),
+                    span: start..end,
+                });
+            }
+            if slot.is_some() {
+                return Err(SearchSyntaxError {
+                    message: format!("'{key}:' is given more than once"),
+                    span: start..end,
+                });
+            }
+            *slot = Some(value);
+        }
+
+        request.query = words.join(" ");
+        Ok(request)
+    }
+
+    /// Nothing to search for
+    pub fn is_empty(&self) -> bool {
+        self.query.is_empty()
+            && [
+                &self.id,
+                &self.name,
+                &self.moniker,
+                &self.tag,
+                &self.command,
+            ]
+            .iter()
+            .all(|field| field.is_none())
+    }
 }
 
 #[cfg(test)]
@@ -293,39 +833,82 @@ mod tests {
             version: "1.0".to_string(),
             source: "winget".to_string(),
             available_version: String::new(),
+            match_reason: String::new(),
             pin_state: PinState::None,
         }
     }
 
     // ── SourceFilter ──────────────────────────────────────────────────────────
 
+    fn names(names: &[&str]) -> Vec<String> {
+        names.iter().map(|n| n.to_string()).collect()
+    }
+
     #[test]
     fn source_filter_cycle() {
-        assert_eq!(SourceFilter::All.cycle(), SourceFilter::Winget);
-        assert_eq!(SourceFilter::Winget.cycle(), SourceFilter::MsStore);
-        assert_eq!(SourceFilter::MsStore.cycle(), SourceFilter::All);
+        let sources = names(&SourceFilter::DEFAULT_SOURCES);
+        let winget = SourceFilter::named("winget");
+        let msstore = SourceFilter::named("msstore");
+        assert_eq!(SourceFilter::All.cycle(&sources), winget);
+        assert_eq!(winget.cycle(&sources), msstore);
+        assert_eq!(msstore.cycle(&sources), SourceFilter::All);
     }
 
     #[test]
-    fn source_filter_as_arg_all_returns_none() {
-        assert_eq!(SourceFilter::All.as_arg(), None);
+    fn source_filter_cycle_includes_custom_sources() {
+        let sources = names(&["winget", "contoso"]);
+        let winget = SourceFilter::named("winget");
+        assert_eq!(winget.cycle(&sources), SourceFilter::named("contoso"));
+        assert_eq!(
+            SourceFilter::named("contoso").cycle(&sources),
+            SourceFilter::All
+        );
     }
 
     #[test]
-    fn source_filter_as_arg_winget_returns_some() {
-        assert_eq!(SourceFilter::Winget.as_arg(), Some("winget"));
+    fn source_filter_cycle_ignores_case() {
+        let sources = names(&["winget", "contoso"]);
+        assert_eq!(
+            SourceFilter::named("WinGet").cycle(&sources),
+            SourceFilter::named("contoso")
+        );
     }
 
     #[test]
-    fn source_filter_as_arg_msstore_returns_some() {
-        assert_eq!(SourceFilter::MsStore.as_arg(), Some("msstore"));
+    fn source_filter_cycle_from_unknown_source_returns_to_all() {
+        let sources = names(&["winget"]);
+        assert_eq!(
+            SourceFilter::named("removed").cycle(&sources),
+            SourceFilter::All
+        );
+        assert_eq!(SourceFilter::All.cycle(&[]), SourceFilter::All);
+    }
+
+    #[test]
+    fn source_filter_named_treats_blank_and_all_as_all() {
+        assert_eq!(SourceFilter::named(""), SourceFilter::All);
+        assert_eq!(SourceFilter::named("All"), SourceFilter::All);
+        assert_eq!(
+            SourceFilter::named(" contoso "),
+            SourceFilter::Named("contoso".to_string())
+        );
+    }
+
+    #[test]
+    fn source_filter_as_arg_all_returns_none() {
+        assert_eq!(SourceFilter::All.as_arg(), None);
+    }
+
+    #[test]
+    fn source_filter_as_arg_named_returns_some() {
+        assert_eq!(SourceFilter::named("winget").as_arg(), Some("winget"));
+        assert_eq!(SourceFilter::named("contoso").as_arg(), Some("contoso"));
     }
 
     #[test]
     fn source_filter_display() {
         assert_eq!(SourceFilter::All.to_string(), "All");
-        assert_eq!(SourceFilter::Winget.to_string(), "winget");
-        assert_eq!(SourceFilter::MsStore.to_string(), "msstore");
+        assert_eq!(SourceFilter::named("msstore").to_string(), "msstore");
     }
 
     #[test]
@@ -344,6 +927,8 @@ mod tests {
             PinState::Gating("1.2.*".to_string()).label(),
             "Pinned to 1.2.*"
         );
+        assert_eq!(PinState::Pinned.kind(), "Pinning");
+        assert_eq!(PinState::Gating("1.2.*".to_string()).kind(), "Gating");
     }
 
     #[test]
@@ -408,6 +993,52 @@ mod tests {
         assert!(!pkg("Microsoft.DotNet.DesktopRuntime.10").is_truncated());
     }
 
+    // ── Package::match_rank ───────────────────────────────────────────────────
+
+    fn matched(id: &str, name: &str, reason: &str) -> Package {
+        Package {
+            name: name.to_string(),
+            match_reason: reason.to_string(),
+            ..pkg(id)
+        }
+    }
+
+    #[test]
+    fn match_rank_puts_exact_id_and_name_ahead_of_tags() {
+        let request = SearchRequest::parse("json").unwrap();
+        let by_id = matched("JSON", "Some Tool", "");
+        let by_name = matched("Example.Json", "Json", "");
+        let by_substring = matched("Example.JsonViewer", "JSON Viewer", "");
+        let by_moniker = matched("Example.Jq", "jq", "Moniker: json");
+        let by_command = matched("Example.Jc", "jc", "Command: json");
+        let by_tag = matched("Example.Fx", "fx", "Tag: json");
+        let ranks: Vec<u8> = [by_id, by_name, by_substring, by_moniker, by_command, by_tag]
+            .iter()
+            .map(|p| p.match_rank(&request))
+            .collect();
+        assert_eq!(ranks, vec![0, 1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn match_rank_uses_qualified_terms() {
+        let request = SearchRequest::parse("id:Git.Git").unwrap();
+        assert_eq!(matched("Git.Git", "Git", "").match_rank(&request), 0);
+        let request = SearchRequest::parse(r#"name:"Visual Studio Code""#).unwrap();
+        assert_eq!(
+            matched("Microsoft.VisualStudioCode", "Visual Studio Code", "").match_rank(&request),
+            1
+        );
+    }
+
+    #[test]
+    fn match_rank_sorts_unknown_reasons_last() {
+        let request = SearchRequest::parse("foo").unwrap();
+        assert_eq!(
+            matched("A.B", "B", "ProductCode: foo").match_rank(&request),
+            6
+        );
+    }
+
     // ── Operation::Display ────────────────────────────────────────────────────
 
     #[test]
@@ -415,6 +1046,7 @@ mod tests {
         let op = Operation::Install {
             id: "Google.Chrome".to_string(),
             version: Some("132.0".to_string()),
+            options: InstallOptions::default(),
         };
         assert_eq!(op.to_string(), "Installing Google.Chrome v132.0");
     }
@@ -424,6 +1056,7 @@ mod tests {
         let op = Operation::Install {
             id: "Google.Chrome".to_string(),
             version: None,
+            options: InstallOptions::default(),
         };
         assert_eq!(op.to_string(), "Installing Google.Chrome");
     }
@@ -432,10 +1065,27 @@ mod tests {
     fn operation_display_uninstall() {
         let op = Operation::Uninstall {
             id: "Google.Chrome".to_string(),
+            options: UninstallOptions::default(),
         };
         assert_eq!(op.to_string(), "Uninstalling Google.Chrome");
     }
 
+    #[test]
+    fn operation_display_uninstall_all_versions() {
+        let op = Operation::Uninstall {
+            id: "Python.Python.3".to_string(),
+            options: UninstallOptions {
+                version: Some("3.11".to_string()),
+                all_versions: true,
+                ..UninstallOptions::default()
+            },
+        };
+        assert_eq!(
+            op.to_string(),
+            "Uninstalling all versions of Python.Python.3"
+        );
+    }
+
     #[test]
     fn operation_display_upgrade() {
         let op = Operation::Upgrade {
@@ -444,6 +1094,71 @@ mod tests {
         assert_eq!(op.to_string(), "Upgrading Google.Chrome");
     }
 
+    #[test]
+    fn operation_display_pin_names_the_kind() {
+        let pin = |pin| Operation::Pin {
+            id: "Foo".to_string(),
+            pin,
+        };
+        assert_eq!(pin(PinState::Pinned).to_string(), "Pinning Foo");
+        assert_eq!(
+            pin(PinState::Blocking).to_string(),
+            "Pinning Foo (blocking)"
+        );
+        assert_eq!(
+            pin(PinState::Gating("1.2.*".to_string())).to_string(),
+            "Pinning Foo to 1.2.*"
+        );
+    }
+
+    #[test]
+    fn operation_display_reset_pins() {
+        assert_eq!(Operation::ResetPins.to_string(), "Resetting all pins");
+    }
+
+    #[test]
+    fn operation_display_sources() {
+        let add = Operation::AddSource {
+            source: Source {
+                name: "contoso".to_string(),
+                url: "https://pkgs.contoso.com/api".to_string(),
+                source_type: "Microsoft.Rest".to_string(),
+            },
+        };
+        assert_eq!(add.to_string(), "Adding source contoso");
+        let remove = Operation::RemoveSource {
+            name: "contoso".to_string(),
+        };
+        assert_eq!(remove.to_string(), "Removing source contoso");
+        assert_eq!(Operation::ResetSources.to_string(), "Resetting sources");
+    }
+
+    #[test]
+    fn package_pin_into_package_uses_pinned_version_and_id_fallback() {
+        let gated = PackagePin {
+            id: "Git.Git".to_string(),
+            name: "Git".to_string(),
+            pinned_version: "2.45.*".to_string(),
+            source: "winget".to_string(),
+            pin_state: PinState::Gating("2.45.*".to_string()),
+        }
+        .into_package();
+        assert_eq!(gated.name, "Git");
+        assert_eq!(gated.version, "2.45.*");
+        assert_eq!(gated.source, "winget");
+        assert!(gated.pin_state.is_pinned());
+
+        let latest = PackagePin {
+            id: "Foo".to_string(),
+            pinned_version: "Latest".to_string(),
+            pin_state: PinState::Pinned,
+            ..PackagePin::default()
+        }
+        .into_package();
+        assert_eq!(latest.name, "Foo");
+        assert!(latest.version.is_empty());
+    }
+
     #[test]
     fn operation_display_batch_upgrade() {
         let op = Operation::BatchUpgrade {
@@ -461,6 +1176,168 @@ mod tests {
         assert_eq!(op.to_string(), "Batch upgrading 0 packages");
     }
 
+    // ── InstallOptions ────────────────────────────────────────────────────────
+
+    #[test]
+    fn install_options_default_adds_no_flags() {
+        assert!(InstallOptions::default().args().is_empty());
+    }
+
+    #[test]
+    fn install_options_args_in_stable_order() {
+        let options = InstallOptions {
+            scope: InstallScope::Machine,
+            architecture: Some("x64".to_string()),
+            installer_type: Some("msi".to_string()),
+            locale: Some("en-US".to_string()),
+            location: Some("D:\\Tools".to_string()),
+            mode: InstallMode::Silent,
+            override_args: Some("/qn /norestart".to_string()),
+        };
+        assert_eq!(
+            options.args(),
+            vec![
+                "--scope",
+                "machine",
+                "--architecture",
+                "x64",
+                "--installer-type",
+                "msi",
+                "--locale",
+                "en-US",
+                "--location",
+                "D:\\Tools",
+                "--silent",
+                "--override",
+                "/qn /norestart",
+            ]
+        );
+    }
+
+    #[test]
+    fn install_options_describe_quotes_values_with_spaces() {
+        let options = InstallOptions {
+            mode: InstallMode::Interactive,
+            override_args: Some("/S /D=C:\\App".to_string()),
+            ..InstallOptions::default()
+        };
+        assert_eq!(
+            options.describe(),
+            "--interactive --override \"/S /D=C:\\App\""
+        );
+    }
+
+    #[test]
+    fn install_scope_and_mode_cycle() {
+        assert_eq!(InstallScope::Any.cycle(), InstallScope::User);
+        assert_eq!(InstallScope::Machine.cycle(), InstallScope::Any);
+        assert_eq!(InstallMode::Default.cycle(), InstallMode::Interactive);
+        assert_eq!(InstallMode::Silent.cycle(), InstallMode::Default);
+    }
+
+    // ── UninstallOptions ──────────────────────────────────────────────────────
+
+    #[test]
+    fn uninstall_options_all_versions_overrides_version() {
+        let options = UninstallOptions {
+            version: Some("1.0".to_string()),
+            all_versions: true,
+            purge: true,
+            force: true,
+        };
+        assert_eq!(options.args(), vec!["--all-versions", "--purge", "--force"]);
+    }
+
+    #[test]
+    fn uninstall_options_describe_removal_names_version_and_purge() {
+        let options = UninstallOptions {
+            version: Some("2.1.0".to_string()),
+            purge: true,
+            ..UninstallOptions::default()
+        };
+        assert_eq!(options.args(), vec!["--version", "2.1.0", "--purge"]);
+        assert_eq!(
+            options.describe_removal("Foo.Bar"),
+            "Foo.Bar v2.1.0 only, including all its files and settings"
+        );
+        assert_eq!(
+            UninstallOptions::default().describe_removal("Foo.Bar"),
+            "Foo.Bar"
+        );
+    }
+
+    // ── SearchRequest::parse ──────────────────────────────────────────────────
+
+    #[test]
+    fn search_request_plain_words_form_the_query() {
+        let request = SearchRequest::parse("  visual  studio ").unwrap();
+        assert_eq!(request.query, "visual studio");
+        assert!(!request.exact);
+    }
+
+    #[test]
+    fn search_request_parses_qualifiers() {
+        let request =
+            SearchRequest::parse("tag:editor cmd:rg id:Microsoft. Name:Foo moniker:vsc").unwrap();
+        assert_eq!(
+            request,
+            SearchRequest {
+                tag: Some("editor".to_string()),
+                command: Some("rg".to_string()),
+                id: Some("Microsoft.".to_string()),
+                name: Some("Foo".to_string()),
+                moniker: Some("vsc".to_string()),
+                ..SearchRequest::default()
+            }
+        );
+    }
+
+    #[test]
+    fn search_request_quotes_group_words_and_request_exact_match() {
+        let request = SearchRequest::parse("\"Visual Studio Code\"").unwrap();
+        assert_eq!(request.query, "Visual Studio Code");
+        assert!(request.exact);
+
+        let request = SearchRequest::parse("name:\"Windows Terminal\"").unwrap();
+        assert_eq!(request.name.as_deref(), Some("Windows Terminal"));
+        assert!(!request.exact, "quotes in a qualifier only group words");
+    }
+
+    #[test]
+    fn search_request_unknown_qualifiers_are_plain_text() {
+        let request = SearchRequest::parse("https://github.com/cli/cli foo:bar").unwrap();
+        assert_eq!(request.query, "https://github.com/cli/cli foo:bar");
+        assert_eq!(request, SearchRequest::parse(&request.query).unwrap());
+        assert!(request.tag.is_none() && request.id.is_none());
+    }
+
+    #[test]
+    fn search_request_colon_after_non_letters_is_plain_text() {
+        let request = SearchRequest::parse("7-zip:x64").unwrap();
+        assert_eq!(request.query, "7-zip:x64");
+    }
+
+    #[test]
+    fn search_request_errors_point_at_the_bad_token() {
+        let err = SearchRequest::parse("code \"visual").unwrap_err();
+        assert_eq!(err.message, "Unclosed quote");
+        assert_eq!(err.span, 5..12);
+
+        let err = SearchRequest::parse("tag: x").unwrap_err();
+        assert_eq!(err.message, "'tag:' needs a value");
+        assert_eq!(err.span, 0..4);
+
+        let err = SearchRequest::parse("tag:a tag:b").unwrap_err();
+        assert_eq!(err.message, "'tag:' is given more than once");
+        assert_eq!(err.span, 6..11);
+    }
+
+    #[test]
+    fn search_request_is_empty_only_without_query_or_filters() {
+        assert!(SearchRequest::parse("").unwrap().is_empty());
+        assert!(!SearchRequest::parse("tag:cli").unwrap().is_empty());
+    }
+
     // ── PackageDetail::merge_over ─────────────────────────────────────────────
 
     #[test]
@@ -476,6 +1353,7 @@ mod tests {
             source: "winget".to_string(),
             release_notes_url: String::new(),
             pin_state: PinState::None,
+            ..PackageDetail::default()
         };
         let base = PackageDetail {
             id: "OLD.ID".to_string(),
@@ -521,6 +1399,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn merge_over_keeps_base_lists_when_fresh_is_empty() {
+        let fresh = PackageDetail {
+            installer_type: "msix".to_string(),
+            ..PackageDetail::default()
+        };
+        let base = PackageDetail {
+            tags: vec!["browser".to_string()],
+            dependencies: vec![DependencyGroup {
+                kind: "Package Dependencies".to_string(),
+                items: vec!["Microsoft.VCLibs.Desktop.14".to_string()],
+            }],
+            ..PackageDetail::default()
+        };
+        let merged = fresh.merge_over(&base);
+        assert_eq!(merged.installer_type, "msix");
+        assert_eq!(merged.tags, vec!["browser"]);
+        assert_eq!(merged.dependencies.len(), 1);
+    }
+
     #[test]
     fn merge_over_release_notes_url_prefers_fresh() {
         let fresh = PackageDetail {
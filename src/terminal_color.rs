@@ -0,0 +1,216 @@
+//! Asks the terminal for its background color, so `theme = "auto"` can pick
+//! a light or dark preset.
+//!
+//! The OSC 11 query is followed by a primary device attributes (DA1) query,
+//! which practically every terminal answers. Terminals that ignore OSC 11
+//! therefore answer only DA1, and there is no need to wait out the timeout.
+
+use std::io::Write;
+use std::time::{Duration, Instant};
+
+use crate::theme::ThemeName;
+
+/// How long to wait for the terminal to answer. DA1 ends the wait as soon as
+/// the answer is in, so this only matters for slow links such as SSH.
+const QUERY_TIMEOUT: Duration = Duration::from_millis(1000);
+
+/// ESC ] 11 ; ? ST, then ESC [ c
+const QUERY: &[u8] = b"\x1b]11;?\x1b\\\x1b[c";
+
+/// The preset `theme = "auto"` stands for in this terminal; `Original` when
+/// the terminal does not report its background.
+pub fn auto_theme() -> ThemeName {
+    query_background().map_or(ThemeName::Original, ThemeName::for_background)
+}
+
+/// The terminal's background color, if it reports one.
+fn query_background() -> Option<(u8, u8, u8)> {
+    crossterm::terminal::enable_raw_mode().ok()?;
+    let reply = read_reply(QUERY_TIMEOUT);
+    let _ = crossterm::terminal::disable_raw_mode();
+    parse_background(&reply?)
+}
+
+/// Whether `reply` holds the DA1 answer (ESC [ ? ... c), which comes last.
+fn is_complete(reply: &str) -> bool {
+    reply
+        .rfind("\x1b[?")
+        .is_some_and(|start| reply[start..].contains('c'))
+}
+
+#[cfg(unix)]
+fn read_reply(timeout: Duration) -> Option<String> {
+    use std::fs::OpenOptions;
+    use std::io::Read;
+    use std::os::fd::AsRawFd;
+
+    let mut tty = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .open("/dev/tty")
+        .ok()?;
+    tty.write_all(QUERY).ok()?;
+    tty.flush().ok()?;
+
+    let deadline = Instant::now() + timeout;
+    let mut reply = Vec::new();
+    while !is_complete(&String::from_utf8_lossy(&reply)) {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        let mut fds = libc::pollfd {
+            fd: tty.as_raw_fd(),
+            events: libc::POLLIN,
+            revents: 0,
+        };
+        // SAFETY: `fds` is a single valid pollfd for the duration of the call
+        let ready = unsafe { libc::poll(&mut fds, 1, remaining.as_millis() as libc::c_int) };
+        if ready <= 0 {
+            break;
+        }
+        let mut buf = [0u8; 64];
+        match tty.read(&mut buf) {
+            Ok(0) | Err(_) => break,
+            Ok(n) => reply.extend_from_slice(&buf[..n]),
+        }
+    }
+    // Whatever part of a late reply has arrived would otherwise reach the
+    // TUI as keypresses
+    if !is_complete(&String::from_utf8_lossy(&reply)) {
+        drain(&mut tty);
+    }
+    Some(String::from_utf8_lossy(&reply).into_owned())
+}
+
+/// Read and discard input until none is pending.
+#[cfg(unix)]
+fn drain(tty: &mut std::fs::File) {
+    use std::io::Read;
+    use std::os::fd::AsRawFd;
+
+    loop {
+        let mut fds = libc::pollfd {
+            fd: tty.as_raw_fd(),
+            events: libc::POLLIN,
+            revents: 0,
+        };
+        // SAFETY: `fds` is a single valid pollfd for the duration of the call
+        if unsafe { libc::poll(&mut fds, 1, 0) } <= 0 {
+            return;
+        }
+        let mut buf = [0u8; 64];
+        if !matches!(tty.read(&mut buf), Ok(n) if n > 0) {
+            return;
+        }
+    }
+}
+
+/// The console delivers the terminal's reply as key presses, one character
+/// at a time.
+#[cfg(windows)]
+fn read_reply(timeout: Duration) -> Option<String> {
+    use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+
+    let mut stdout = std::io::stdout();
+    stdout.write_all(QUERY).ok()?;
+    stdout.flush().ok()?;
+
+    let deadline = Instant::now() + timeout;
+    let mut reply = String::new();
+    while !is_complete(&reply) {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if !event::poll(remaining).unwrap_or(false) {
+            break;
+        }
+        match event::read() {
+            Ok(Event::Key(key)) if key.kind == KeyEventKind::Press => match key.code {
+                KeyCode::Char(c) => reply.push(c),
+                KeyCode::Esc => reply.push('\x1b'),
+                _ => {}
+            },
+            Ok(_) => {}
+            Err(_) => break,
+        }
+    }
+    // Whatever part of a late reply has arrived would otherwise reach the
+    // TUI as keypresses
+    if !is_complete(&reply) {
+        while event::poll(Duration::ZERO).unwrap_or(false) {
+            if event::read().is_err() {
+                break;
+            }
+        }
+    }
+    Some(reply)
+}
+
+/// Extract the color from an OSC 11 reply such as
+/// `ESC ] 11 ; rgb:1e1e/1e1e/1e1e ESC \`. Each channel has 1 to 4 hex
+/// digits.
+fn parse_background(reply: &str) -> Option<(u8, u8, u8)> {
+    let start = reply.find("]11;")? + "]11;".len();
+    let body = &reply[start..];
+    let end = body.find(['\x1b', '\x07']).unwrap_or(body.len());
+    let spec = &body[..end];
+    let channels = spec
+        .strip_prefix("rgb:")
+        .or_else(|| spec.strip_prefix("rgba:"))?;
+    let mut channels = channels.split('/').map(|hex| {
+        if hex.is_empty() || hex.len() > 4 {
+            return None;
+        }
+        let value = u32::from_str_radix(hex, 16).ok()?;
+        let max = (1u32 << (4 * hex.len())) - 1;
+        Some((value * 255 / max) as u8)
+    });
+    let red = channels.next()??;
+    let green = channels.next()??;
+    let blue = channels.next()??;
+    Some((red, green, blue))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_four_digit_channels_with_st() {
+        let reply = "\x1b]11;rgb:fafa/f9f9/f8f8\x1b\\\x1b[?62;22c";
+        assert_eq!(parse_background(reply), Some((250, 249, 248)));
+        assert!(is_complete(reply));
+    }
+
+    #[test]
+    fn parses_short_channels_with_bel() {
+        assert_eq!(
+            parse_background("\x1b]11;rgb:1e/1e/1e\x07"),
+            Some((30, 30, 30))
+        );
+        assert_eq!(
+            parse_background("\x1b]11;rgb:f/0/8\x07"),
+            Some((255, 0, 136))
+        );
+    }
+
+    #[test]
+    fn parses_rgba_ignoring_alpha() {
+        assert_eq!(
+            parse_background("\x1b]11;rgba:0000/0000/0000/ffff\x1b\\"),
+            Some((0, 0, 0))
+        );
+    }
+
+    #[test]
+    fn only_device_attributes_means_no_background() {
+        let reply = "\x1b[?1;2c";
+        assert!(is_complete(reply));
+        assert_eq!(parse_background(reply), None);
+        assert!(!is_complete("\x1b]11;rgb:0/0/0\x1b\\"));
+    }
+
+    #[test]
+    fn malformed_replies_are_ignored() {
+        assert_eq!(parse_background("\x1b]11;#ffffff\x07"), None);
+        assert_eq!(parse_background("\x1b]11;rgb:ff/ff\x07"), None);
+        assert_eq!(parse_background("\x1b]11;rgb:fffff/0/0\x07"), None);
+        assert_eq!(parse_background(""), None);
+    }
+}
@@ -93,6 +93,52 @@ impl Theme {
         }
     }
 
+    pub const fn light() -> Self {
+        Self {
+            background: Color::Rgb(250, 250, 250),
+            surface: Color::Rgb(235, 235, 235),
+            text_primary: Color::Rgb(30, 30, 30),
+            text_secondary: Color::Rgb(89, 89, 89),
+            accent: Color::Rgb(138, 90, 0),
+            accent_dim: Color::Rgb(118, 118, 118),
+            success: Color::Rgb(26, 127, 55),
+            error: Color::Rgb(198, 40, 40),
+            info: Color::Rgb(10, 95, 180),
+            selection: Color::Rgb(142, 63, 168),
+            install: Color::Rgb(179, 38, 30),
+            danger: Color::Rgb(198, 40, 40),
+            on_accent: Color::Rgb(255, 255, 255),
+            on_success: Color::Rgb(255, 255, 255),
+            on_info: Color::Rgb(255, 255, 255),
+            on_selection: Color::Rgb(255, 255, 255),
+            on_install: Color::Rgb(255, 255, 255),
+            on_danger: Color::Rgb(255, 255, 255),
+        }
+    }
+
+    pub const fn high_contrast() -> Self {
+        Self {
+            background: Color::Rgb(0, 0, 0),
+            surface: Color::Rgb(26, 26, 26),
+            text_primary: Color::Rgb(255, 255, 255),
+            text_secondary: Color::Rgb(204, 204, 204),
+            accent: Color::Rgb(255, 215, 0),
+            accent_dim: Color::Rgb(160, 160, 160),
+            success: Color::Rgb(0, 255, 127),
+            error: Color::Rgb(255, 110, 110),
+            info: Color::Rgb(95, 215, 255),
+            selection: Color::Rgb(255, 135, 255),
+            install: Color::Rgb(255, 175, 0),
+            danger: Color::Rgb(255, 95, 95),
+            on_accent: Color::Rgb(0, 0, 0),
+            on_success: Color::Rgb(0, 0, 0),
+            on_info: Color::Rgb(0, 0, 0),
+            on_selection: Color::Rgb(0, 0, 0),
+            on_install: Color::Rgb(0, 0, 0),
+            on_danger: Color::Rgb(0, 0, 0),
+        }
+    }
+
     pub const fn terminal() -> Self {
         Self {
             background: Color::Reset,
@@ -116,11 +162,74 @@ impl Theme {
         }
     }
 
+    /// Field names as written in a `[theme]` table or theme file
+    pub const FIELDS: [&'static str; 18] = [
+        "background",
+        "surface",
+        "text_primary",
+        "text_secondary",
+        "accent",
+        "accent_dim",
+        "success",
+        "error",
+        "info",
+        "selection",
+        "install",
+        "danger",
+        "on_accent",
+        "on_success",
+        "on_info",
+        "on_selection",
+        "on_install",
+        "on_danger",
+    ];
+
+    /// Set the field named `field` (see [`Theme::FIELDS`]). Returns false
+    /// for an unknown name.
+    pub fn set_color(&mut self, field: &str, color: Color) -> bool {
+        let slot = match field {
+            "background" => &mut self.background,
+            "surface" => &mut self.surface,
+            "text_primary" => &mut self.text_primary,
+            "text_secondary" => &mut self.text_secondary,
+            "accent" => &mut self.accent,
+            "accent_dim" => &mut self.accent_dim,
+            "success" => &mut self.success,
+            "error" => &mut self.error,
+            "info" => &mut self.info,
+            "selection" => &mut self.selection,
+            "install" => &mut self.install,
+            "danger" => &mut self.danger,
+            "on_accent" => &mut self.on_accent,
+            "on_success" => &mut self.on_success,
+            "on_info" => &mut self.on_info,
+            "on_selection" => &mut self.on_selection,
+            "on_install" => &mut self.on_install,
+            "on_danger" => &mut self.on_danger,
+            _ => return false,
+        };
+        *slot = color;
+        true
+    }
+
+    /// `base` with the given fields replaced, as configured by a `[theme]`
+    /// table.
+    pub fn custom(base: ThemeName, colors: &[(String, Color)]) -> Self {
+        let mut theme = Self::from_name(base);
+        for (field, color) in colors {
+            theme.set_color(field, *color);
+        }
+        theme
+    }
+
     pub const fn from_name(name: ThemeName) -> Self {
         match name {
-            ThemeName::Original => Self::original(),
+            // `auto` is resolved at startup; this is its fallback
+            ThemeName::Original | ThemeName::Auto => Self::original(),
             ThemeName::Retro => Self::retro(),
             ThemeName::Nord => Self::nord(),
+            ThemeName::Light => Self::light(),
+            ThemeName::HighContrast => Self::high_contrast(),
             ThemeName::Terminal => Self::terminal(),
         }
     }
@@ -137,23 +246,216 @@ pub enum ThemeName {
     Original,
     Retro,
     Nord,
+    Light,
+    HighContrast,
     Terminal,
+    /// Light or original, whichever suits the terminal's background
+    Auto,
 }
 
 impl ThemeName {
-    pub fn parse(value: &str) -> Self {
-        if value.eq_ignore_ascii_case("retro") {
-            Self::Retro
+    /// The accepted preset names, for messages
+    pub const NAMES: &'static str = "original, retro, nord, light, high_contrast, terminal or auto";
+
+    pub fn from_name(value: &str) -> Option<Self> {
+        if value.eq_ignore_ascii_case("original") {
+            Some(Self::Original)
+        } else if value.eq_ignore_ascii_case("retro") {
+            Some(Self::Retro)
         } else if value.eq_ignore_ascii_case("nord") {
-            Self::Nord
+            Some(Self::Nord)
+        } else if value.eq_ignore_ascii_case("light") {
+            Some(Self::Light)
+        } else if value.eq_ignore_ascii_case("high_contrast")
+            || value.eq_ignore_ascii_case("high-contrast")
+        {
+            Some(Self::HighContrast)
         } else if value.eq_ignore_ascii_case("terminal") || value.eq_ignore_ascii_case("system") {
-            Self::Terminal
+            Some(Self::Terminal)
+        } else if value.eq_ignore_ascii_case("auto") {
+            Some(Self::Auto)
+        } else {
+            None
+        }
+    }
+
+    /// The preset for a terminal whose background is `background`: light on
+    /// a background that dark text contrasts with better than white text.
+    pub fn for_background((red, green, blue): (u8, u8, u8)) -> Self {
+        let background = Color::Rgb(red, green, blue);
+        let dark_text = contrast(Color::Rgb(0, 0, 0), background);
+        let white_text = contrast(Color::Rgb(255, 255, 255), background);
+        if dark_text > white_text {
+            Self::Light
         } else {
             Self::Original
         }
     }
 }
 
+/// WCAG minimum contrast for text
+pub const MIN_TEXT_CONTRAST: f64 = 4.5;
+/// WCAG minimum contrast for borders and other non-text elements
+pub const MIN_NON_TEXT_CONTRAST: f64 = 3.0;
+
+/// The RGB value of `color`, when it has a fixed one. The first 16 indexed
+/// colors and the named ones are set by the terminal's color scheme.
+fn rgb(color: Color) -> Option<(u8, u8, u8)> {
+    match color {
+        Color::Rgb(red, green, blue) => Some((red, green, blue)),
+        // xterm's 6×6×6 color cube
+        Color::Indexed(index @ 16..=231) => {
+            let level = |n: u8| if n == 0 { 0 } else { 55 + n * 40 };
+            let index = index - 16;
+            Some((level(index / 36), level(index / 6 % 6), level(index % 6)))
+        }
+        // and its grayscale ramp
+        Color::Indexed(index @ 232..) => {
+            let gray = 8 + (index - 232) * 10;
+            Some((gray, gray, gray))
+        }
+        _ => None,
+    }
+}
+
+fn relative_luminance(color: Color) -> Option<f64> {
+    let (red, green, blue) = rgb(color)?;
+    let channel = |value: u8| {
+        let value = f64::from(value) / 255.0;
+        if value <= 0.04045 {
+            value / 12.92
+        } else {
+            ((value + 0.055) / 1.055).powf(2.4)
+        }
+    };
+    Some(0.2126 * channel(red) + 0.7152 * channel(green) + 0.0722 * channel(blue))
+}
+
+/// WCAG contrast ratio between two colors, or `None` when either depends on
+/// the terminal's color scheme.
+pub fn contrast(foreground: Color, background: Color) -> Option<f64> {
+    let foreground = relative_luminance(foreground)?;
+    let background = relative_luminance(background)?;
+    let (lighter, darker) = if foreground > background {
+        (foreground, background)
+    } else {
+        (background, foreground)
+    };
+    Some((lighter + 0.05) / (darker + 0.05))
+}
+
+/// Every foreground/background pair the UI renders, with the contrast it
+/// needs to stay readable.
+pub fn contrast_pairs(theme: &Theme) -> [(&'static str, Color, Color, f64); 18] {
+    [
+        (
+            "root text",
+            theme.text_primary,
+            theme.background,
+            MIN_TEXT_CONTRAST,
+        ),
+        (
+            "root secondary text",
+            theme.text_secondary,
+            theme.background,
+            MIN_TEXT_CONTRAST,
+        ),
+        (
+            "surface text",
+            theme.text_primary,
+            theme.surface,
+            MIN_TEXT_CONTRAST,
+        ),
+        (
+            "surface secondary text",
+            theme.text_secondary,
+            theme.surface,
+            MIN_TEXT_CONTRAST,
+        ),
+        (
+            "accent text",
+            theme.accent,
+            theme.background,
+            MIN_TEXT_CONTRAST,
+        ),
+        (
+            "surface accent text",
+            theme.accent,
+            theme.surface,
+            MIN_TEXT_CONTRAST,
+        ),
+        (
+            "success text",
+            theme.success,
+            theme.background,
+            MIN_TEXT_CONTRAST,
+        ),
+        ("error text", theme.error, theme.surface, MIN_TEXT_CONTRAST),
+        ("info text", theme.info, theme.background, MIN_TEXT_CONTRAST),
+        (
+            "surface info text",
+            theme.info,
+            theme.surface,
+            MIN_TEXT_CONTRAST,
+        ),
+        (
+            "selected row",
+            theme.on_accent,
+            theme.accent,
+            MIN_TEXT_CONTRAST,
+        ),
+        (
+            "confirm action",
+            theme.on_success,
+            theme.success,
+            MIN_TEXT_CONTRAST,
+        ),
+        ("Winget badge", theme.on_info, theme.info, MIN_TEXT_CONTRAST),
+        (
+            "MsStore badge",
+            theme.on_selection,
+            theme.selection,
+            MIN_TEXT_CONTRAST,
+        ),
+        (
+            "install action",
+            theme.on_install,
+            theme.install,
+            MIN_TEXT_CONTRAST,
+        ),
+        (
+            "danger action",
+            theme.on_danger,
+            theme.danger,
+            MIN_TEXT_CONTRAST,
+        ),
+        (
+            "focused border",
+            theme.accent,
+            theme.background,
+            MIN_NON_TEXT_CONTRAST,
+        ),
+        (
+            "unfocused border",
+            theme.accent_dim,
+            theme.background,
+            MIN_NON_TEXT_CONTRAST,
+        ),
+    ]
+}
+
+/// Describe each pair in `theme` whose contrast is below its minimum.
+pub fn unreadable_pairs(theme: &Theme) -> Vec<String> {
+    contrast_pairs(theme)
+        .into_iter()
+        .filter_map(|(pair, foreground, background, minimum)| {
+            let ratio = contrast(foreground, background)?;
+            (ratio < minimum)
+                .then(|| format!("{pair} contrast {ratio:.2}:1 is below {minimum:.1}:1"))
+        })
+        .collect()
+}
+
 pub fn root(theme: &Theme) -> Style {
     Style::default().fg(theme.text_primary).bg(theme.background)
 }
@@ -298,6 +600,14 @@ pub fn source_msstore(theme: &Theme) -> Style {
         .add_modifier(Modifier::BOLD)
 }
 
+/// Badge for any source other than winget and msstore.
+pub fn source_custom(theme: &Theme) -> Style {
+    Style::default()
+        .fg(theme.on_success)
+        .bg(theme.success)
+        .add_modifier(Modifier::BOLD)
+}
+
 pub fn help_section(theme: &Theme) -> Style {
     Style::default()
         .fg(theme.accent)
@@ -355,40 +665,6 @@ pub fn logo_lines(theme: &Theme) -> Vec<Line<'static>> {
 mod tests {
     use super::*;
 
-    const MIN_TEXT_CONTRAST: f64 = 4.5;
-    const MIN_NON_TEXT_CONTRAST: f64 = 3.0;
-
-    fn rgb(color: Color) -> Option<(u8, u8, u8)> {
-        match color {
-            Color::Rgb(red, green, blue) => Some((red, green, blue)),
-            _ => None,
-        }
-    }
-
-    fn relative_luminance(color: Color) -> Option<f64> {
-        let (red, green, blue) = rgb(color)?;
-        let channel = |value: u8| {
-            let value = f64::from(value) / 255.0;
-            if value <= 0.04045 {
-                value / 12.92
-            } else {
-                ((value + 0.055) / 1.055).powf(2.4)
-            }
-        };
-        Some(0.2126 * channel(red) + 0.7152 * channel(green) + 0.0722 * channel(blue))
-    }
-
-    fn contrast(foreground: Color, background: Color) -> Option<f64> {
-        let foreground = relative_luminance(foreground)?;
-        let background = relative_luminance(background)?;
-        let (lighter, darker) = if foreground > background {
-            (foreground, background)
-        } else {
-            (background, foreground)
-        };
-        Some((lighter + 0.05) / (darker + 0.05))
-    }
-
     fn assert_contrast(
         theme_name: &str,
         pair_name: &str,
@@ -407,12 +683,18 @@ mod tests {
 
     #[test]
     fn theme_names_are_case_insensitive() {
-        assert_eq!(ThemeName::parse("ORIGINAL"), ThemeName::Original);
-        assert_eq!(ThemeName::parse("ReTrO"), ThemeName::Retro);
-        assert_eq!(ThemeName::parse("NORD"), ThemeName::Nord);
-        assert_eq!(ThemeName::parse("terminal"), ThemeName::Terminal);
-        assert_eq!(ThemeName::parse("SYSTEM"), ThemeName::Terminal);
-        assert_eq!(ThemeName::parse("unknown"), ThemeName::Original);
+        assert_eq!(ThemeName::from_name("ORIGINAL"), Some(ThemeName::Original));
+        assert_eq!(ThemeName::from_name("ReTrO"), Some(ThemeName::Retro));
+        assert_eq!(ThemeName::from_name("NORD"), Some(ThemeName::Nord));
+        assert_eq!(ThemeName::from_name("terminal"), Some(ThemeName::Terminal));
+        assert_eq!(ThemeName::from_name("SYSTEM"), Some(ThemeName::Terminal));
+        assert_eq!(ThemeName::from_name("Light"), Some(ThemeName::Light));
+        assert_eq!(
+            ThemeName::from_name("HIGH-CONTRAST"),
+            Some(ThemeName::HighContrast)
+        );
+        assert_eq!(ThemeName::from_name("auto"), Some(ThemeName::Auto));
+        assert_eq!(ThemeName::from_name("unknown"), None);
     }
 
     #[test]
@@ -421,41 +703,11 @@ mod tests {
             ("original", Theme::original()),
             ("retro", Theme::retro()),
             ("nord", Theme::nord()),
+            ("light", Theme::light()),
+            ("high_contrast", Theme::high_contrast()),
         ] {
-            for (pair, foreground, background) in [
-                ("root text", theme.text_primary, theme.background),
-                (
-                    "root secondary text",
-                    theme.text_secondary,
-                    theme.background,
-                ),
-                ("surface text", theme.text_primary, theme.surface),
-                (
-                    "surface secondary text",
-                    theme.text_secondary,
-                    theme.surface,
-                ),
-                ("accent text", theme.accent, theme.background),
-                ("surface accent text", theme.accent, theme.surface),
-                ("success text", theme.success, theme.background),
-                ("error text", theme.error, theme.surface),
-                ("info text", theme.info, theme.background),
-                ("surface info text", theme.info, theme.surface),
-                ("selected row", theme.on_accent, theme.accent),
-                ("confirm action", theme.on_success, theme.success),
-                ("Winget badge", theme.on_info, theme.info),
-                ("MsStore badge", theme.on_selection, theme.selection),
-                ("install action", theme.on_install, theme.install),
-                ("danger action", theme.on_danger, theme.danger),
-            ] {
-                assert_contrast(name, pair, foreground, background, MIN_TEXT_CONTRAST);
-            }
-
-            for (pair, foreground, background) in [
-                ("focused border", theme.accent, theme.background),
-                ("unfocused border", theme.accent_dim, theme.background),
-            ] {
-                assert_contrast(name, pair, foreground, background, MIN_NON_TEXT_CONTRAST);
+            for (pair, foreground, background, minimum) in contrast_pairs(&theme) {
+                assert_contrast(name, pair, foreground, background, minimum);
             }
 
             assert_contrast(
@@ -503,4 +755,46 @@ mod tests {
         assert_eq!(theme.on_install, Color::Rgb(240, 240, 240));
         assert_eq!(theme.on_danger, Color::Rgb(30, 30, 30));
     }
+
+    #[test]
+    fn presets_have_no_unreadable_pairs() {
+        for theme in [
+            Theme::original(),
+            Theme::retro(),
+            Theme::nord(),
+            Theme::light(),
+            Theme::high_contrast(),
+        ] {
+            assert_eq!(unreadable_pairs(&theme), Vec::<String>::new());
+        }
+    }
+
+    #[test]
+    fn auto_follows_the_terminal_background() {
+        assert_eq!(ThemeName::for_background((255, 255, 255)), ThemeName::Light);
+        assert_eq!(ThemeName::for_background((238, 232, 213)), ThemeName::Light);
+        assert_eq!(ThemeName::for_background((30, 30, 30)), ThemeName::Original);
+        assert_eq!(ThemeName::for_background((0, 43, 54)), ThemeName::Original);
+        assert_eq!(Theme::from_name(ThemeName::Auto), Theme::original());
+    }
+
+    #[test]
+    fn palette_indices_above_15_have_fixed_colors() {
+        assert_eq!(rgb(Color::Indexed(16)), Some((0, 0, 0)));
+        assert_eq!(rgb(Color::Indexed(231)), Some((255, 255, 255)));
+        assert_eq!(rgb(Color::Indexed(110)), Some((135, 175, 215)));
+        assert_eq!(rgb(Color::Indexed(244)), Some((128, 128, 128)));
+        assert_eq!(rgb(Color::Indexed(4)), None);
+        assert_eq!(rgb(Color::Blue), None);
+    }
+
+    #[test]
+    fn set_color_accepts_every_field_name() {
+        let mut theme = Theme::original();
+        for field in Theme::FIELDS {
+            assert!(theme.set_color(field, Color::Indexed(42)), "{field}");
+        }
+        assert_eq!(theme.on_danger, Color::Indexed(42));
+        assert!(!theme.set_color("border", Color::Red));
+    }
 }
@@ -13,8 +13,11 @@ use ratatui::{
 
 use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
-use crate::app::{App, AppMode, ConfirmDialog, FocusZone, InputMode};
-use crate::models::{SortDir, SortField};
+use crate::app::{
+    App, AppMode, DetailTab, FocusZone, InputMode, InstallDialog, InstallField, PinChoice,
+    PinDialog, QueueStatus, SourceDialog, SourceField, UninstallDialog, UninstallField,
+};
+use crate::models::{PackageDetail, SearchRequest, SortDir, SortField, SourceUpdate};
 use crate::theme;
 
 const APP_VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -66,14 +69,53 @@ pub fn draw(f: &mut Frame, app: &mut App) {
         draw_status_bar(f, app, chunks[3]);
     }
 
+    // Drawn first so its confirmations and add-source dialog appear on top
+    if app.show_sources {
+        draw_sources_overlay(f, app, &palette);
+    }
+
     if let Some(confirm) = &app.confirm {
-        draw_confirm_dialog(f, confirm, &palette);
+        draw_confirm_dialog(f, &confirm.message, &palette);
+    }
+
+    if app.confirm_cancel {
+        if let Some(running) = &app.running_op {
+            let message = format!(
+                "Cancel the running operation ({})? winget will be stopped.",
+                running.operation
+            );
+            draw_confirm_dialog(f, &message, &palette);
+        }
     }
 
     if app.input_mode == InputMode::VersionInput {
         draw_version_input_dialog(f, app, &palette);
     }
 
+    if let Some(dialog) = &app.install_dialog {
+        draw_install_dialog(f, dialog, &palette);
+    }
+
+    if let Some(dialog) = &app.uninstall_dialog {
+        draw_uninstall_dialog(f, dialog, &palette);
+    }
+
+    if let Some(dialog) = &app.pin_dialog {
+        draw_pin_dialog(f, dialog, &palette);
+    }
+
+    if let Some(dialog) = &app.source_dialog {
+        draw_source_dialog(f, dialog, &palette);
+    }
+
+    if app.show_queue {
+        draw_queue_overlay(f, app, &palette);
+    }
+
+    if app.show_output {
+        draw_output_overlay(f, app, &palette);
+    }
+
     if app.show_help {
         draw_help_overlay(f, app, &palette);
     }
@@ -109,6 +151,7 @@ fn draw_header(f: &mut Frame, app: &mut App, area: Rect) {
         (AppMode::Search, "\u{25C7} Search"),       // ◇ Search
         (AppMode::Installed, "\u{25A3} Installed"), // ▣ Installed
         (AppMode::Upgrades, "\u{25B3} Upgrades"),   // △ Upgrades
+        (AppMode::Pins, "\u{25C8} Pins"),           // ◈ Pins
     ];
 
     // Calculate vertical center row (center within the logo height, not the spacing)
@@ -179,14 +222,43 @@ fn draw_search_bar(f: &mut Frame, app: &mut App, area: Rect) {
         && app.input_mode != InputMode::Search
         && app.input_mode != InputMode::LocalFilter
     {
-        placeholder.to_string()
+        Line::raw(placeholder)
+    } else {
+        Line::raw(format!(" {}", active_text))
+    };
+
+    // Mark the part of a search query that does not parse, and say why
+    let syntax_error = if app.mode == AppMode::Search {
+        SearchRequest::parse(active_text).err()
     } else {
-        format!(" {}", active_text)
+        None
+    };
+    let search_text = match &syntax_error {
+        Some(e) => {
+            let error_style = search_style.fg(palette.error);
+            Line::from(vec![
+                Span::raw(" "),
+                Span::raw(&active_text[..e.span.start]),
+                Span::styled(
+                    &active_text[e.span.clone()],
+                    error_style.add_modifier(Modifier::UNDERLINED),
+                ),
+                Span::raw(&active_text[e.span.end..]),
+            ])
+        }
+        None => search_text,
     };
 
     let search = Paragraph::new(search_text).style(search_style);
     f.render_widget(search, area);
 
+    if let Some(e) = &syntax_error {
+        let message = Paragraph::new(format!("\u{26A0} {e} "))
+            .style(search_style.fg(palette.error))
+            .alignment(Alignment::Right);
+        f.render_widget(message, area);
+    }
+
     // Show cursor in search mode
     if matches!(app.input_mode, InputMode::Search | InputMode::LocalFilter) {
         let cursor_x = area.x + 1 + UnicodeWidthStr::width(active_text) as u16;
@@ -225,8 +297,9 @@ fn draw_package_list(f: &mut Frame, app: &mut App, area: Rect) {
                 "Upgrades".to_string()
             }
         }
+        AppMode::Pins => "Pins".to_string(),
     };
-    let title = if app.mode != AppMode::Search {
+    let title = if matches!(app.mode, AppMode::Installed | AppMode::Upgrades) {
         match app.pin_filter {
             crate::models::PinFilter::All => title,
             crate::models::PinFilter::PinnedOnly => format!("{title} -- only 📌"),
@@ -236,6 +309,7 @@ fn draw_package_list(f: &mut Frame, app: &mut App, area: Rect) {
         title
     };
 
+    let show_match = app.shows_match_column();
     let header_cells: Vec<Cow<'_, str>> = if app.mode == AppMode::Upgrades {
         let dir = app.sort_dir;
         vec![
@@ -248,6 +322,24 @@ fn draw_package_list(f: &mut Frame, app: &mut App, area: Rect) {
             Cow::Borrowed("Available"),
             Cow::Borrowed("Source"),
         ]
+    } else if app.mode == AppMode::Pins {
+        let dir = app.sort_dir;
+        vec![
+            sort_header("Name", SortField::Name, app.sort_field, dir),
+            sort_header("ID", SortField::Id, app.sort_field, dir),
+            Cow::Borrowed("Pin Type"),
+            sort_header("Pinned Version", SortField::Version, app.sort_field, dir),
+            Cow::Borrowed("Source"),
+        ]
+    } else if show_match {
+        let dir = app.sort_dir;
+        vec![
+            sort_header("Name", SortField::Name, app.sort_field, dir),
+            sort_header("ID", SortField::Id, app.sort_field, dir),
+            sort_header("Version", SortField::Version, app.sort_field, dir),
+            sort_header("Match", SortField::Relevance, app.sort_field, dir),
+            Cow::Borrowed("Source"),
+        ]
     } else {
         let dir = app.sort_dir;
         vec![
@@ -314,6 +406,22 @@ fn draw_package_list(f: &mut Frame, app: &mut App, area: Rect) {
                     Cell::from(Span::styled(&pkg.available_version, available_style)),
                     Cell::from(pkg.source.as_str()),
                 ]
+            } else if app.mode == AppMode::Pins {
+                vec![
+                    Cell::from(format!("{}{}", prefix, truncate(&pkg.name, 18))),
+                    Cell::from(truncate(&pkg.id, 25)),
+                    Cell::from(pkg.pin_state.kind()),
+                    Cell::from(pkg.version.as_str()),
+                    Cell::from(pkg.source.as_str()),
+                ]
+            } else if show_match {
+                vec![
+                    Cell::from(format!("{}{}", prefix, truncate(&pkg.name, 18))),
+                    Cell::from(truncate(&pkg.id, 25)),
+                    Cell::from(pkg.version.as_str()),
+                    Cell::from(truncate(&pkg.match_reason, 20)),
+                    Cell::from(pkg.source.as_str()),
+                ]
             } else {
                 vec![
                     Cell::from(format!(
@@ -332,7 +440,7 @@ fn draw_package_list(f: &mut Frame, app: &mut App, area: Rect) {
         })
         .collect();
 
-    let widths = if app.mode == AppMode::Upgrades {
+    let widths = if matches!(app.mode, AppMode::Upgrades | AppMode::Pins) {
         vec![
             Constraint::Percentage(25),
             Constraint::Percentage(30),
@@ -340,6 +448,14 @@ fn draw_package_list(f: &mut Frame, app: &mut App, area: Rect) {
             Constraint::Percentage(15),
             Constraint::Percentage(15),
         ]
+    } else if show_match {
+        vec![
+            Constraint::Percentage(22),
+            Constraint::Percentage(28),
+            Constraint::Percentage(14),
+            Constraint::Percentage(22),
+            Constraint::Percentage(14),
+        ]
     } else {
         vec![
             Constraint::Percentage(25),
@@ -369,8 +485,9 @@ fn draw_package_list(f: &mut Frame, app: &mut App, area: Rect) {
         .style(theme::root(palette))
         .padding(ratatui::widgets::Padding::top(1));
 
-    // Loading / empty state
-    let loading_msg = if app.loading {
+    // Loading / empty state. A cached list stays on screen while it revalidates.
+    let showing_cached = app.stale_list_age.is_some() && !app.filtered_packages.is_empty();
+    let loading_msg = if app.loading && !showing_cached {
         Some(format!(" {} Loading...", app.spinner()))
     } else if app.filtered_packages.is_empty() {
         Some(
@@ -399,6 +516,7 @@ fn draw_package_list(f: &mut Frame, app: &mut App, area: Rect) {
                 }
                 AppMode::Installed => " No packages found",
                 AppMode::Upgrades => " All packages are up to date!",
+                AppMode::Pins => " No pins configured",
             }
             .to_string(),
         )
@@ -462,225 +580,45 @@ fn draw_detail_panel(f: &mut Frame, app: &mut App, area: Rect) {
         .border_style(border_style)
         .title(title)
         .title_style(theme::title(palette))
-        .style(theme::root(palette))
-        .padding(ratatui::widgets::Padding::top(1));
+        .style(theme::root(palette));
 
     if let Some(detail) = &app.detail {
-        let label_style = theme::detail_label(palette);
-
-        let available_version = app
-            .selected_package()
-            .map(|p| p.available_version.as_str())
-            .unwrap_or("");
-
-        let mut lines = vec![
-            Line::from(vec![
-                Span::styled("  Name      ", label_style),
-                Span::raw(&detail.name),
-            ]),
-            Line::from(vec![
-                Span::styled("  ID        ", label_style),
-                Span::styled(&detail.id, theme::info_text(palette)),
-            ]),
-            Line::from(vec![
-                Span::styled("  Version   ", label_style),
-                Span::raw(&detail.version),
-            ]),
-        ];
-
-        if !available_version.is_empty() {
-            lines.push(Line::from(vec![
-                Span::styled("  Available ", label_style),
-                Span::styled(
-                    available_version.to_string(),
-                    theme::success_text(palette).add_modifier(Modifier::BOLD),
-                ),
-            ]));
-        }
-
-        lines.extend([
-            Line::from(vec![
-                Span::styled("  Publisher ", label_style),
-                Span::raw(&detail.publisher),
-            ]),
-            Line::from(vec![
-                Span::styled("  Source    ", label_style),
-                Span::raw(&detail.source),
-            ]),
-        ]);
-
-        if detail.pin_state.is_pinned() {
-            lines.push(Line::from(vec![
-                Span::styled("  Pin       ", label_style),
-                Span::styled("📌 ", theme::table_header(palette)),
-                Span::raw(detail.pin_state.label()),
-            ]));
-        }
-
-        if !detail.license.is_empty() {
-            lines.push(Line::from(vec![
-                Span::styled("  License   ", label_style),
-                Span::raw(&detail.license),
-            ]));
-        }
-
-        if !detail.homepage.is_empty() {
-            lines.push(Line::raw(""));
-            lines.push(Line::from(vec![
-                Span::raw("  "),
-                Span::styled(
-                    &detail.homepage,
-                    theme::info_text(palette).add_modifier(Modifier::UNDERLINED),
-                ),
-            ]));
-        }
-
-        if !detail.release_notes_url.is_empty() {
-            if detail.homepage.is_empty() {
-                lines.push(Line::raw(""));
-            }
-            lines.push(Line::from(vec![
-                Span::styled("  📋 ", theme::info_text(palette)),
-                Span::styled(
-                    &detail.release_notes_url,
-                    theme::info_text(palette).add_modifier(Modifier::UNDERLINED),
-                ),
-            ]));
-        }
-
-        if !detail.description.is_empty() {
-            lines.push(Line::raw(""));
-            lines.push(Line::from(Span::styled("  Description", label_style)));
-            // Manually word-wrap description to maintain consistent 2-space indent
-            let desc_style = theme::secondary(palette);
-            let indent = "  ";
-            // Available width: area minus borders (2) minus block padding (0 horiz) minus indent (2)
-            let max_width = (area.width as usize).saturating_sub(4);
-            for wrapped_line in word_wrap(&detail.description, max_width) {
-                lines.push(Line::from(vec![
-                    Span::raw(indent),
-                    Span::styled(wrapped_line, desc_style),
-                ]));
-            }
-        }
-
-        lines.push(Line::raw(""));
-
-        // Show context-appropriate actions (stacked vertically with spacing)
-        let has_upgrade = !available_version.is_empty();
-        match app.mode {
-            AppMode::Search => {
-                lines.push(Line::from(vec![
-                    Span::raw("  "),
-                    Span::styled(" i ", theme::action_install(palette)),
-                    Span::raw(" Install"),
-                ]));
-                lines.push(Line::raw(""));
-                lines.push(Line::from(vec![
-                    Span::raw("  "),
-                    Span::styled(" I ", theme::action_install(palette)),
-                    Span::raw(" Install specific version"),
-                ]));
-            }
-            AppMode::Installed => {
-                if has_upgrade {
-                    lines.push(Line::from(vec![
-                        Span::raw("  "),
-                        Span::styled(" u ", theme::action_key(palette)),
-                        Span::raw(" Upgrade"),
-                    ]));
-                    lines.push(Line::raw(""));
-                }
-                lines.push(Line::from(vec![
-                    Span::raw("  "),
-                    Span::styled(" x ", theme::action_danger(palette)),
-                    Span::raw(" Uninstall"),
-                ]));
-                lines.push(Line::raw(""));
-                lines.push(Line::from(vec![
-                    Span::raw("  "),
-                    Span::styled(" p ", theme::action_key(palette)),
-                    Span::raw(if detail.pin_state.is_pinned() {
-                        " Remove pin"
-                    } else {
-                        " Pin current version"
-                    }),
-                ]));
-            }
-            AppMode::Upgrades => {
-                lines.push(Line::from(vec![
-                    Span::raw("  "),
-                    Span::styled(" u ", theme::action_key(palette)),
-                    Span::raw(" Upgrade"),
-                ]));
-                lines.push(Line::raw(""));
-                lines.push(Line::from(vec![
-                    Span::raw("  "),
-                    Span::styled(" x ", theme::action_danger(palette)),
-                    Span::raw(" Uninstall"),
-                ]));
-                lines.push(Line::raw(""));
-                lines.push(Line::from(vec![
-                    Span::raw("  "),
-                    Span::styled(" Spc ", theme::action_key(palette)),
-                    Span::raw(" Select"),
-                ]));
-                lines.push(Line::raw(""));
-                lines.push(Line::from(vec![
-                    Span::raw("  "),
-                    Span::styled(" a ", theme::action_key(palette)),
-                    Span::raw(" All"),
-                ]));
-                lines.push(Line::raw(""));
-                lines.push(Line::from(vec![
-                    Span::raw("  "),
-                    Span::styled(" p ", theme::action_key(palette)),
-                    Span::raw(if detail.pin_state.is_pinned() {
-                        " Remove pin"
-                    } else {
-                        " Pin current version"
-                    }),
-                ]));
-                if !app.selected_packages.is_empty() {
-                    lines.push(Line::raw(""));
-                    lines.push(Line::from(vec![
-                        Span::raw("  "),
-                        Span::styled(" U ", theme::action_key(palette)),
-                        Span::raw(format!(" Upgrade {}", app.selected_packages.len())),
-                    ]));
-                }
-            }
-        }
-        // Open homepage hint when available
-        if !detail.homepage.is_empty() {
-            lines.push(Line::raw(""));
-            lines.push(Line::from(vec![
-                Span::raw("  "),
-                Span::styled(" o ", theme::action_key(palette)),
-                Span::raw(" Open homepage"),
-            ]));
-        }
-        if !detail.release_notes_url.is_empty() {
-            lines.push(Line::raw(""));
-            lines.push(Line::from(vec![
-                Span::raw("  "),
-                Span::styled(" c ", theme::action_key(palette)),
-                Span::raw(" Open changelog"),
-            ]));
-        }
-
-        // Store total line count for scroll clamping (used by scroll_detail)
-        app.detail_content_lines = lines.len();
+        // Tab strip on the first row, a spacer, then the active tab's content
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+        let tab_row = Rect {
+            height: inner.height.min(1),
+            ..inner
+        };
+        let content = Rect {
+            y: inner.y + 2,
+            height: inner.height.saturating_sub(2),
+            ..inner
+        };
+        let (tab_line, tab_regions) = detail_tab_strip(app, tab_row);
+        f.render_widget(Paragraph::new(tab_line), tab_row);
+
+        let lines = match app.detail_tab {
+            DetailTab::Overview => detail_overview_lines(app, detail, area.width),
+            DetailTab::Versions => detail_versions_lines(app, detail),
+            DetailTab::Installer => detail_installer_lines(app, detail, area.width),
+            DetailTab::Dependencies => detail_dependencies_lines(app, detail),
+            DetailTab::Raw => detail_raw_lines(app, detail, area.width),
+        };
+        let content_lines = lines.len();
 
         let p = Paragraph::new(lines)
-            .block(block)
             .wrap(Wrap { trim: false })
             .scroll((app.detail_scroll as u16, 0));
-        f.render_widget(p, area);
+        f.render_widget(p, content);
+
+        // Store total line count for scroll clamping (used by scroll_detail)
+        app.detail_content_lines = content_lines;
+        app.layout.detail_tab_y = tab_row.y;
+        app.layout.detail_tab_regions = tab_regions;
 
         // Scrollbar for detail panel (shown when content overflows)
-        let viewport_height = area.height.saturating_sub(3) as usize;
-        if app.detail_content_lines > viewport_height {
+        if app.detail_content_lines > content.height as usize {
             let mut scrollbar_state =
                 ScrollbarState::new(app.detail_content_lines).position(app.detail_scroll);
             let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
@@ -690,10 +628,11 @@ fn draw_detail_panel(f: &mut Frame, app: &mut App, area: Rect) {
                 .thumb_symbol("\u{2588}"); // █
             f.render_stateful_widget(
                 scrollbar,
-                area.inner(ratatui::layout::Margin {
-                    vertical: 1,
-                    horizontal: 0,
-                }),
+                Rect {
+                    x: area.x,
+                    width: area.width,
+                    ..content
+                },
                 &mut scrollbar_state,
             );
         }
@@ -707,109 +646,610 @@ fn draw_detail_panel(f: &mut Frame, app: &mut App, area: Rect) {
         } else {
             "  Select a package to view details".to_string()
         };
+        app.layout.detail_tab_regions.clear();
         let p = Paragraph::new(msg)
-            .block(block)
+            .block(block.padding(ratatui::widgets::Padding::top(1)))
             .style(theme::secondary(palette));
         f.render_widget(p, area);
     }
 }
 
-fn draw_status_bar(f: &mut Frame, app: &App, area: Rect) {
+/// The detail panel's tab strip and the click region of each tab.
+fn detail_tab_strip(app: &App, row: Rect) -> (Line<'static>, Vec<(u16, u16, DetailTab)>) {
     let palette = &app.theme;
-    let filter_text = format!(" {} ", app.source_filter);
-    let filter_len = UnicodeWidthStr::width(filter_text.as_str()) as u16 + 2; // + padding
-    let show_pin_badge = app.mode != AppMode::Search;
-    let pin_text = match app.pin_filter {
-        crate::models::PinFilter::All => " 📌 all ".to_string(),
-        crate::models::PinFilter::PinnedOnly => " 📌 only ".to_string(),
-        crate::models::PinFilter::UnpinnedOnly => " 📌 hide ".to_string(),
-    };
-    let pin_len = if show_pin_badge {
-        UnicodeWidthStr::width(pin_text.as_str()) as u16 + 1
-    } else {
-        0
-    };
-
-    let chunks = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([
-            Constraint::Length(filter_len), // filter badge
-            Constraint::Length(pin_len),    // pin badge
-            Constraint::Min(1),             // status message
-            Constraint::Length(88),         // global hotkeys
-        ])
-        .split(area);
-
-    // Filter badge
-    let filter_style = match app.source_filter {
-        crate::models::SourceFilter::All => theme::surface(palette),
-        crate::models::SourceFilter::Winget => theme::source_winget(palette),
-        crate::models::SourceFilter::MsStore => theme::source_msstore(palette),
-    };
-    let filter_badge = Paragraph::new(filter_text).style(filter_style);
-    f.render_widget(filter_badge, chunks[0]);
-
-    if show_pin_badge {
-        let pin_style = match app.pin_filter {
-            crate::models::PinFilter::All => theme::surface(palette),
-            crate::models::PinFilter::PinnedOnly => theme::navbar_active(palette),
-            crate::models::PinFilter::UnpinnedOnly => theme::source_winget(palette),
+    let mut x = row.x + 1;
+    let mut regions = Vec::new();
+    let mut spans = vec![Span::raw(" ")];
+    for tab in DetailTab::ALL {
+        let text = format!(" {} ", tab.label());
+        let width = UnicodeWidthStr::width(text.as_str()) as u16;
+        let style = if tab == app.detail_tab {
+            theme::navbar_active(palette)
+        } else {
+            theme::secondary(palette)
         };
-        let pin_badge = Paragraph::new(pin_text).style(pin_style);
-        f.render_widget(pin_badge, chunks[1]);
+        regions.push((x, x + width, tab));
+        x += width + 1;
+        spans.push(Span::styled(text, style));
+        spans.push(Span::raw(" "));
     }
+    (Line::from(spans), regions)
+}
 
-    // Status message with spinner when loading
-    let status_text = if app.loading {
-        format!(" {} {}", app.spinner(), app.status_message)
-    } else {
-        format!(" {}", app.status_message)
-    };
-    let status_style =
-        if app.status_message.contains("failed") || app.status_message.contains("Error") {
-            theme::status_error(palette)
-        } else if app.loading {
-            theme::status_loading(palette)
-        } else {
-            theme::status_normal(palette)
-        };
-    let status = Paragraph::new(status_text).style(status_style);
-    f.render_widget(status, chunks[2]);
+/// A dimmed one-line message for a tab with nothing (yet) to show.
+fn detail_placeholder(app: &App, text: String) -> Vec<Line<'static>> {
+    vec![Line::from(Span::styled(
+        format!("  {text}"),
+        theme::secondary(&app.theme),
+    ))]
+}
 
-    // Global hotkey badges
-    let key_style = theme::action_key(palette);
-    let sep = Span::raw(" ");
-    let label_style = theme::surface_secondary(palette);
+/// Whether the active tab's data for `id` is still being fetched.
+fn detail_tab_pending(app: &App, id: &str) -> bool {
+    app.detail_tab_loading
+        .as_ref()
+        .is_some_and(|(tab, pending)| *tab == app.detail_tab && pending == id)
+}
 
-    let hotkeys = match app.input_mode {
-        InputMode::Search => Line::from(vec![
-            Span::styled(" Esc ", key_style),
-            Span::styled(" Cancel ", label_style),
-            sep.clone(),
-            Span::styled(" Enter ", key_style),
-            Span::styled(" Search ", label_style),
+fn detail_overview_lines<'a>(app: &'a App, detail: &'a PackageDetail, width: u16) -> Vec<Line<'a>> {
+    let palette = &app.theme;
+    let label_style = theme::detail_label(palette);
+
+    let available_version = app
+        .selected_package()
+        .map(|p| p.available_version.as_str())
+        .unwrap_or("");
+
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled("  Name      ", label_style),
+            Span::raw(&detail.name),
         ]),
-        InputMode::LocalFilter => Line::from(vec![
-            Span::styled(" Esc ", key_style),
-            Span::styled(" Clear ", label_style),
-            sep.clone(),
-            Span::styled(" Enter ", key_style),
-            Span::styled(" Done ", label_style),
-            sep.clone(),
-            Span::styled(" Bksp ", key_style),
-            Span::styled(" Delete ", label_style),
+        Line::from(vec![
+            Span::styled("  ID        ", label_style),
+            Span::styled(&detail.id, theme::info_text(palette)),
         ]),
-        InputMode::VersionInput => Line::from(vec![
-            Span::styled(" Esc ", key_style),
-            Span::styled(" Cancel ", label_style),
-            sep.clone(),
-            Span::styled(" Enter ", key_style),
-            Span::styled(" Confirm ", label_style),
-            sep.clone(),
-            Span::styled(" Bksp ", key_style),
-            Span::styled(" Delete ", label_style),
+        Line::from(vec![
+            Span::styled("  Version   ", label_style),
+            Span::raw(&detail.version),
         ]),
-        InputMode::Normal => Line::from(vec![
+    ];
+
+    if !available_version.is_empty() {
+        lines.push(Line::from(vec![
+            Span::styled("  Available ", label_style),
+            Span::styled(
+                available_version.to_string(),
+                theme::success_text(palette).add_modifier(Modifier::BOLD),
+            ),
+        ]));
+    }
+
+    lines.extend([
+        Line::from(vec![
+            Span::styled("  Publisher ", label_style),
+            Span::raw(&detail.publisher),
+        ]),
+        Line::from(vec![
+            Span::styled("  Source    ", label_style),
+            Span::raw(&detail.source),
+        ]),
+    ]);
+
+    if detail.pin_state.is_pinned() {
+        lines.push(Line::from(vec![
+            Span::styled("  Pin       ", label_style),
+            Span::styled("📌 ", theme::table_header(palette)),
+            Span::raw(detail.pin_state.label()),
+        ]));
+    }
+
+    if !detail.license.is_empty() {
+        lines.push(Line::from(vec![
+            Span::styled("  License   ", label_style),
+            Span::raw(&detail.license),
+        ]));
+    }
+
+    let plain = Style::default();
+    let link = theme::info_text(palette).add_modifier(Modifier::UNDERLINED);
+    for (label, value) in [
+        ("Author", &detail.author),
+        ("Moniker", &detail.moniker),
+        ("Copyright", &detail.copyright),
+    ] {
+        if !value.is_empty() {
+            lines.extend(detail_field_lines(label, value, label_style, plain, width));
+        }
+    }
+
+    if !detail.homepage.is_empty() {
+        lines.push(Line::raw(""));
+        lines.push(Line::from(vec![
+            Span::raw("  "),
+            Span::styled(
+                &detail.homepage,
+                theme::info_text(palette).add_modifier(Modifier::UNDERLINED),
+            ),
+        ]));
+    }
+
+    if !detail.release_notes_url.is_empty() {
+        if detail.homepage.is_empty() {
+            lines.push(Line::raw(""));
+        }
+        lines.push(Line::from(vec![
+            Span::styled("  📋 ", theme::info_text(palette)),
+            Span::styled(
+                &detail.release_notes_url,
+                theme::info_text(palette).add_modifier(Modifier::UNDERLINED),
+            ),
+        ]));
+    }
+
+    let extra_links: Vec<(&str, &str)> = [
+        ("Privacy", detail.privacy_url.as_str()),
+        ("Support", detail.support_url.as_str()),
+    ]
+    .into_iter()
+    .chain(
+        detail
+            .documentation
+            .iter()
+            .map(|(label, url)| (label.as_str(), url.as_str())),
+    )
+    .filter(|(_, url)| !url.is_empty())
+    .collect();
+    if !extra_links.is_empty() {
+        if detail.homepage.is_empty() && detail.release_notes_url.is_empty() {
+            lines.push(Line::raw(""));
+        }
+        for (label, url) in extra_links {
+            lines.extend(detail_field_lines(label, url, label_style, link, width));
+        }
+    }
+
+    if !detail.description.is_empty() {
+        lines.push(Line::raw(""));
+        lines.push(Line::from(Span::styled("  Description", label_style)));
+        // Manually word-wrap description to maintain consistent 2-space indent
+        let desc_style = theme::secondary(palette);
+        let indent = "  ";
+        // Available width: area minus borders (2) minus block padding (0 horiz) minus indent (2)
+        let max_width = (width as usize).saturating_sub(4);
+        for wrapped_line in word_wrap(&detail.description, max_width) {
+            lines.push(Line::from(vec![
+                Span::raw(indent),
+                Span::styled(wrapped_line, desc_style),
+            ]));
+        }
+    }
+
+    if !detail.release_notes.is_empty() {
+        lines.push(Line::raw(""));
+        let (marker, hint) = if app.release_notes_expanded {
+            ("\u{25BE}", "n to collapse")
+        } else {
+            ("\u{25B8}", "n to expand")
+        };
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {marker} Release Notes"), label_style),
+            Span::styled(format!("  ({hint})"), theme::secondary(palette)),
+        ]));
+        if app.release_notes_expanded {
+            let max_width = (width as usize).saturating_sub(4);
+            for line in release_notes_lines(&detail.release_notes, max_width) {
+                lines.push(Line::from(vec![
+                    Span::raw("  "),
+                    Span::styled(line, theme::secondary(palette)),
+                ]));
+            }
+        }
+    }
+
+    if !detail.tags.is_empty() {
+        lines.push(Line::raw(""));
+        lines.push(Line::from(Span::styled("  Tags", label_style)));
+        let max_width = (width as usize).saturating_sub(4);
+        for wrapped_line in word_wrap(&detail.tags.join(", "), max_width) {
+            lines.push(Line::from(vec![
+                Span::raw("  "),
+                Span::styled(wrapped_line, theme::secondary(palette)),
+            ]));
+        }
+    }
+
+    if !detail.agreements.is_empty() {
+        lines.push(Line::raw(""));
+        lines.push(Line::from(Span::styled("  Agreements", label_style)));
+        for (label, value) in &detail.agreements {
+            let style = if value.starts_with("http") {
+                link
+            } else {
+                plain
+            };
+            lines.extend(detail_field_lines(label, value, label_style, style, width));
+        }
+    }
+
+    lines.push(Line::raw(""));
+
+    // Show context-appropriate actions (stacked vertically with spacing)
+    let has_upgrade = !available_version.is_empty();
+    match app.mode {
+        AppMode::Search => {
+            lines.push(Line::from(vec![
+                Span::raw("  "),
+                Span::styled(" i ", theme::action_install(palette)),
+                Span::raw(" Install"),
+            ]));
+            lines.push(Line::raw(""));
+            lines.push(Line::from(vec![
+                Span::raw("  "),
+                Span::styled(" I ", theme::action_install(palette)),
+                Span::raw(" Install specific version"),
+            ]));
+            lines.push(Line::raw(""));
+            lines.push(Line::from(vec![
+                Span::raw("  "),
+                Span::styled(" O ", theme::action_install(palette)),
+                Span::raw(" Install with options"),
+            ]));
+        }
+        AppMode::Installed => {
+            if has_upgrade {
+                lines.push(Line::from(vec![
+                    Span::raw("  "),
+                    Span::styled(" u ", theme::action_key(palette)),
+                    Span::raw(" Upgrade"),
+                ]));
+                lines.push(Line::raw(""));
+            }
+            lines.push(Line::from(vec![
+                Span::raw("  "),
+                Span::styled(" x ", theme::action_danger(palette)),
+                Span::raw(" Uninstall"),
+            ]));
+            lines.push(Line::raw(""));
+            lines.push(Line::from(vec![
+                Span::raw("  "),
+                Span::styled(" p ", theme::action_key(palette)),
+                Span::raw(if detail.pin_state.is_pinned() {
+                    " Change or remove pin"
+                } else {
+                    " Pin current version"
+                }),
+            ]));
+        }
+        AppMode::Upgrades => {
+            lines.push(Line::from(vec![
+                Span::raw("  "),
+                Span::styled(" u ", theme::action_key(palette)),
+                Span::raw(" Upgrade"),
+            ]));
+            lines.push(Line::raw(""));
+            lines.push(Line::from(vec![
+                Span::raw("  "),
+                Span::styled(" x ", theme::action_danger(palette)),
+                Span::raw(" Uninstall"),
+            ]));
+            lines.push(Line::raw(""));
+            lines.push(Line::from(vec![
+                Span::raw("  "),
+                Span::styled(" Spc ", theme::action_key(palette)),
+                Span::raw(" Select"),
+            ]));
+            lines.push(Line::raw(""));
+            lines.push(Line::from(vec![
+                Span::raw("  "),
+                Span::styled(" a ", theme::action_key(palette)),
+                Span::raw(" All"),
+            ]));
+            lines.push(Line::raw(""));
+            lines.push(Line::from(vec![
+                Span::raw("  "),
+                Span::styled(" p ", theme::action_key(palette)),
+                Span::raw(if detail.pin_state.is_pinned() {
+                    " Change or remove pin"
+                } else {
+                    " Pin current version"
+                }),
+            ]));
+            if !app.selected_packages.is_empty() {
+                lines.push(Line::raw(""));
+                lines.push(Line::from(vec![
+                    Span::raw("  "),
+                    Span::styled(" U ", theme::action_key(palette)),
+                    Span::raw(format!(" Upgrade {}", app.selected_packages.len())),
+                ]));
+            }
+        }
+        AppMode::Pins => {
+            lines.push(Line::from(vec![
+                Span::raw("  "),
+                Span::styled(" p ", theme::action_key(palette)),
+                Span::raw(" Change or remove pin"),
+            ]));
+            lines.push(Line::raw(""));
+            lines.push(Line::from(vec![
+                Span::raw("  "),
+                Span::styled(" R ", theme::action_danger(palette)),
+                Span::raw(" Reset all pins"),
+            ]));
+        }
+    }
+    // Open homepage hint when available
+    if !detail.homepage.is_empty() {
+        lines.push(Line::raw(""));
+        lines.push(Line::from(vec![
+            Span::raw("  "),
+            Span::styled(" o ", theme::action_key(palette)),
+            Span::raw(" Open homepage"),
+        ]));
+    }
+    if !detail.release_notes_url.is_empty() {
+        lines.push(Line::raw(""));
+        lines.push(Line::from(vec![
+            Span::raw("  "),
+            Span::styled(" c ", theme::action_key(palette)),
+            Span::raw(" Open changelog"),
+        ]));
+    }
+
+    lines
+}
+
+fn detail_versions_lines<'a>(app: &'a App, detail: &PackageDetail) -> Vec<Line<'a>> {
+    let palette = &app.theme;
+    let Some(versions) = app.versions_cache.get(&detail.id) else {
+        return if detail_tab_pending(app, &detail.id) {
+            detail_placeholder(app, format!("{} Loading versions...", app.spinner()))
+        } else {
+            detail_placeholder(
+                app,
+                "No version list available for this package".to_string(),
+            )
+        };
+    };
+    if versions.is_empty() {
+        return detail_placeholder(app, "No versions reported by winget".to_string());
+    }
+
+    let installed = app
+        .selected_package()
+        .filter(|p| p.id == detail.id && app.mode != AppMode::Search)
+        .map(|p| p.version.as_str())
+        .unwrap_or("");
+    let width = versions
+        .iter()
+        .map(|v| UnicodeWidthStr::width(v.as_str()))
+        .max()
+        .unwrap_or(0);
+    let mut lines = vec![
+        Line::from(Span::styled(
+            format!("  {} versions", versions.len()),
+            theme::detail_label(palette),
+        )),
+        Line::raw(""),
+    ];
+    for (i, version) in versions.iter().enumerate() {
+        let mut spans = vec![Span::raw(format!("  {version:<width$}"))];
+        if i == 0 {
+            spans.push(Span::styled("  latest", theme::info_text(palette)));
+        }
+        if !installed.is_empty() && version == installed {
+            spans.push(Span::styled("  installed", theme::success_text(palette)));
+        }
+        lines.push(Line::from(spans));
+    }
+    lines
+}
+
+fn detail_installer_lines<'a>(
+    app: &'a App,
+    detail: &'a PackageDetail,
+    width: u16,
+) -> Vec<Line<'a>> {
+    let palette = &app.theme;
+    let label_style = theme::detail_label(palette);
+    let plain = Style::default();
+    let link = theme::info_text(palette).add_modifier(Modifier::UNDERLINED);
+    let installer = [
+        ("Type", &detail.installer_type, plain),
+        ("Released", &detail.release_date, plain),
+        ("URL", &detail.installer_url, link),
+        (
+            "SHA256",
+            &detail.installer_sha256,
+            theme::secondary(palette),
+        ),
+    ];
+    if installer.iter().all(|(_, value, _)| value.is_empty()) {
+        return detail_placeholder(app, "No installer information available".to_string());
+    }
+    installer
+        .into_iter()
+        .filter(|(_, value, _)| !value.is_empty())
+        .flat_map(|(label, value, style)| {
+            detail_field_lines(label, value, label_style, style, width)
+        })
+        .collect()
+}
+
+fn detail_dependencies_lines<'a>(app: &'a App, detail: &'a PackageDetail) -> Vec<Line<'a>> {
+    let palette = &app.theme;
+    if detail.dependencies.is_empty() {
+        return detail_placeholder(app, "No dependencies declared".to_string());
+    }
+    let mut lines = Vec::new();
+    for group in &detail.dependencies {
+        if !lines.is_empty() {
+            lines.push(Line::raw(""));
+        }
+        if !group.kind.is_empty() {
+            lines.push(Line::from(Span::styled(
+                format!("  {}", group.kind),
+                theme::detail_label(palette),
+            )));
+        }
+        for item in &group.items {
+            lines.push(Line::from(format!("    \u{2022} {item}")));
+        }
+    }
+    lines
+}
+
+fn detail_raw_lines(app: &App, detail: &PackageDetail, width: u16) -> Vec<Line<'static>> {
+    let Some(raw) = app.raw_cache.get(&detail.id) else {
+        return if detail_tab_pending(app, &detail.id) || app.detail_loading {
+            detail_placeholder(
+                app,
+                format!("{} Loading winget show output...", app.spinner()),
+            )
+        } else {
+            detail_placeholder(app, "No winget show output for this package".to_string())
+        };
+    };
+    let max_width = (width as usize).saturating_sub(4);
+    raw.lines()
+        .flat_map(|line| wrap_chars(line, max_width))
+        .map(|line| Line::raw(format!("  {line}")))
+        .collect()
+}
+
+fn draw_status_bar(f: &mut Frame, app: &App, area: Rect) {
+    let palette = &app.theme;
+    let filter_text = format!(" {} ", truncate(&app.source_filter.to_string(), 20));
+    let filter_len = UnicodeWidthStr::width(filter_text.as_str()) as u16 + 2; // + padding
+    let show_pin_badge = matches!(app.mode, AppMode::Installed | AppMode::Upgrades);
+    let pin_text = match app.pin_filter {
+        crate::models::PinFilter::All => " 📌 all ".to_string(),
+        crate::models::PinFilter::PinnedOnly => " 📌 only ".to_string(),
+        crate::models::PinFilter::UnpinnedOnly => " 📌 hide ".to_string(),
+    };
+    let pin_len = if show_pin_badge {
+        UnicodeWidthStr::width(pin_text.as_str()) as u16 + 1
+    } else {
+        0
+    };
+
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Length(filter_len), // filter badge
+            Constraint::Length(pin_len),    // pin badge
+            Constraint::Min(1),             // status message
+            Constraint::Length(88),         // global hotkeys
+        ])
+        .split(area);
+
+    // Filter badge
+    let filter_style = match app.source_filter.as_arg() {
+        None => theme::surface(palette),
+        Some(name) if name.eq_ignore_ascii_case("winget") => theme::source_winget(palette),
+        Some(name) if name.eq_ignore_ascii_case("msstore") => theme::source_msstore(palette),
+        Some(_) => theme::source_custom(palette),
+    };
+    let filter_badge = Paragraph::new(filter_text).style(filter_style);
+    f.render_widget(filter_badge, chunks[0]);
+
+    if show_pin_badge {
+        let pin_style = match app.pin_filter {
+            crate::models::PinFilter::All => theme::surface(palette),
+            crate::models::PinFilter::PinnedOnly => theme::navbar_active(palette),
+            crate::models::PinFilter::UnpinnedOnly => theme::source_winget(palette),
+        };
+        let pin_badge = Paragraph::new(pin_text).style(pin_style);
+        f.render_widget(pin_badge, chunks[1]);
+    }
+
+    // Status message with spinner when loading, plus live operation progress
+    let progress = app
+        .operation_progress_label()
+        .map(|label| format!(" · {label}"))
+        .unwrap_or_default();
+    let queued = match app.pending_count() {
+        0 => String::new(),
+        n => format!(" · {n} queued"),
+    };
+    let stale = app
+        .stale_list_age
+        .map(|age| format!(" · ◷ cached {}", crate::cache::describe_age(age)))
+        .unwrap_or_default();
+    let processes = app
+        .process_depth_label()
+        .map(|label| format!(" · ⚙ {label}"))
+        .unwrap_or_default();
+    let reboot = if app.reboot_pending {
+        " · ⟳ restart required"
+    } else {
+        ""
+    };
+    let status_text = if app.loading || app.running_op.is_some() {
+        format!(
+            " {} {}{}{}{}{}{}",
+            app.spinner(),
+            app.status_message,
+            stale,
+            progress,
+            queued,
+            processes,
+            reboot
+        )
+    } else {
+        format!(
+            " {}{}{}{}{}{}",
+            app.status_message, stale, progress, queued, processes, reboot
+        )
+    };
+    let status_style =
+        if app.status_message.contains("failed") || app.status_message.contains("Error") {
+            theme::status_error(palette)
+        } else if app.loading {
+            theme::status_loading(palette)
+        } else {
+            theme::status_normal(palette)
+        };
+    let status = Paragraph::new(status_text).style(status_style);
+    f.render_widget(status, chunks[2]);
+
+    // Global hotkey badges
+    let key_style = theme::action_key(palette);
+    let sep = Span::raw(" ");
+    let label_style = theme::surface_secondary(palette);
+
+    let hotkeys = match app.input_mode {
+        InputMode::Search => Line::from(vec![
+            Span::styled(" Esc ", key_style),
+            Span::styled(" Cancel ", label_style),
+            sep.clone(),
+            Span::styled(" Enter ", key_style),
+            Span::styled(" Search ", label_style),
+        ]),
+        InputMode::LocalFilter => Line::from(vec![
+            Span::styled(" Esc ", key_style),
+            Span::styled(" Clear ", label_style),
+            sep.clone(),
+            Span::styled(" Enter ", key_style),
+            Span::styled(" Done ", label_style),
+            sep.clone(),
+            Span::styled(" Bksp ", key_style),
+            Span::styled(" Delete ", label_style),
+        ]),
+        InputMode::VersionInput => Line::from(vec![
+            Span::styled(" Esc ", key_style),
+            Span::styled(" Cancel ", label_style),
+            sep.clone(),
+            Span::styled(" ↑↓ ", key_style),
+            Span::styled(" Pick ", label_style),
+            sep.clone(),
+            Span::styled(" Enter ", key_style),
+            Span::styled(" Confirm ", label_style),
+            sep.clone(),
+            Span::styled(" Bksp ", key_style),
+            Span::styled(" Delete ", label_style),
+        ]),
+        InputMode::Normal => Line::from(vec![
             Span::styled(" / ", key_style),
             Span::styled(" Search/Filter ", label_style),
             sep.clone(),
@@ -836,36 +1276,595 @@ fn draw_status_bar(f: &mut Frame, app: &App, area: Rect) {
         ]),
     };
 
-    let hints = Paragraph::new(hotkeys)
-        .style(theme::surface(palette))
-        .alignment(Alignment::Right);
-    f.render_widget(hints, chunks[3]);
+    let hints = Paragraph::new(hotkeys)
+        .style(theme::surface(palette))
+        .alignment(Alignment::Right);
+    f.render_widget(hints, chunks[3]);
+}
+
+fn draw_confirm_dialog(f: &mut Frame, message: &str, palette: &theme::Theme) {
+    let area = centered_rect(50, 20, f.area());
+    f.render_widget(Clear, area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(palette.accent).bg(palette.surface))
+        .title(" Confirm ")
+        .title_style(theme::help_section(palette))
+        .style(theme::surface(palette));
+
+    let lines = vec![
+        Line::raw(""),
+        Line::from(vec![Span::raw("  "), Span::raw(message)]),
+        Line::raw(""),
+        Line::from(vec![
+            Span::raw("  "),
+            Span::styled(" y ", theme::action_confirm(palette)),
+            Span::raw(" Yes   "),
+            Span::styled(" n ", theme::action_danger(palette)),
+            Span::raw(" No"),
+        ]),
+    ];
+
+    let p = Paragraph::new(lines)
+        .block(block)
+        .style(theme::surface(palette))
+        .wrap(Wrap { trim: false });
+    f.render_widget(p, area);
+}
+
+fn draw_version_input_dialog(f: &mut Frame, app: &App, palette: &theme::Theme) {
+    let area = centered_rect(55, 60, f.area());
+    f.render_widget(Clear, area);
+
+    let pkg = app.selected_package();
+    let pkg_name = pkg.map(|p| p.name.as_str()).unwrap_or("package");
+    let installed = pkg.map(|p| p.version.as_str()).unwrap_or_default();
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(" 📦 Install Specific Version ")
+        .title_style(theme::help_section(palette))
+        .border_style(Style::default().fg(palette.accent).bg(palette.surface))
+        .style(theme::surface(palette));
+
+    let label_style = Style::default()
+        .fg(palette.accent)
+        .bg(palette.surface)
+        .add_modifier(Modifier::BOLD);
+
+    let mut lines = vec![
+        Line::raw(""),
+        Line::from(vec![
+            Span::raw("  Package: "),
+            Span::styled(pkg_name, label_style),
+        ]),
+        Line::raw(""),
+        Line::from(vec![
+            Span::raw("  Version: "),
+            Span::styled(
+                &app.version_input,
+                theme::surface(palette).add_modifier(Modifier::BOLD),
+            ),
+            Span::styled("█", Style::default().fg(palette.accent).bg(palette.surface)),
+        ]),
+        Line::raw(""),
+    ];
+
+    // Header (5 lines) and footer (2 lines) inside the borders
+    let list_rows = area.height.saturating_sub(2 + 5 + 2) as usize;
+    let versions = app.filtered_versions();
+    if app.picker_loading {
+        lines.push(Line::from(Span::styled(
+            format!("  {} Loading versions...", app.spinner()),
+            theme::surface_secondary(palette),
+        )));
+    } else if versions.is_empty() {
+        let hint = if app.version_input.trim().is_empty() {
+            "  No versions listed -- type one, or Enter for the latest"
+        } else {
+            "  No match -- Enter installs the typed version"
+        };
+        lines.push(Line::from(Span::styled(
+            hint,
+            theme::surface_secondary(palette),
+        )));
+    }
+
+    let latest = app.latest_version();
+    let scroll = app
+        .picker_selected
+        .saturating_sub(list_rows.saturating_sub(1));
+    for (index, version) in versions.iter().enumerate().skip(scroll).take(list_rows) {
+        let mut text = version.to_string();
+        if !app.is_listed_version(version) {
+            text.push_str("  (typed)");
+        }
+        if *version == installed {
+            text.push_str("  (installed)");
+        }
+        if Some(*version) == latest {
+            text.push_str("  (latest)");
+        }
+        if index == app.picker_selected {
+            lines.push(Line::from(vec![
+                Span::raw("  "),
+                Span::styled(format!("> {text}"), theme::selected_row(palette)),
+            ]));
+        } else {
+            lines.push(Line::from(vec![Span::raw("    "), Span::raw(text)]));
+        }
+    }
+    while lines.len() < 5 + list_rows.max(1) {
+        lines.push(Line::raw(""));
+    }
+
+    lines.push(Line::raw(""));
+    lines.push(Line::from(vec![
+        Span::raw("  "),
+        Span::styled(" Enter ", theme::action_confirm(palette)),
+        Span::raw(" Confirm   "),
+        Span::styled(" Esc ", theme::action_danger(palette)),
+        Span::raw(" Cancel"),
+    ]));
+
+    let p = Paragraph::new(lines)
+        .block(block)
+        .style(theme::surface(palette));
+    f.render_widget(p, area);
+
+    // Position terminal cursor at end of version input field
+    let cursor_x = area.x + 12 + UnicodeWidthStr::width(app.version_input.as_str()) as u16;
+    let cursor_y = area.y + 4; // row 0: border, 1: blank, 2: package, 3: blank, 4: version
+    f.set_cursor_position((cursor_x, cursor_y));
+}
+
+fn draw_install_dialog(f: &mut Frame, dialog: &InstallDialog, palette: &theme::Theme) {
+    let area = centered_rect(60, 60, f.area());
+    f.render_widget(Clear, area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(" 📦 Install Options ")
+        .title_style(theme::help_section(palette))
+        .border_style(Style::default().fg(palette.accent).bg(palette.surface))
+        .style(theme::surface(palette));
+
+    let label_style = Style::default()
+        .fg(palette.accent)
+        .bg(palette.surface)
+        .add_modifier(Modifier::BOLD);
+    let key = theme::help_key(palette);
+
+    let mut lines = vec![
+        Line::raw(""),
+        Line::from(vec![
+            Span::raw("  Package: "),
+            Span::styled(dialog.id.as_str(), label_style),
+        ]),
+        Line::raw(""),
+    ];
+
+    let current = dialog.current_field();
+    for field in InstallField::ALL {
+        let mut value = dialog.value(field);
+        if field == current && field.is_text() {
+            value.push('█');
+        } else if !field.is_text() {
+            value = format!("‹ {value} ›");
+        }
+        let text = format!("{:<16}{value}", field.label());
+        if field == current {
+            lines.push(Line::from(vec![
+                Span::raw("  "),
+                Span::styled(text, theme::selected_row(palette)),
+            ]));
+        } else {
+            lines.push(Line::from(vec![Span::raw("  "), Span::raw(text)]));
+        }
+    }
+
+    lines.push(Line::raw(""));
+    lines.push(Line::from(vec![
+        Span::styled("  ↑/↓ ", key),
+        Span::raw("field  "),
+        Span::styled("←/→ Space ", key),
+        Span::raw("change  "),
+        Span::raw("type to edit text"),
+    ]));
+    lines.push(Line::raw(""));
+    lines.push(Line::from(vec![
+        Span::raw("  "),
+        Span::styled(" Enter ", theme::action_confirm(palette)),
+        Span::raw(" Confirm   "),
+        Span::styled(" Esc ", theme::action_danger(palette)),
+        Span::raw(" Cancel"),
+    ]));
+
+    let p = Paragraph::new(lines)
+        .block(block)
+        .style(theme::surface(palette))
+        .wrap(Wrap { trim: false });
+    f.render_widget(p, area);
+}
+
+fn draw_uninstall_dialog(f: &mut Frame, dialog: &UninstallDialog, palette: &theme::Theme) {
+    let area = centered_rect(60, 50, f.area());
+    f.render_widget(Clear, area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(" 🗑 Uninstall Options ")
+        .title_style(theme::help_section(palette))
+        .border_style(Style::default().fg(palette.accent).bg(palette.surface))
+        .style(theme::surface(palette));
+
+    let label_style = Style::default()
+        .fg(palette.accent)
+        .bg(palette.surface)
+        .add_modifier(Modifier::BOLD);
+    let key = theme::help_key(palette);
+
+    let mut lines = vec![
+        Line::raw(""),
+        Line::from(vec![
+            Span::raw("  Package: "),
+            Span::styled(dialog.id.as_str(), label_style),
+        ]),
+        Line::raw(""),
+    ];
+
+    let current = dialog.current_field();
+    for field in UninstallField::ALL {
+        let mut value = dialog.value(field);
+        if field == UninstallField::Version {
+            if field == current {
+                value.push('█');
+            } else if value.is_empty() {
+                value = "any".to_string();
+            }
+        } else {
+            value = format!("‹ {value} ›");
+        }
+        let text = format!("{:<16}{value}", field.label());
+        if field == current {
+            lines.push(Line::from(vec![
+                Span::raw("  "),
+                Span::styled(text, theme::selected_row(palette)),
+            ]));
+        } else {
+            lines.push(Line::from(vec![Span::raw("  "), Span::raw(text)]));
+        }
+    }
+
+    let removal = dialog.options.describe_removal(&dialog.id);
+    lines.push(Line::raw(""));
+    lines.push(Line::from(vec![
+        Span::raw("  Removes: "),
+        Span::styled(removal, label_style),
+    ]));
+    lines.push(Line::raw(""));
+    lines.push(Line::from(vec![
+        Span::styled("  ↑/↓ ", key),
+        Span::raw("field  "),
+        Span::styled("←/→ Space ", key),
+        Span::raw("toggle  "),
+        Span::raw("type a version"),
+    ]));
+    lines.push(Line::raw(""));
+    lines.push(Line::from(vec![
+        Span::raw("  "),
+        Span::styled(" Enter ", theme::action_confirm(palette)),
+        Span::raw(" Confirm   "),
+        Span::styled(" Esc ", theme::action_danger(palette)),
+        Span::raw(" Cancel"),
+    ]));
+
+    let p = Paragraph::new(lines)
+        .block(block)
+        .style(theme::surface(palette))
+        .wrap(Wrap { trim: false });
+    f.render_widget(p, area);
+}
+
+fn draw_pin_dialog(f: &mut Frame, dialog: &PinDialog, palette: &theme::Theme) {
+    let area = centered_rect(60, 45, f.area());
+    f.render_widget(Clear, area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(" 📌 Pin Package ")
+        .title_style(theme::help_section(palette))
+        .border_style(Style::default().fg(palette.accent).bg(palette.surface))
+        .style(theme::surface(palette));
+
+    let label_style = Style::default()
+        .fg(palette.accent)
+        .bg(palette.surface)
+        .add_modifier(Modifier::BOLD);
+
+    let mut lines = vec![
+        Line::raw(""),
+        Line::from(vec![
+            Span::raw("  Package: "),
+            Span::styled(dialog.id.as_str(), label_style),
+        ]),
+        Line::raw(""),
+    ];
+
+    for (index, choice) in dialog.choices.iter().enumerate() {
+        let text = format!(" {} ", choice.label());
+        if index == dialog.selected {
+            lines.push(Line::from(vec![
+                Span::raw("  "),
+                Span::styled(format!(">{text}"), theme::selected_row(palette)),
+            ]));
+        } else {
+            lines.push(Line::from(vec![Span::raw("   "), Span::raw(text)]));
+        }
+    }
+
+    lines.push(Line::raw(""));
+    let editing = dialog.current_choice() == PinChoice::Gating;
+    let mut range = vec![
+        Span::raw("  Version range: "),
+        Span::styled(
+            dialog.gating_range.as_str(),
+            theme::surface(palette).add_modifier(Modifier::BOLD),
+        ),
+    ];
+    if editing {
+        range.push(Span::styled(
+            "█",
+            Style::default().fg(palette.accent).bg(palette.surface),
+        ));
+    }
+    lines.push(Line::from(range));
+
+    lines.push(Line::raw(""));
+    lines.push(Line::from(vec![
+        Span::raw("  "),
+        Span::styled(" Enter ", theme::action_confirm(palette)),
+        Span::raw(" Confirm   "),
+        Span::styled(" Esc ", theme::action_danger(palette)),
+        Span::raw(" Cancel"),
+    ]));
+
+    let p = Paragraph::new(lines)
+        .block(block)
+        .style(theme::surface(palette))
+        .wrap(Wrap { trim: false });
+    f.render_widget(p, area);
+}
+
+fn draw_queue_overlay(f: &mut Frame, app: &App, palette: &theme::Theme) {
+    let area = centered_rect(70, 60, f.area());
+    f.render_widget(Clear, area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(palette.accent).bg(palette.surface))
+        .title(format!(
+            " Operation Queue -- {} pending  Q to close ",
+            app.pending_count()
+        ))
+        .title_style(theme::help_section(palette))
+        .style(theme::surface(palette));
+
+    let inner_width = area.width.saturating_sub(4) as usize;
+    let key = theme::help_key(palette);
+    let mut lines = vec![Line::from(vec![
+        Span::styled("  K/J ", key),
+        Span::raw("reorder  "),
+        Span::styled("d ", key),
+        Span::raw("remove pending  "),
+        Span::styled("c ", key),
+        Span::raw("clear finished  "),
+        Span::styled("C ", key),
+        Span::raw("cancel running"),
+    ])];
+    lines.push(Line::raw(""));
+
+    if app.queue.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "  No operations queued",
+            theme::surface_secondary(palette),
+        )));
+    }
+
+    for (index, entry) in app.queue.iter().enumerate() {
+        let (marker, marker_style) = match entry.status {
+            QueueStatus::Pending => ("…", theme::surface_secondary(palette)),
+            QueueStatus::Running => ("▶", Style::default().fg(palette.accent).bg(palette.surface)),
+            QueueStatus::Done => (
+                "✓",
+                Style::default().fg(palette.success).bg(palette.surface),
+            ),
+            QueueStatus::Failed => ("✗", Style::default().fg(palette.error).bg(palette.surface)),
+            QueueStatus::Cancelled => ("⊘", theme::surface_secondary(palette)),
+        };
+        let mut text = format!("{} [{}]", entry.operation, entry.status.label());
+        if entry.status == QueueStatus::Running {
+            if let Some(label) = app.operation_progress_label() {
+                text.push_str(&format!(" {label}"));
+            }
+        } else if !entry.message.is_empty() {
+            text.push_str(&format!(" -- {}", entry.message));
+        }
+        let text = truncate(&text, inner_width.saturating_sub(4)).into_owned();
+        if index == app.queue_selected {
+            let style = theme::selected_row(palette);
+            lines.push(Line::from(vec![
+                Span::raw("  "),
+                Span::styled(format!("{marker} {text}"), style),
+            ]));
+        } else {
+            lines.push(Line::from(vec![
+                Span::raw("  "),
+                Span::styled(marker, marker_style),
+                Span::raw(format!(" {text}")),
+            ]));
+        }
+    }
+
+    // Keep the highlighted entry visible in long queues
+    let inner_height = area.height.saturating_sub(2) as usize;
+    let selected_line = app.queue_selected + 2;
+    let scroll = selected_line.saturating_sub(inner_height.saturating_sub(1));
+
+    let p = Paragraph::new(lines)
+        .block(block)
+        .style(theme::surface(palette))
+        .scroll((scroll as u16, 0));
+    f.render_widget(p, area);
+}
+
+fn draw_sources_overlay(f: &mut Frame, app: &App, palette: &theme::Theme) {
+    let area = centered_rect(75, 60, f.area());
+    f.render_widget(Clear, area);
+
+    let title = if app.sources_loading {
+        format!(" Sources -- {} Loading...  F to close ", app.spinner())
+    } else {
+        format!(" Sources -- {} configured  F to close ", app.sources.len())
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(palette.accent).bg(palette.surface))
+        .title(title)
+        .title_style(theme::help_section(palette))
+        .style(theme::surface(palette));
+
+    let inner_width = area.width.saturating_sub(4) as usize;
+    let key = theme::help_key(palette);
+    let mut lines = vec![Line::from(vec![
+        Span::styled("  a ", key),
+        Span::raw("add  "),
+        Span::styled("d ", key),
+        Span::raw("remove  "),
+        Span::styled("u ", key),
+        Span::raw("update all  "),
+        Span::styled("R ", key),
+        Span::raw("reset to defaults  "),
+        Span::styled("r ", key),
+        Span::raw("reload"),
+    ])];
+    lines.push(Line::raw(""));
+
+    if app.sources.is_empty() && !app.sources_loading {
+        lines.push(Line::from(Span::styled(
+            "  No sources configured",
+            theme::surface_secondary(palette),
+        )));
+    }
+
+    for (index, source) in app.sources.iter().enumerate() {
+        let (marker, marker_style, status) = match app.source_updates.get(&source.name) {
+            Some(SourceUpdate::Updating) => (
+                "…",
+                theme::surface_secondary(palette),
+                "updating".to_string(),
+            ),
+            Some(SourceUpdate::Updated) => (
+                "✓",
+                Style::default().fg(palette.success).bg(palette.surface),
+                "updated".to_string(),
+            ),
+            Some(SourceUpdate::Failed(msg)) => (
+                "✗",
+                Style::default().fg(palette.error).bg(palette.surface),
+                format!("failed: {msg}"),
+            ),
+            None => (" ", theme::surface(palette), String::new()),
+        };
+        let mut text = format!(
+            "{:<14} {:<28} {}",
+            truncate(&source.name, 14),
+            truncate(&source.source_type, 28),
+            source.url
+        );
+        if !status.is_empty() {
+            text.push_str(&format!(" [{status}]"));
+        }
+        let text = truncate(&text, inner_width.saturating_sub(4)).into_owned();
+        if index == app.sources_selected {
+            lines.push(Line::from(vec![
+                Span::raw("  "),
+                Span::styled(format!("{marker} {text}"), theme::selected_row(palette)),
+            ]));
+        } else {
+            lines.push(Line::from(vec![
+                Span::raw("  "),
+                Span::styled(marker, marker_style),
+                Span::raw(format!(" {text}")),
+            ]));
+        }
+    }
+
+    let p = Paragraph::new(lines)
+        .block(block)
+        .style(theme::surface(palette));
+    f.render_widget(p, area);
 }
 
-fn draw_confirm_dialog(f: &mut Frame, confirm: &ConfirmDialog, palette: &theme::Theme) {
-    let area = centered_rect(50, 20, f.area());
+fn draw_source_dialog(f: &mut Frame, dialog: &SourceDialog, palette: &theme::Theme) {
+    let area = centered_rect(60, 40, f.area());
     f.render_widget(Clear, area);
 
     let block = Block::default()
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
-        .border_style(Style::default().fg(palette.accent).bg(palette.surface))
-        .title(" Confirm ")
+        .title(" Add Source ")
         .title_style(theme::help_section(palette))
+        .border_style(Style::default().fg(palette.accent).bg(palette.surface))
         .style(theme::surface(palette));
 
-    let lines = vec![
-        Line::raw(""),
-        Line::from(vec![Span::raw("  "), Span::raw(&confirm.message)]),
-        Line::raw(""),
-        Line::from(vec![
-            Span::raw("  "),
-            Span::styled(" y ", theme::action_confirm(palette)),
-            Span::raw(" Yes   "),
-            Span::styled(" n ", theme::action_danger(palette)),
-            Span::raw(" No"),
-        ]),
-    ];
+    let key = theme::help_key(palette);
+    let mut lines = vec![Line::raw("")];
+
+    let current = dialog.current_field();
+    for field in SourceField::ALL {
+        let value = if field == SourceField::Type {
+            format!("‹ {} ›", dialog.value(field))
+        } else if field == current {
+            format!("{}█", dialog.value(field))
+        } else {
+            dialog.value(field).to_string()
+        };
+        let text = format!("{:<14}{value}", field.label());
+        if field == current {
+            lines.push(Line::from(vec![
+                Span::raw("  "),
+                Span::styled(text, theme::selected_row(palette)),
+            ]));
+        } else {
+            lines.push(Line::from(vec![Span::raw("  "), Span::raw(text)]));
+        }
+    }
+
+    lines.push(Line::raw(""));
+    lines.push(Line::from(vec![
+        Span::styled("  ↑/↓ ", key),
+        Span::raw("field  "),
+        Span::styled("←/→ ", key),
+        Span::raw("change type  "),
+        Span::raw("type to edit text"),
+    ]));
+    lines.push(Line::raw(""));
+    lines.push(Line::from(vec![
+        Span::raw("  "),
+        Span::styled(" Enter ", theme::action_confirm(palette)),
+        Span::raw(" Confirm   "),
+        Span::styled(" Esc ", theme::action_danger(palette)),
+        Span::raw(" Cancel"),
+    ]));
 
     let p = Paragraph::new(lines)
         .block(block)
@@ -874,63 +1873,65 @@ fn draw_confirm_dialog(f: &mut Frame, confirm: &ConfirmDialog, palette: &theme::
     f.render_widget(p, area);
 }
 
-fn draw_version_input_dialog(f: &mut Frame, app: &App, palette: &theme::Theme) {
-    let area = centered_rect(55, 25, f.area());
+fn draw_output_overlay(f: &mut Frame, app: &App, palette: &theme::Theme) {
+    let area = centered_rect(80, 70, f.area());
     f.render_widget(Clear, area);
 
-    let pkg_name = app
-        .selected_package()
-        .map(|p| p.name.as_str())
-        .unwrap_or("package");
-
+    let title = match app.operation_progress_label() {
+        Some(label) => format!(" Operation Output -- {label}  L to close "),
+        None => " Operation Output  L to close ".to_string(),
+    };
     let block = Block::default()
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
-        .title(" 📦 Install Specific Version ")
-        .title_style(theme::help_section(palette))
         .border_style(Style::default().fg(palette.accent).bg(palette.surface))
+        .title(title)
+        .title_style(theme::help_section(palette))
         .style(theme::surface(palette));
 
-    let label_style = Style::default()
-        .fg(palette.accent)
-        .bg(palette.surface)
-        .add_modifier(Modifier::BOLD);
+    let inner_width = area.width.saturating_sub(4) as usize;
+    let inner_height = area.height.saturating_sub(2) as usize;
+    let mut lines = Vec::new();
 
-    let lines = vec![
-        Line::raw(""),
-        Line::from(vec![
-            Span::raw("  Package: "),
-            Span::styled(pkg_name, label_style),
-        ]),
-        Line::raw(""),
-        Line::from(vec![
-            Span::raw("  Version: "),
+    if let Some(percent) = app.op_percent {
+        // "  [" + bar + "] 100%" leaves the rest of the row for the bar itself
+        let bar_width = inner_width.saturating_sub(10);
+        let filled = bar_width * usize::from(percent) / 100;
+        lines.push(Line::from(vec![
+            Span::raw("  ["),
             Span::styled(
-                &app.version_input,
-                theme::surface(palette).add_modifier(Modifier::BOLD),
+                "█".repeat(filled),
+                Style::default().fg(palette.accent).bg(palette.surface),
             ),
-            Span::styled("█", Style::default().fg(palette.accent).bg(palette.surface)),
-        ]),
-        Line::raw(""),
-        Line::from(vec![
-            Span::raw("  "),
-            Span::styled(" Enter ", theme::action_confirm(palette)),
-            Span::raw(" Confirm   "),
-            Span::styled(" Esc ", theme::action_danger(palette)),
-            Span::raw(" Cancel"),
-        ]),
-    ];
+            Span::styled(
+                "░".repeat(bar_width - filled),
+                theme::surface_secondary(palette),
+            ),
+            Span::raw(format!("] {percent}%")),
+        ]));
+    }
+
+    if app.op_output.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "  No output yet",
+            theme::surface_secondary(palette),
+        )));
+    } else {
+        // Follow the tail so the newest output is always visible
+        let room = inner_height.saturating_sub(lines.len());
+        let skip = app.op_output.len().saturating_sub(room);
+        lines.extend(
+            app.op_output
+                .iter()
+                .skip(skip)
+                .map(|line| Line::raw(format!("  {}", truncate(line, inner_width)))),
+        );
+    }
 
     let p = Paragraph::new(lines)
         .block(block)
-        .style(theme::surface(palette))
-        .wrap(Wrap { trim: false });
+        .style(theme::surface(palette));
     f.render_widget(p, area);
-
-    // Position terminal cursor at end of version input field
-    let cursor_x = area.x + 12 + UnicodeWidthStr::width(app.version_input.as_str()) as u16;
-    let cursor_y = area.y + 4; // row 0: border, 1: blank, 2: package, 3: blank, 4: version
-    f.set_cursor_position((cursor_x, cursor_y));
 }
 
 fn draw_help_overlay(f: &mut Frame, app: &mut App, palette: &theme::Theme) {
@@ -967,7 +1968,7 @@ fn draw_help_overlay(f: &mut Frame, app: &mut App, palette: &theme::Theme) {
         ]),
         Line::from(vec![
             Span::styled("  lt/rt       ", key),
-            Span::raw("Switch view (Search / Installed / Upgrades)"),
+            Span::raw("Switch view (Search / Installed / Upgrades / Pins)"),
         ]),
         Line::from(vec![
             Span::styled("  Tab/S-Tab   ", key),
@@ -977,6 +1978,10 @@ fn draw_help_overlay(f: &mut Frame, app: &mut App, palette: &theme::Theme) {
             Span::styled("  / or s      ", key),
             Span::raw("Search or local filter"),
         ]),
+        Line::from(vec![
+            Span::raw("              "),
+            Span::raw("Search: tag:  cmd:  id:  name:  moniker:  \"exact\""),
+        ]),
         Line::from(vec![
             Span::styled("  f           ", key),
             Span::raw("Cycle source filter"),
@@ -995,17 +2000,21 @@ fn draw_help_overlay(f: &mut Frame, app: &mut App, palette: &theme::Theme) {
             Span::styled("  I           ", key),
             Span::raw("Install specific version"),
         ]),
+        Line::from(vec![
+            Span::styled("  O           ", key),
+            Span::raw("Install with options (scope, architecture, ...)"),
+        ]),
         Line::from(vec![
             Span::styled("  u           ", key),
             Span::raw("Upgrade selected package"),
         ]),
         Line::from(vec![
             Span::styled("  p           ", key),
-            Span::raw("Pin / unpin package (block upgrades)"),
+            Span::raw("Pin (regular, blocking, gating) / unpin"),
         ]),
         Line::from(vec![
             Span::styled("  x           ", key),
-            Span::raw("Uninstall selected package"),
+            Span::raw("Uninstall selected package (with options)"),
         ]),
         Line::from(vec![
             Span::styled("  Space       ", key),
@@ -1027,6 +2036,14 @@ fn draw_help_overlay(f: &mut Frame, app: &mut App, palette: &theme::Theme) {
             Span::styled("  P           ", key),
             Span::raw("Cycle pinned filter"),
         ]),
+        Line::from(vec![
+            Span::styled("  R           ", key),
+            Span::raw("Reset all pins (Pins view)"),
+        ]),
+        Line::from(vec![
+            Span::styled("  F           ", key),
+            Span::raw("Manage sources: add, remove, update, reset"),
+        ]),
         Line::from(vec![
             Span::styled("  Enter       ", key),
             Span::raw("Show package details / activate nav"),
@@ -1039,10 +2056,30 @@ fn draw_help_overlay(f: &mut Frame, app: &mut App, palette: &theme::Theme) {
             Span::styled("  c           ", key),
             Span::raw("Open changelog / release notes"),
         ]),
+        Line::from(vec![
+            Span::styled("  [ / ]       ", key),
+            Span::raw("Detail tabs: Overview, Versions, Installer, Dependencies, Raw"),
+        ]),
+        Line::from(vec![
+            Span::styled("  n           ", key),
+            Span::raw("Expand / collapse inline release notes"),
+        ]),
         Line::from(vec![
             Span::styled("  S           ", key),
             Span::raw("Cycle sort: Name↑ → Name↓ → ID↑ → ID↓ → Version↑ → Version↓ → off"),
         ]),
+        Line::from(vec![
+            Span::styled("  L           ", key),
+            Span::raw("Show live output of the running operation"),
+        ]),
+        Line::from(vec![
+            Span::styled("  C           ", key),
+            Span::raw("Cancel the running install / upgrade / batch"),
+        ]),
+        Line::from(vec![
+            Span::styled("  Q           ", key),
+            Span::raw("Operation queue (K/J reorder, d remove pending)"),
+        ]),
         Line::raw(""),
         Line::from(Span::styled("  Mouse", section)),
         Line::from(vec![
@@ -1157,6 +2194,77 @@ fn truncate(s: &str, max: usize) -> Cow<'_, str> {
 /// Word-wrap text into lines of at most `max_width` display columns.
 /// Breaks on word boundaries when possible; forces a break mid-word if a
 /// single word exceeds the line width.
+/// Wrap release notes line by line with `word_wrap`, keeping each line's
+/// indentation and hanging wrapped bullet text under the bullet's first word.
+fn release_notes_lines(notes: &str, max_width: usize) -> Vec<String> {
+    let mut out = Vec::new();
+    for line in notes.lines() {
+        let body = line.trim_start();
+        if body.is_empty() {
+            out.push(String::new());
+            continue;
+        }
+        let indent = &line[..line.len() - body.len()];
+        let (marker, text) = ["- ", "* ", "\u{2022} "]
+            .iter()
+            .find_map(|b| body.strip_prefix(b).map(|rest| (&body[..b.len()], rest)))
+            .unwrap_or(("", body));
+        let hang = UnicodeWidthStr::width(indent) + UnicodeWidthStr::width(marker);
+        let wrapped = word_wrap(text, max_width.saturating_sub(hang).max(1));
+        for (i, chunk) in wrapped.into_iter().enumerate() {
+            if i == 0 {
+                out.push(format!("{indent}{marker}{chunk}"));
+            } else {
+                out.push(format!("{}{chunk}", " ".repeat(hang)));
+            }
+        }
+    }
+    out
+}
+
+/// Break `line` every `max_width` display columns, keeping its spacing intact.
+fn wrap_chars(line: &str, max_width: usize) -> Vec<String> {
+    let mut out = vec![String::new()];
+    let mut width = 0;
+    for ch in line.chars() {
+        let cw = UnicodeWidthChar::width(ch).unwrap_or(0);
+        if max_width > 0 && width + cw > max_width && width > 0 {
+            out.push(String::new());
+            width = 0;
+        }
+        if let Some(current) = out.last_mut() {
+            current.push(ch);
+        }
+        width += cw;
+    }
+    out
+}
+
+/// A `  Label     value` detail row. Labels are padded to the usual 10-column
+/// gutter (or just past a longer label), and long values wrap under the value column.
+fn detail_field_lines(
+    label: &str,
+    value: &str,
+    label_style: Style,
+    value_style: Style,
+    width: u16,
+) -> Vec<Line<'static>> {
+    let gutter = 2 + UnicodeWidthStr::width(label).max(9) + 1;
+    let max_width = (width as usize).saturating_sub(2 + gutter);
+    word_wrap(value, max_width)
+        .into_iter()
+        .enumerate()
+        .map(|(i, chunk)| {
+            let prefix = if i == 0 {
+                Span::styled(format!("  {label:<w$}", w = gutter - 2), label_style)
+            } else {
+                Span::raw(" ".repeat(gutter))
+            };
+            Line::from(vec![prefix, Span::styled(chunk, value_style)])
+        })
+        .collect()
+}
+
 fn word_wrap(text: &str, max_width: usize) -> Vec<String> {
     if max_width == 0 {
         return vec![text.to_string()];
@@ -1222,14 +2330,16 @@ fn word_wrap(text: &str, max_width: usize) -> Vec<String> {
 mod tests {
     use std::sync::Arc;
 
-    use anyhow::Result;
     use async_trait::async_trait;
     use ratatui::{backend::TestBackend, buffer::Buffer, style::Color, Terminal};
 
     use super::*;
-    use crate::backend::WingetBackend;
+    use crate::app::{ConfirmDialog, InstallDialog, PinDialog, UninstallDialog};
+    use crate::backend::{ProgressFn, WingetBackend};
+    use crate::error::Result;
     use crate::models::{
-        Operation, Package, PackageDetail, PackagePin, SortDir, SortField, Source, SourceFilter,
+        InstallOptions, Operation, Package, PackageDetail, PackagePin, PinState, SortDir,
+        SortField, Source, SourceFilter, UninstallOptions,
     };
     use crate::theme::Theme;
 
@@ -1237,7 +2347,7 @@ mod tests {
 
     #[async_trait]
     impl WingetBackend for NoopBackend {
-        async fn search(&self, _: &str, _: Option<&str>) -> Result<Vec<Package>> {
+        async fn search(&self, _: &SearchRequest, _: Option<&str>) -> Result<Vec<Package>> {
             Ok(Vec::new())
         }
 
@@ -1252,16 +2362,25 @@ mod tests {
         async fn show(&self, _: &str) -> Result<PackageDetail> {
             Ok(PackageDetail::default())
         }
+        async fn show_versions(&self, _: &str) -> Result<Vec<String>> {
+            Ok(vec![])
+        }
 
-        async fn install(&self, _: &str, _: Option<&str>) -> Result<String> {
+        async fn install(
+            &self,
+            _: &str,
+            _: Option<&str>,
+            _: &InstallOptions,
+            _: &ProgressFn,
+        ) -> Result<String> {
             Ok(String::new())
         }
 
-        async fn uninstall(&self, _: &str) -> Result<String> {
+        async fn uninstall(&self, _: &str, _: &UninstallOptions, _: &ProgressFn) -> Result<String> {
             Ok(String::new())
         }
 
-        async fn upgrade(&self, _: &str) -> Result<String> {
+        async fn upgrade(&self, _: &str, _: &ProgressFn) -> Result<String> {
             Ok(String::new())
         }
 
@@ -1269,13 +2388,28 @@ mod tests {
             Ok(Vec::new())
         }
 
-        async fn pin(&self, _: &str) -> Result<String> {
+        async fn pin(&self, _: &str, _: &PinState) -> Result<String> {
             Ok(String::new())
         }
 
         async fn unpin(&self, _: &str) -> Result<String> {
             Ok(String::new())
         }
+        async fn reset_pins(&self) -> Result<String> {
+            Ok(String::new())
+        }
+        async fn add_source(&self, _: &Source) -> Result<String> {
+            Ok(String::new())
+        }
+        async fn remove_source(&self, _: &str) -> Result<String> {
+            Ok(String::new())
+        }
+        async fn reset_sources(&self) -> Result<String> {
+            Ok(String::new())
+        }
+        async fn update_source(&self, _: &str) -> Result<String> {
+            Ok(String::new())
+        }
 
         async fn list_sources(&self) -> Result<Vec<Source>> {
             Ok(Vec::new())
@@ -1295,18 +2429,23 @@ mod tests {
         terminal.backend().buffer().clone()
     }
 
-    fn find_text(buffer: &Buffer, needle: &str) -> (u16, u16) {
+    fn locate_text(buffer: &Buffer, needle: &str) -> Option<(u16, u16)> {
         for y in 0..buffer.area().height {
             for start_x in 0..buffer.area().width {
                 let suffix: String = (start_x..buffer.area().width)
                     .map(|x| buffer[(x, y)].symbol())
                     .collect();
                 if suffix.starts_with(needle) {
-                    return (start_x, y);
+                    return Some((start_x, y));
                 }
             }
         }
-        panic!("did not find {needle:?} in rendered buffer");
+        None
+    }
+
+    fn find_text(buffer: &Buffer, needle: &str) -> (u16, u16) {
+        locate_text(buffer, needle)
+            .unwrap_or_else(|| panic!("did not find {needle:?} in rendered buffer"))
     }
 
     fn is_wide_continuation(buffer: &Buffer, index: usize) -> bool {
@@ -1366,12 +2505,226 @@ mod tests {
             assert_text_style(&error, "Error: test", theme.error, theme.surface);
 
             let msstore = render(theme, |app| {
-                app.source_filter = SourceFilter::MsStore;
+                app.source_filter = SourceFilter::named("msstore");
             });
             assert_text_style(&msstore, "msstore", theme.on_selection, theme.selection);
+
+            let msstore_caps = render(theme, |app| {
+                app.source_filter = SourceFilter::named("MSStore");
+            });
+            assert_text_style(
+                &msstore_caps,
+                "MSStore",
+                theme.on_selection,
+                theme.selection,
+            );
+
+            let custom = render(theme, |app| {
+                app.source_filter = SourceFilter::named("contoso");
+            });
+            assert_text_style(&custom, "contoso", theme.on_success, theme.success);
         }
     }
 
+    #[test]
+    fn detail_panel_shows_installer_dependencies_and_agreements() {
+        let buffer = render(Theme::original(), |app| {
+            app.detail = Some(PackageDetail {
+                id: "Example.App".to_string(),
+                name: "Example".to_string(),
+                author: "Jane Doe".to_string(),
+                tags: vec!["editor".to_string(), "cli".to_string()],
+                installer_type: "msix".to_string(),
+                dependencies: vec![crate::models::DependencyGroup {
+                    kind: "Package Dependencies".to_string(),
+                    items: vec!["Microsoft.VCLibs.Desktop.14".to_string()],
+                }],
+                agreements: vec![("Pricing".to_string(), "Free".to_string())],
+                ..PackageDetail::default()
+            });
+        });
+        find_text(&buffer, "Author    Jane Doe");
+        find_text(&buffer, "editor, cli");
+        find_text(&buffer, "Pricing   Free");
+    }
+
+    #[test]
+    fn detail_tabs_show_installer_dependencies_versions_and_raw() {
+        let detail = PackageDetail {
+            id: "Example.App".to_string(),
+            installer_type: "msix".to_string(),
+            dependencies: vec![crate::models::DependencyGroup {
+                kind: "Package Dependencies".to_string(),
+                items: vec!["Microsoft.VCLibs.Desktop.14".to_string()],
+            }],
+            ..PackageDetail::default()
+        };
+        let with_tab = |tab: DetailTab| {
+            let detail = detail.clone();
+            render(Theme::original(), move |app| {
+                app.detail = Some(detail);
+                app.detail_tab = tab;
+                app.versions_cache
+                    .insert("Example.App".to_string(), vec!["2.0".into(), "1.0".into()]);
+                app.raw_cache.insert(
+                    "Example.App".to_string(),
+                    "Found Example [Example.App]\nVersion: 2.0".to_string(),
+                );
+            })
+        };
+
+        let installer = with_tab(DetailTab::Installer);
+        find_text(&installer, " Overview ");
+        find_text(&installer, "Type      msix");
+        assert!(locate_text(&installer, "Jane Doe").is_none());
+
+        let dependencies = with_tab(DetailTab::Dependencies);
+        find_text(&dependencies, "Package Dependencies");
+        find_text(&dependencies, "• Microsoft.VCLibs.Desktop.14");
+
+        let versions = with_tab(DetailTab::Versions);
+        find_text(&versions, "2 versions");
+        find_text(&versions, "2.0  latest");
+
+        let raw = with_tab(DetailTab::Raw);
+        find_text(&raw, "Found Example [Example.App]");
+    }
+
+    #[test]
+    fn detail_tab_strip_records_click_regions() {
+        let mut app = App::new(Arc::new(NoopBackend), crate::config::Config::default());
+        app.detail = Some(PackageDetail::default());
+        let backend = TestBackend::new(160, 40);
+        let mut terminal = Terminal::new(backend).expect("test terminal");
+        terminal
+            .draw(|frame| draw(frame, &mut app))
+            .expect("render succeeds");
+        let regions = &app.layout.detail_tab_regions;
+        assert_eq!(regions.len(), DetailTab::ALL.len());
+        assert_eq!(regions[0].2, DetailTab::Overview);
+        assert!(regions.windows(2).all(|w| w[0].1 < w[1].0));
+        assert!(app.layout.detail_tab_y > app.layout.detail_panel.y);
+    }
+
+    #[test]
+    fn release_notes_section_is_collapsible() {
+        let detail = PackageDetail {
+            id: "Example.App".to_string(),
+            release_notes: "- Faster startup".to_string(),
+            ..PackageDetail::default()
+        };
+        let collapsed = render(Theme::original(), |app| app.detail = Some(detail.clone()));
+        find_text(&collapsed, "▸ Release Notes  (n to expand)");
+        assert!(locate_text(&collapsed, "Faster startup").is_none());
+
+        let expanded = render(Theme::original(), |app| {
+            app.detail = Some(detail);
+            app.release_notes_expanded = true;
+        });
+        find_text(&expanded, "▾ Release Notes  (n to collapse)");
+        find_text(&expanded, "- Faster startup");
+    }
+
+    #[test]
+    fn release_notes_lines_keep_indent_and_hang_bullets() {
+        let notes = "Fixed\n\n  - a very long bullet item";
+        assert_eq!(
+            release_notes_lines(notes, 16),
+            vec!["Fixed", "", "  - a very long", "    bullet item"]
+        );
+    }
+
+    #[test]
+    fn detail_field_lines_wrap_under_the_value_column() {
+        let lines = detail_field_lines(
+            "SHA256",
+            "0123456789abcdef",
+            Style::default(),
+            Style::default(),
+            22,
+        );
+        let text: Vec<String> = lines.iter().map(|l| l.to_string()).collect();
+        assert_eq!(text, vec!["  SHA256    01234567", "            89abcdef"]);
+    }
+
+    #[test]
+    fn search_bar_flags_invalid_query_syntax() {
+        let theme = Theme::original();
+        let buffer = render(theme, |app| {
+            app.mode = AppMode::Search;
+            app.search_query = "code \"visual".to_string();
+        });
+        let (x, y) = find_text(&buffer, "\"visual");
+        assert_eq!(buffer[(x, y)].fg, theme.error);
+        find_text(&buffer, "⚠ Unclosed quote");
+
+        let valid = render(theme, |app| {
+            app.mode = AppMode::Search;
+            app.search_query = "tag:editor".to_string();
+        });
+        assert!(locate_text(&valid, "⚠").is_none());
+    }
+
+    #[test]
+    fn search_results_show_match_column_only_when_reported() {
+        let result = |reason: &str| Package {
+            id: "Example.Fx".to_string(),
+            name: "fx".to_string(),
+            version: "1.0".to_string(),
+            source: "winget".to_string(),
+            available_version: String::new(),
+            match_reason: reason.to_string(),
+            pin_state: crate::models::PinState::None,
+        };
+        let buffer = render(Theme::original(), |app| {
+            app.mode = AppMode::Search;
+            app.search_query = "json".to_string();
+            app.packages = vec![result("Tag: json")];
+            app.apply_filter();
+        });
+        let (header_x, header_y) = find_text(&buffer, "Match");
+        let (cell_x, cell_y) = find_text(&buffer, "Tag: json");
+        assert_eq!(header_x, cell_x);
+        assert!(cell_y > header_y);
+
+        let plain = render(Theme::original(), |app| {
+            app.mode = AppMode::Search;
+            app.search_query = "fx".to_string();
+            app.packages = vec![result("")];
+            app.apply_filter();
+        });
+        assert!(locate_text(&plain, "Match").is_none());
+    }
+
+    #[test]
+    fn cached_list_stays_visible_while_loading() {
+        let buffer = render(Theme::original(), |app| {
+            app.packages = vec![Package {
+                id: "Cached.Pkg".to_string(),
+                name: "Cached".to_string(),
+                version: "1.0".to_string(),
+                source: "winget".to_string(),
+                available_version: String::new(),
+                match_reason: String::new(),
+                pin_state: crate::models::PinState::None,
+            }];
+            app.apply_filter();
+            app.stale_list_age = Some(std::time::Duration::from_secs(600));
+            app.loading = true;
+        });
+        find_text(&buffer, "Cached.Pkg");
+        find_text(&buffer, "◷ cached 10 min ago");
+    }
+
+    #[test]
+    fn status_bar_flags_pending_reboot() {
+        let buffer = render(Theme::original(), |app| {
+            app.status_message = "Upgrading Foo — failed".to_string();
+            app.reboot_pending = true;
+        });
+        find_text(&buffer, "⟳ restart required");
+    }
+
     #[test]
     fn overlays_repaint_cells_after_clear() {
         for theme in [Theme::original(), Theme::retro(), Theme::nord()] {
@@ -1395,12 +2748,61 @@ mod tests {
             assert_text_style(&help, "Move up / down", theme.text_primary, theme.surface);
             assert_no_reset_cells(&help, centered_rect(60, 70, *help.area()));
 
+            let output = render(theme, |app| {
+                app.show_output = true;
+                app.op_percent = Some(40);
+                app.op_output
+                    .push_back("Starting package install...".to_string());
+            });
+            assert_text_style(
+                &output,
+                "Starting package install...",
+                theme.text_primary,
+                theme.surface,
+            );
+            assert_no_reset_cells(&output, centered_rect(80, 70, *output.area()));
+
+            let queue = render(theme, |app| {
+                app.show_queue = true;
+                app.queue.push(crate::app::QueueEntry {
+                    operation: Operation::Upgrade {
+                        id: "Example.Package".to_string(),
+                    },
+                    status: QueueStatus::Pending,
+                    message: String::new(),
+                });
+            });
+            assert_no_reset_cells(&queue, centered_rect(70, 60, *queue.area()));
+
             let version = render(theme, |app| {
                 app.input_mode = InputMode::VersionInput;
                 app.version_input = "1.2.3".to_string();
             });
             assert_text_style(&version, "1.2.3", theme.text_primary, theme.surface);
-            assert_no_reset_cells(&version, centered_rect(55, 25, *version.area()));
+            assert_no_reset_cells(&version, centered_rect(55, 60, *version.area()));
+
+            let install = render(theme, |app| {
+                app.install_dialog = Some(InstallDialog::new("Git.Git".to_string()));
+            });
+            assert_text_style(&install, "Git.Git", theme.accent, theme.surface);
+            assert_no_reset_cells(&install, centered_rect(60, 60, *install.area()));
+
+            let uninstall = render(theme, |app| {
+                app.uninstall_dialog = Some(UninstallDialog::new("Foo.Bar".to_string()));
+            });
+            assert_text_style(&uninstall, "Foo.Bar", theme.accent, theme.surface);
+            assert_no_reset_cells(&uninstall, centered_rect(60, 50, *uninstall.area()));
+
+            let pin = render(theme, |app| {
+                app.pin_dialog = Some(PinDialog::new(
+                    "Foo.Bar".to_string(),
+                    "1.2.3",
+                    &PinState::None,
+                ));
+            });
+            assert_text_style(&pin, "Foo.Bar", theme.accent, theme.surface);
+            find_text(&pin, "Version range: 1.2.*");
+            assert_no_reset_cells(&pin, centered_rect(60, 45, *pin.area()));
         }
     }
 
@@ -1415,6 +2817,7 @@ mod tests {
                     version: "1.0".to_string(),
                     available_version: "2.0".to_string(),
                     source: "winget".to_string(),
+                    match_reason: String::new(),
                     pin_state: Default::default(),
                 }];
                 app.filtered_packages = app.packages.clone();
@@ -1423,6 +2826,66 @@ mod tests {
         }
     }
 
+    #[test]
+    fn version_picker_marks_installed_and_latest() {
+        let buffer = render(Theme::original(), |app| {
+            app.packages = vec![Package {
+                name: "Example".to_string(),
+                id: "Example.Package".to_string(),
+                version: "1.9.2".to_string(),
+                source: "winget".to_string(),
+                available_version: String::new(),
+                match_reason: String::new(),
+                pin_state: Default::default(),
+            }];
+            app.filtered_packages = app.packages.clone();
+            app.input_mode = InputMode::VersionInput;
+            app.picker_versions = vec!["1.10.0".into(), "1.9.2".into(), "1.2.0".into()];
+        });
+        find_text(&buffer, "> 1.10.0  (latest)");
+        find_text(&buffer, "1.9.2  (installed)");
+    }
+
+    #[test]
+    fn pins_view_shows_pin_type_and_pinned_version() {
+        let buffer = render(Theme::original(), |app| {
+            app.mode = AppMode::Pins;
+            app.packages = vec![Package {
+                name: "Git".to_string(),
+                id: "Git.Git".to_string(),
+                version: "2.45.*".to_string(),
+                source: "winget".to_string(),
+                available_version: String::new(),
+                match_reason: String::new(),
+                pin_state: PinState::Gating("2.45.*".to_string()),
+            }];
+            app.filtered_packages = app.packages.clone();
+        });
+        find_text(&buffer, "\u{25C8} Pins");
+        find_text(&buffer, "Pinned Version");
+        find_text(&buffer, "Gating");
+        find_text(&buffer, "2.45.*");
+    }
+
+    #[test]
+    fn sources_view_lists_sources_with_update_results() {
+        let buffer = render(Theme::original(), |app| {
+            app.show_sources = true;
+            app.sources = vec![Source {
+                name: "contoso".to_string(),
+                url: "https://pkgs.contoso.com/api".to_string(),
+                source_type: "Microsoft.Rest".to_string(),
+            }];
+            app.source_updates.insert(
+                "contoso".to_string(),
+                SourceUpdate::Failed("unreachable".to_string()),
+            );
+        });
+        find_text(&buffer, "Sources -- 1 configured");
+        find_text(&buffer, "Microsoft.Rest");
+        find_text(&buffer, "[failed: unreachable]");
+    }
+
     #[test]
     fn focused_panel_has_a_non_color_marker() {
         let list_focused = render(Theme::original(), |_| {});